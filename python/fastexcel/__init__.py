@@ -2,7 +2,9 @@ from __future__ import annotations
 
 import sys
 import typing
-from typing import TYPE_CHECKING, Callable, Literal
+from datetime import date, datetime, timedelta, tzinfo
+from glob import glob
+from typing import TYPE_CHECKING, Any, Callable, Iterable, Iterator, Literal, TypedDict
 
 if sys.version_info < (3, 10):
     from typing_extensions import TypeAlias
@@ -13,34 +15,174 @@ if TYPE_CHECKING:
     import pandas as pd
     import polars as pl
 
+    if sys.version_info < (3, 11):
+        from typing_extensions import Unpack
+    else:
+        from typing import Unpack
+
 from os.path import expanduser
 from pathlib import Path
 
 import pyarrow as pa
 
 from ._fastexcel import (
+    ArrowColumn,
     ArrowError,
     CalamineCellError,
     CalamineError,
     CannotRetrieveCellDataError,
+    CellError,
     ColumnInfo,
     ColumnNotFoundError,
+    ColumnStats,
+    CorruptFileError,
+    DuplicateColumnError,
     FastExcelError,
+    HeaderWarning,
+    InternalError,
+    InvalidDtypeError,
     InvalidParametersError,
+    ReadWarning,
+    ResourceLimitError,
+    SchemaError,
     SheetNotFoundError,
+    SizeLimitExceededError,
+    TypePromotion,
+    UnknownColumnError,
     UnsupportedColumnTypeCombinationError,
     __version__,
     _ExcelReader,
     _ExcelSheet,
+    _ExcelSheetBatches,
     _ExcelTable,
 )
+from ._fastexcel import _concat_record_batches, _merge_record_batches
 from ._fastexcel import read_excel as _read_excel
 
-DType = Literal["null", "int", "float", "string", "boolean", "datetime", "date", "duration"]
+DType = Literal[
+    "null",
+    "int",
+    "float",
+    "string",
+    "boolean",
+    "bool_from_int",
+    "bool",
+    "datetime",
+    "datetime_tz",
+    "date",
+    "duration",
+    "excel_serial",
+]
 DTypeMap: TypeAlias = "dict[str | int, DType]"
 ColumnNameFrom: TypeAlias = Literal["provided", "looked_up", "generated"]
-DTypeFrom: TypeAlias = Literal["provided_by_index", "provided_by_name", "guessed"]
+DTypeFrom: TypeAlias = Literal[
+    "provided_by_index", "provided_by_name", "provided_for_all", "guessed"
+]
 SheetVisible: TypeAlias = Literal["visible", "hidden", "veryhidden"]
+TimestampUnit: TypeAlias = Literal["ms", "us", "ns"]
+ParquetCompression: TypeAlias = Literal["none", "snappy", "gzip", "brotli", "lz4", "zstd"]
+ExtraColumns: TypeAlias = Literal["drop", "error"]
+MissingColumns: TypeAlias = Literal["error", "null"]
+UnknownRenames: TypeAlias = Literal["ignore", "error"]
+DuplicateColumns: TypeAlias = Literal["underscore", "dot", "error"]
+SampleStrategy: TypeAlias = Literal["head", "spread", "full"]
+SizeLimitOverflow: TypeAlias = Literal["error", "truncate"]
+CellsMode: TypeAlias = Literal["values", "value_and_formula"]
+FillValue: TypeAlias = "bool | int | float | str | date | datetime | timedelta"
+
+
+class LoadSheetOptions(TypedDict, total=False):
+    """The keyword arguments of `ExcelReader.load_sheet`, documented there"""
+
+    header_row: int | None
+    column_names: list[str] | None
+    skip_rows: int
+    n_rows: int | None
+    schema_sample_rows: int | float | None
+    sample_strategy: SampleStrategy
+    dtype_coercion: Literal["coerce", "strict"]
+    use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None
+    dtypes: DTypeMap | None
+    timestamp_unit: TimestampUnit
+    index_column: int | str | None
+    bool_as_int8: bool
+    bool_fill: bool | None
+    epoch: date | None
+    assume_timezone: str | tzinfo | None
+    timezone: str | tzinfo | None
+    na_values: list[str] | None
+    empty_string_as_null: bool
+    trim_strings: bool
+    n_threads: int | None
+    all_columns_dtype: DType | None
+    infer_dates: bool
+    true_values: list[str] | None
+    false_values: list[str] | None
+    rename: dict[str, str] | None
+    unknown_renames: UnknownRenames
+    duplicate_columns: DuplicateColumns
+    currency_symbols: list[str] | None
+    capture_currency: bool
+    tighten_nullability: bool
+    decimal_separator: str | None
+    thousands_separator: str | None
+    fill_values: dict[str, FillValue] | None
+    forward_fill: list[int | str] | None
+    column_prefix: str
+    auto_header: bool
+    validate_header: bool
+    stop_at_empty_row: bool
+    header_depth: int
+    header_separator: str
+    keep_header_in_data: bool
+    data_start_row: int | None
+    data_rows: list[int] | None
+    expected_headers: list[str] | None
+    header_search_limit: int
+    respect_autofilter: bool
+    transpose: bool
+    cell_range: str | None
+    fill_merged_cells: bool
+    max_columns: int | None
+    max_rows: int | None
+    max_cells: int | None
+    on_size_limit: SizeLimitOverflow
+    cells: CellsMode
+    collect_warnings: bool
+
+
+# Key of the field metadata flagging the column selected with `index_column`
+_INDEX_COLUMN_METADATA_KEY = b"fastexcel.index_column"
+
+
+def _dtype_for_arrow_type(arrow_type: pa.DataType) -> DType | None:
+    """The dtype to read a column as, before casting it to `arrow_type`. `None` if the dtype
+    should be guessed"""
+    if pa.types.is_integer(arrow_type):
+        return "int"
+    if pa.types.is_floating(arrow_type) or pa.types.is_decimal(arrow_type):
+        return "float"
+    if pa.types.is_string(arrow_type) or pa.types.is_large_string(arrow_type):
+        return "string"
+    if pa.types.is_boolean(arrow_type):
+        return "boolean"
+    if pa.types.is_date(arrow_type):
+        return "date"
+    if pa.types.is_timestamp(arrow_type):
+        return "datetime" if arrow_type.tz is None else "datetime_tz"
+    if pa.types.is_duration(arrow_type):
+        return "duration"
+    return None
+
+
+def _recordbatch_to_pandas(rb: pa.RecordBatch) -> pd.DataFrame:
+    df = rb.to_pandas()
+    index_columns = [
+        field.name
+        for field in rb.schema
+        if field.metadata is not None and field.metadata.get(_INDEX_COLUMN_METADATA_KEY) == b"true"
+    ]
+    return df.set_index(index_columns) if index_columns else df
 
 
 def _recordbatch_to_polars(rb: pa.RecordBatch) -> pl.DataFrame:
@@ -51,6 +193,52 @@ def _recordbatch_to_polars(rb: pa.RecordBatch) -> pl.DataFrame:
     return df
 
 
+def _recordbatch_to_parquet(
+    rb: pa.RecordBatch,
+    where: Path | str,
+    row_group_size: int | None,
+    compression: ParquetCompression,
+) -> None:
+    import pyarrow.parquet as pq
+
+    pq.write_table(
+        pa.Table.from_batches([rb]),
+        where,
+        row_group_size=row_group_size,
+        compression=compression,
+    )
+
+
+def _batches_to_parquet(
+    batches: _ExcelSheetBatches,
+    where: Path | str,
+    row_group_size: int | None,
+    compression: ParquetCompression,
+) -> None:
+    import pyarrow.parquet as pq
+
+    with pq.ParquetWriter(where, batches.schema, compression=compression) as writer:
+        # Batches are buffered until they fill a row group, so that row groups do not depend on
+        # the batch size
+        buffered: list[pa.RecordBatch] = []
+        buffered_rows = 0
+        for batch in batches:
+            buffered.append(batch)
+            buffered_rows += batch.num_rows
+            if row_group_size is None or buffered_rows < row_group_size:
+                continue
+            table = pa.Table.from_batches(buffered, schema=batches.schema)
+            full_rows = buffered_rows - buffered_rows % row_group_size
+            writer.write_table(table.slice(0, full_rows), row_group_size=row_group_size)
+            buffered = table.slice(full_rows).to_batches()
+            buffered_rows -= full_rows
+        if buffered_rows:
+            writer.write_table(
+                pa.Table.from_batches(buffered, schema=batches.schema),
+                row_group_size=row_group_size,
+            )
+
+
 class ExcelSheet:
     """A class representing a single sheet in an Excel File"""
 
@@ -77,6 +265,33 @@ class ExcelSheet:
         """The sheet's total height"""
         return self._sheet.total_height
 
+    @property
+    def header_row(self) -> int | None:
+        """The index of the header row, or `None` if the column names are not read from the sheet"""
+        return self._sheet.header_row
+
+    @property
+    def origin(self) -> tuple[int, int]:
+        """The (row, column) position in the worksheet of the first cell of the loaded data.
+
+        It is the first data row in the first column of the data, below the header and the
+        skipped rows. Positions start at 0, e.g. `(3, 1)` is cell B4.
+        """
+        return self._sheet.origin
+
+    @property
+    def header_warnings(self) -> list[HeaderWarning]:
+        """The warnings emitted when the sheet was loaded with `validate_header=True`"""
+        return self._sheet.header_warnings
+
+    @property
+    def read_warnings(self) -> list[ReadWarning]:
+        """The warnings collected when the sheet was loaded with `collect_warnings=True`.
+
+        Cells read as null are only known once the sheet was converted, e.g. with `to_arrow`.
+        """
+        return self._sheet.read_warnings
+
     @property
     def selected_columns(self) -> list[ColumnInfo]:
         """The sheet's selected columns"""
@@ -97,17 +312,138 @@ class ExcelSheet:
         """The visibility of the sheet"""
         return self._sheet.visible
 
+    @property
+    def schema(self) -> pa.Schema:
+        """The sheet's arrow schema, computed from the selected columns without reading any data.
+
+        All fields are nullable, as the data is not read. If the sheet was loaded with
+        `tighten_nullability`, the selected columns are scanned instead, and the fields of the
+        columns without nulls are non-nullable, as in `to_arrow`.
+        """
+        return self._sheet.schema
+
+    @property
+    def columns(self) -> list[tuple[str, str]]:
+        """The name and arrow type of the selected columns, as plain strings.
+
+        The types are named as in pyarrow, e.g. `"double"` or `"timestamp[ms]"`.
+        """
+        return self._sheet.columns
+
+    def is_empty(self) -> bool:
+        """Whether the sheet has no data rows below its header.
+
+        This does not read any cell. `to_arrow` on an empty sheet returns a RecordBatch with no
+        rows, rather than raising an error.
+        """
+        return self._sheet.is_empty()
+
     def to_arrow(self) -> pa.RecordBatch:
-        """Converts the sheet to a pyarrow `RecordBatch`"""
+        """Converts the sheet to a pyarrow `RecordBatch`.
+
+        A field is only nullable if its column contains nulls. This is determined from every row
+        that is read, not from the `schema_sample_rows` used to guess the dtypes.
+        """
         return self._sheet.to_arrow()
 
+    def schema_and_arrow(self) -> tuple[pa.Schema, pa.RecordBatch]:
+        """Converts the sheet to a pyarrow `RecordBatch`, returned along with its schema.
+
+        The schema is computed once, along with the data.
+        """
+        return self._sheet.schema_and_arrow()
+
+    def column(self, idx_or_name: int | str) -> ArrowColumn:
+        """Converts a single selected column, without converting the other ones.
+
+        The returned `ArrowColumn` implements the Arrow PyCapsule interface
+        (`__arrow_c_array__`), so that libraries supporting it can import it without copies,
+        e.g. with `pyarrow.array(column)`.
+
+        :param idx_or_name: The index of the column in the sheet (as in `use_columns`), or its
+                            name. Raises a `ColumnNotFoundError` if it is not selected.
+        """
+        return self._sheet.column(idx_or_name)
+
+    def cell_reference(self, row: int, column: int | str) -> str:
+        """The A1 reference of a loaded cell in the worksheet, e.g. `"AB12"`.
+
+        Useful to point users to the cell a value was read from. Selected data rows and
+        transposed sheets are mapped back to their cells in the worksheet.
+
+        :param row: The index of the row in the loaded data, as in `to_arrow`. For batches of
+                    `to_arrow_batches`, add the number of rows of the previous batches.
+        :param column: The index of the column in the sheet (as in `use_columns`), or its name.
+                       Raises a `ColumnNotFoundError` if it is not selected.
+        """
+        return self._sheet.cell_reference(row, column)
+
+    def column_stats(self) -> list[ColumnStats]:
+        """Computes statistics of the selected columns, without converting them to arrow.
+
+        Every cell is read once and converted as it would be for its column's dtype. This is
+        useful for profiling a sheet, e.g. to choose dtypes before reading it.
+
+        Returns one `ColumnStats` per selected column, with the number of values and nulls.
+        Numeric and temporal columns also have their `min` and `max` values, and string columns
+        an estimate of their number of distinct values (`distinct_estimate`).
+        """
+        return self._sheet.column_stats()
+
+    def rows(self) -> Iterator[dict[str, Any]]:
+        """Iterates over the sheet's rows, as dicts keyed by column name.
+
+        Values are converted to Python objects according to the dtype of their column, e.g.
+        `datetime.datetime` for `datetime` columns, and empty cells are `None`. The fill options,
+        such as `fill_values` and `forward_fill`, apply as in `to_arrow`, and the formula columns
+        read with `cells="value_and_formula"` are keys of the rows too.
+
+        This is convenient for scripting, but much slower than converting the sheet to arrow:
+        it is not meant for large sheets.
+        """
+        return self._sheet.rows()
+
+    def to_arrow_batches(self, batch_size: int = 65_536) -> Iterator[pa.RecordBatch]:
+        """Iterates over the sheet in pyarrow `RecordBatch`es of at most `batch_size` rows.
+
+        Only one batch is converted at a time, which bounds the memory used by arrow data when
+        reading large sheets. Every batch has the same schema, with all fields nullable unless
+        the sheet was loaded with `tighten_nullability`.
+        """
+        return self._sheet.to_arrow_batches(batch_size)
+
+    def to_arrow_with_schema(
+        self,
+        template: pa.Schema | ExcelSheet,
+        *,
+        extra_columns: ExtraColumns = "drop",
+    ) -> pa.RecordBatch:
+        """Converts the sheet to a pyarrow `RecordBatch` conforming to a template schema.
+
+        This guarantees a stable schema when reading several files with the same layout. The
+        resulting batch contains exactly the template's columns, in the template's order, all of
+        them nullable. Columns missing from the sheet are filled with nulls of the template's
+        type, and the other ones are cast to the template's type.
+
+        :param template: The template schema. If an `ExcelSheet` is provided, the schema of its
+                         selected columns is used, without reading its data.
+        :param extra_columns: What to do with the sheet's columns that are not part of the
+                              template: `"drop"` them, or raise a `ColumnNotFoundError`
+                              (`"error"`).
+        """
+        if isinstance(template, ExcelSheet):
+            return self._sheet.to_arrow_with_schema(template._sheet, extra_columns=extra_columns)
+        return self._sheet.to_arrow_with_schema(template, extra_columns=extra_columns)
+
     def to_pandas(self) -> "pd.DataFrame":
         """Converts the sheet to a Pandas `DataFrame`.
 
+        If an `index_column` was specified, it is used as the `DataFrame`'s index.
+
         Requires the `pandas` extra to be installed.
         """
         # We know for sure that the sheet will yield exactly one RecordBatch
-        return self.to_arrow().to_pandas()
+        return _recordbatch_to_pandas(self.to_arrow())
 
     def to_polars(self) -> "pl.DataFrame":
         """Converts the sheet to a Polars `DataFrame`.
@@ -116,6 +452,33 @@ class ExcelSheet:
         """
         return _recordbatch_to_polars(self.to_arrow())
 
+    def to_parquet(
+        self,
+        where: Path | str,
+        *,
+        row_group_size: int | None = 1_000_000,
+        compression: ParquetCompression = "zstd",
+        batch_size: int | None = None,
+    ) -> None:
+        """Writes the sheet to a Parquet file.
+
+        :param where: The path of the file to write.
+        :param row_group_size: The maximum number of rows in each row group. Large row groups
+                               are better suited for analytical scans, whereas smaller ones are
+                               better suited for selective reads. If `None`, the whole sheet is
+                               written in a single row group, up to pyarrow's own maximum.
+        :param compression: The compression codec to use.
+        :param batch_size: If set, the sheet is converted and written `batch_size` rows at a
+                           time (see `to_arrow_batches`) rather than as a whole, so that huge
+                           sheets can be converted with bounded memory.
+        """
+        if batch_size is None:
+            _recordbatch_to_parquet(self.to_arrow(), where, row_group_size, compression)
+        else:
+            _batches_to_parquet(
+                self._sheet.to_arrow_batches(batch_size), where, row_group_size, compression
+            )
+
     def __repr__(self) -> str:
         return self._sheet.__repr__()
 
@@ -171,17 +534,47 @@ class ExcelTable:
         """The dtypes specified for the table"""
         return self._table.specified_dtypes
 
+    @property
+    def schema(self) -> pa.Schema:
+        """The table's arrow schema, computed from the selected columns without reading any data.
+
+        All fields are nullable, as the data is not read. If the table was loaded with
+        `tighten_nullability`, the selected columns are scanned instead, and the fields of the
+        columns without nulls are non-nullable, as in `to_arrow`.
+        """
+        return self._table.schema
+
     def to_arrow(self) -> pa.RecordBatch:
-        """Converts the table to a pyarrow `RecordBatch`"""
+        """Converts the table to a pyarrow `RecordBatch`.
+
+        A field is only nullable if its column contains nulls. This is determined from every row
+        that is read, not from the `schema_sample_rows` used to guess the dtypes.
+        """
         return self._table.to_arrow()
 
+    def schema_and_arrow(self) -> tuple[pa.Schema, pa.RecordBatch]:
+        """Converts the table to a pyarrow `RecordBatch`, returned along with its schema.
+
+        The schema is computed once, along with the data.
+        """
+        return self._table.schema_and_arrow()
+
+    def column(self, idx_or_name: int | str) -> ArrowColumn:
+        """Converts a single selected column, without converting the other ones.
+
+        See `ExcelSheet.column`.
+        """
+        return self._table.column(idx_or_name)
+
     def to_pandas(self) -> "pd.DataFrame":
         """Converts the table to a Pandas `DataFrame`.
 
+        If an `index_column` was specified, it is used as the `DataFrame`'s index.
+
         Requires the `pandas` extra to be installed.
         """
         # We know for sure that the table will yield exactly one RecordBatch
-        return self.to_arrow().to_pandas()
+        return _recordbatch_to_pandas(self.to_arrow())
 
     def to_polars(self) -> "pl.DataFrame":
         """Converts the table to a Polars `DataFrame`.
@@ -190,6 +583,84 @@ class ExcelTable:
         """
         return _recordbatch_to_polars(self.to_arrow())
 
+    def to_parquet(
+        self,
+        where: Path | str,
+        *,
+        row_group_size: int | None = 1_000_000,
+        compression: ParquetCompression = "zstd",
+    ) -> None:
+        """Writes the table to a Parquet file.
+
+        :param where: The path of the file to write.
+        :param row_group_size: The maximum number of rows in each row group. Large row groups
+                               are better suited for analytical scans, whereas smaller ones are
+                               better suited for selective reads. If `None`, the whole table is
+                               written in a single row group, up to pyarrow's own maximum.
+        :param compression: The compression codec to use.
+        """
+        _recordbatch_to_parquet(self.to_arrow(), where, row_group_size, compression)
+
+
+class LazySheet:
+    """A sheet that is only loaded, and its dtypes inferred, when it is first used.
+
+    The sheet is loaded once: every later access reuses the same `ExcelSheet` and `RecordBatch`.
+    """
+
+    def __init__(self, reader: ExcelReader, name: str, load_kwargs: dict[str, Any]) -> None:
+        self._reader = reader
+        self._name = name
+        self._load_kwargs = load_kwargs
+        self._sheet: ExcelSheet | None = None
+        self._record_batch: pa.RecordBatch | None = None
+
+    @property
+    def name(self) -> str:
+        """The name of the sheet"""
+        return self._name
+
+    @property
+    def is_loaded(self) -> bool:
+        """Whether the sheet was loaded"""
+        return self._sheet is not None
+
+    def load(self) -> ExcelSheet:
+        """Loads the sheet, or returns the already loaded one"""
+        if self._sheet is None:
+            self._sheet = self._reader.load_sheet(self._name, **self._load_kwargs)
+        return self._sheet
+
+    @property
+    def schema(self) -> pa.Schema:
+        """The sheet's arrow schema. Loads the sheet, but does not convert its data"""
+        return self.load().schema
+
+    def to_arrow(self) -> pa.RecordBatch:
+        """Converts the sheet to a pyarrow `RecordBatch`. The conversion is only done once"""
+        if self._record_batch is None:
+            self._record_batch = self.load().to_arrow()
+        return self._record_batch
+
+    def to_pandas(self) -> "pd.DataFrame":
+        """Converts the sheet to a Pandas `DataFrame`.
+
+        Requires the `pandas` extra to be installed.
+        """
+        return _recordbatch_to_pandas(self.to_arrow())
+
+    def to_polars(self) -> "pl.DataFrame":
+        """Converts the sheet to a Polars `DataFrame`.
+
+        Requires the `polars` extra to be installed.
+        """
+        return _recordbatch_to_polars(self.to_arrow())
+
+    def __repr__(self) -> str:
+        if self._sheet is None:
+            return f"LazySheet<{self._name}, not loaded>"
+        return f"LazySheet<{self._sheet!r}>"
+
 
 class ExcelReader:
     """A class representing an open Excel file and allowing to read its sheets"""
@@ -202,6 +673,128 @@ class ExcelReader:
         """The list of sheet names"""
         return self._reader.sheet_names
 
+    @property
+    def visible_sheet_names(self) -> list[str]:
+        """The list of sheet names, without the hidden and very hidden sheets"""
+        return self._reader.visible_sheet_names
+
+    def column_names(
+        self,
+        idx_or_name: int | str,
+        *,
+        header_row: int | None = 0,
+        header_depth: int = 1,
+        header_separator: str = " / ",
+        column_prefix: str = "__UNNAMED__",
+        duplicate_columns: DuplicateColumns = "underscore",
+    ) -> list[str]:
+        """Reads the column names of a sheet, without inferring any dtype from the data.
+
+        The names are deduplicated the same way as when loading the sheet.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        :param header_row: The index of the row containing the column labels. If `None`, the
+                           sheet does not have any column labels and names are generated.
+        :param header_depth: The number of rows the header spans. See `load_sheet`.
+        :param header_separator: The separator used to join the labels of multi-row headers.
+        :param column_prefix: The prefix of the names generated for columns without a label.
+        :param duplicate_columns: How columns with the same name are given a unique name. See
+                                  `load_sheet`.
+        """
+        return self._reader.column_names(
+            idx_or_name,
+            header_row=header_row,
+            header_depth=header_depth,
+            header_separator=header_separator,
+            column_prefix=column_prefix,
+            duplicate_columns=duplicate_columns,
+        )
+
+    def uses_1904_dates(self) -> bool:
+        """Whether the workbook stores its dates as days since 1904-01-01 rather than since
+        1899-12-30.
+
+        The date system is read from the workbook once, and is used to convert the numbers of
+        `date` and `datetime` columns. Only xlsx workbooks can use the 1904 date system.
+        """
+        return self._reader.uses_1904_dates()
+
+    def shared_strings(self) -> list[str]:
+        """Reads the unique strings of the shared strings table of the workbook, in order of
+        first appearance.
+
+        The text of rich text strings is concatenated. Workbooks storing their strings inline
+        have no shared strings table, and return an empty list.
+
+        :raises InvalidParametersError: If the workbook is not an xlsx file.
+        """
+        return self._reader.shared_strings()
+
+    def load_first_sheet(self, **kwargs: Any) -> ExcelSheet:
+        """Loads the first sheet of the workbook lazily.
+
+        :param kwargs: Any parameter is forwarded to `load_sheet`.
+        :raises SheetNotFoundError: If the workbook does not contain any sheet.
+        """
+        if not self.sheet_names:
+            raise SheetNotFoundError("the workbook does not contain any sheet")
+        return self.load_sheet(0, **kwargs)
+
+    @typing.overload
+    def load_sheets(
+        self,
+        names: list[str] | None = None,
+        *,
+        skip_hidden: bool = False,
+        lazy: Literal[False] = ...,
+        **kwargs: Any,
+    ) -> dict[str, ExcelSheet]: ...
+    @typing.overload
+    def load_sheets(
+        self,
+        names: list[str] | None = None,
+        *,
+        skip_hidden: bool = False,
+        lazy: Literal[True] = ...,
+        **kwargs: Any,
+    ) -> dict[str, LazySheet]: ...
+    def load_sheets(
+        self,
+        names: list[str] | None = None,
+        *,
+        skip_hidden: bool = False,
+        lazy: bool = False,
+        **kwargs: Any,
+    ) -> dict[str, ExcelSheet] | dict[str, LazySheet]:
+        """Loads several sheets of the workbook lazily.
+
+        :param names: The names of the sheets to load. Defaults to all the sheets of the
+                      workbook. Raises a `SheetNotFoundError` if any of them is not found.
+        :param skip_hidden: Whether hidden and very hidden sheets should be skipped. Workbooks
+                            often contain scratch data in hidden sheets.
+        :param lazy: If `True`, `LazySheet`s are returned, and a sheet's data is only parsed
+                     when the sheet is first used. Useful when only some of the sheets of a large
+                     workbook are needed.
+        :param kwargs: Any other parameter is forwarded to `load_sheet` for every sheet.
+        :return: A dict mapping sheet names to sheets. Sheets are in the order of `names` if
+                 provided, in the workbook's order otherwise.
+        """
+        if names is None:
+            names = self.sheet_names
+        else:
+            missing = [name for name in names if name not in self.sheet_names]
+            if missing:
+                raise SheetNotFoundError(
+                    f"sheets not found in the workbook: {', '.join(map(repr, missing))}. "
+                    f"Available sheets: {', '.join(map(repr, self.sheet_names))}"
+                )
+        if skip_hidden:
+            visible_sheet_names = self.visible_sheet_names
+            names = [name for name in names if name in visible_sheet_names]
+        if lazy:
+            return {name: LazySheet(self, name, kwargs) for name in names}
+        return {name: self.load_sheet(name, **kwargs) for name in names}
+
     def load_sheet(
         self,
         idx_or_name: int | str,
@@ -210,10 +803,57 @@ class ExcelReader:
         column_names: list[str] | None = None,
         skip_rows: int = 0,
         n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
+        schema_sample_rows: int | float | None = 1_000,
+        sample_strategy: SampleStrategy = "head",
         dtype_coercion: Literal["coerce", "strict"] = "coerce",
         use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
         dtypes: DTypeMap | None = None,
+        timestamp_unit: TimestampUnit = "ms",
+        index_column: int | str | None = None,
+        bool_as_int8: bool = False,
+        bool_fill: bool | None = None,
+        epoch: date | None = None,
+        assume_timezone: str | tzinfo | None = None,
+        timezone: str | tzinfo | None = None,
+        na_values: list[str] | None = None,
+        empty_string_as_null: bool = False,
+        trim_strings: bool = False,
+        n_threads: int | None = None,
+        all_columns_dtype: DType | None = None,
+        infer_dates: bool = False,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        rename: dict[str, str] | None = None,
+        unknown_renames: UnknownRenames = "ignore",
+        duplicate_columns: DuplicateColumns = "underscore",
+        currency_symbols: list[str] | None = None,
+        capture_currency: bool = False,
+        tighten_nullability: bool = False,
+        decimal_separator: str | None = None,
+        thousands_separator: str | None = None,
+        fill_values: dict[str, FillValue] | None = None,
+        forward_fill: list[int | str] | None = None,
+        column_prefix: str = "__UNNAMED__",
+        auto_header: bool = False,
+        validate_header: bool = False,
+        stop_at_empty_row: bool = False,
+        header_depth: int = 1,
+        header_separator: str = " / ",
+        keep_header_in_data: bool = False,
+        data_start_row: int | None = None,
+        data_rows: list[int] | None = None,
+        expected_headers: list[str] | None = None,
+        header_search_limit: int = 100,
+        respect_autofilter: bool = False,
+        transpose: bool = False,
+        cell_range: str | None = None,
+        fill_merged_cells: bool = False,
+        max_columns: int | None = None,
+        max_rows: int | None = None,
+        max_cells: int | None = None,
+        on_size_limit: SizeLimitOverflow = "error",
+        cells: CellsMode = "values",
+        collect_warnings: bool = False,
     ) -> ExcelSheet:
         """Loads a sheet lazily by index or name.
 
@@ -229,14 +869,24 @@ class ExcelReader:
                           Any rows before the `header_row` are automatically skipped.
                           If `header_row` is `None`, it skips the number of rows from the
                           start of the sheet.
+                          Skipping more rows than the sheet has results in no data row.
         :param schema_sample_rows: Specifies how many rows should be used to determine
-                                   the dtype of a column.
+                                   the dtype of a column. A float between 0 and 1 is a
+                                   fraction of the rows that are read, rounded up, e.g. `0.1`
+                                   for 10% of them.
                                    If `None`, all rows will be used.
+        :param sample_strategy: How the rows used to determine the dtype of a column are chosen:
+                                the first `schema_sample_rows` rows (`"head"`, the default),
+                                `schema_sample_rows` rows evenly spaced over all the rows
+                                (`"spread"`), which catches dirty data at the bottom of the
+                                sheet, or all the rows (`"full"`).
         :param dtype_coercion: Specifies how type coercion should behave. `coerce` (the default)
                                will try to coerce different dtypes in a column to the same one,
                                whereas `strict` will raise an error in case a column contains
                                several dtypes. Note that this only applies to columns whose dtype
-                               is guessed, i.e. not specified via `dtypes`.
+                               is guessed, i.e. not specified via `dtypes`. Error cells such as
+                               `#DIV/0!` are read as null, unless coercion is `strict`: they are
+                               then an error in every column, including those of `dtypes`.
         :param use_columns: Specifies the columns to use. Can either be:
                             - `None` to select all columns
                             - A list of strings and ints, the column names and/or indices
@@ -245,8 +895,181 @@ class ExcelReader:
                               ranges (e.g. `“A:E”` or `“A,C,E:F”`, which would result in
                               `A,B,C,D,E` and `A,C,E,F`)
                             - A callable, a function that takes a column and returns a boolean
-                              indicating whether the column should be used
+                              indicating whether the column should be used. The column is a
+                              `ColumnInfo`, exposing its `index`, `name` and guessed `dtype`
+                              (e.g. `lambda col: col.name.startswith("metric_")`)
         :param dtypes: An optional dict of dtypes. Keys can be column indices or names
+        :param timestamp_unit: The time unit of `datetime` columns. One of `"ms"` (the default),
+                               `"us"` or `"ns"`. With `"ns"`, datetimes which cannot be
+                               represented (before 1677 or after 2262) are null.
+        :param index_column: An optional column index or name to use as the index of the frame.
+                             The column must be one of the selected columns. It is moved to the
+                             first position and flagged in the field metadata of the arrow
+                             schema, and `to_pandas` uses it as the `DataFrame`'s index.
+        :param bool_as_int8: If `True`, boolean columns are built as `int8` columns containing 0
+                             or 1 rather than as `bool` columns.
+        :param bool_fill: If set, the empty cells of boolean columns take this value instead of
+                          being null.
+        :param epoch: The origin `date` and `datetime` values are relative to. Defaults to the
+                      Unix epoch (1970-01-01). With another epoch, these columns are read as
+                      `int32` days and `int64` timestamps, with the epoch in the
+                      `fastexcel.epoch` metadata of their fields. Values too far from the epoch
+                      to be represented in the column's integer type are null, or an error if
+                      `dtype_coercion` is `"strict"`.
+        :param assume_timezone: The timezone of the naive datetimes of `"datetime_tz"` columns,
+                                e.g. when a column mixes Excel datetimes with ISO 8601 timestamps
+                                carrying a UTC offset. Either `"UTC"`, a UTC offset such as
+                                `"+02:00"`, an IANA timezone name such as `"Europe/Paris"`, a
+                                fixed-offset `datetime.timezone` or a `zoneinfo.ZoneInfo`. IANA
+                                timezones follow daylight saving time: datetimes repeated when
+                                clocks are set back are the earliest ones. Defaults to UTC.
+        :param timezone: The timezone of the timestamps of `"datetime_tz"` columns, accepting the
+                         same values as `assume_timezone`. When set, `"datetime"` columns are
+                         read as `"datetime_tz"` columns, their naive datetimes being in
+                         `assume_timezone`. Timestamps are in UTC by default.
+        :param na_values: Strings read as null in string, int and float columns, e.g. `["-"]`.
+                          They are not ignored when guessing dtypes, so a numeric column
+                          containing them should have its dtype specified via `dtypes`.
+        :param empty_string_as_null: Whether empty strings are read as null in string, int and
+                                     float columns. They are read as empty strings by default.
+        :param trim_strings: Whether leading and trailing whitespace is trimmed from the values of
+                             string columns, before `na_values` and `empty_string_as_null` are
+                             applied, so that a cell only made of spaces can be read as null.
+                             Whitespace includes tabs, line breaks and non-breaking spaces.
+        :param n_threads: The maximum number of threads building the columns of large sheets, e.g.
+                          `1` when reading many sheets from several Python threads at once.
+                          Defaults to the number of CPUs. The threads are shared by the reads
+                          using the same `n_threads`.
+        :param all_columns_dtype: The dtype of every column whose dtype is not specified via
+                                  `dtypes`, e.g. `"string"`. No dtype is guessed from the data
+                                  when set, which is the most robust way to read messy sheets.
+        :param infer_dates: Whether the `"datetime"` columns whose sampled cells are all at
+                            midnight are read as `"date"` columns. Only applies to guessed
+                            dtypes.
+        :param true_values: Strings read as `True` in `"boolean"` columns, compared
+                            case-insensitively. Defaults to `"true"`, `"yes"`, `"y"` and
+                            `"1"`.
+        :param false_values: Strings read as `False` in `"boolean"` columns, compared
+                             case-insensitively. Defaults to `"false"`, `"no"`, `"n"` and
+                             `"0"`. Other strings are null, or an error if `dtype_coercion` is
+                             `"strict"`.
+        :param rename: New names of columns, keyed by their name in the header. Columns are renamed
+                       before duplicate names are deduplicated, and `use_columns`, `dtypes` and
+                       `index_column` refer to the new names.
+        :param unknown_renames: What to do with the columns of `rename` that are not part of the
+                                header: `"ignore"` them (the default) or raise an `"error"`.
+        :param duplicate_columns: How columns with the same name are given a unique name, after
+                                  `rename` is applied: `"underscore"` names them `name`, `name_1`,
+                                  `name_2`... (the default), `"dot"` names them `name`, `name.1`,
+                                  `name.2`... as pandas does, and `"error"` raises a
+                                  `DuplicateColumnError` naming the first duplicate.
+        :param currency_symbols: Currency symbols stripped from the string cells of `"float"`
+                                 columns before they are parsed, e.g. `["$", "€"]`. A sign may
+                                 precede the symbol, as in `"-$5"`, and thousands may be
+                                 grouped with commas, or with the `thousands_separator`.
+                                 Amounts that are not a number once the symbol is stripped are
+                                 null, or an error if `dtype_coercion` is `"strict"`.
+        :param capture_currency: If `True`, the currency symbols found in a `"float"` column are
+                                 stored as a comma-separated list under the
+                                 `fastexcel.currency` key of its arrow field's metadata.
+        :param tighten_nullability: If `True`, the fields of the columns without any null among
+                                    the read rows are non-nullable in `schema`, in the schema of
+                                    `to_arrow_batches` and in `to_arrow`, so that all of them
+                                    agree. This requires a scan of the selected columns.
+        :param decimal_separator: The decimal separator of the numbers found in string cells,
+                                  which are parsed in `"float"` and `"int"` columns, e.g. `","`
+                                  for `"1234,5"`. Defaults to `"."`.
+        :param thousands_separator: The separator grouping the thousands of the numbers found in
+                                    string cells, e.g. `"."` for `"1.234,5"`. Thousands must be
+                                    grouped by three. It must differ from the decimal separator,
+                                    and neither can be a digit.
+        :param fill_values: Values replacing the nulls of columns, by column name. Empty cells and
+                            cells that cannot be converted to the column's dtype are filled.
+                            The value must match the dtype of its column, e.g. `0.0` or `0` for
+                            a float column, `""` for a string column. Ints can fill float
+                            columns, and timezone-aware datetimes fill `datetime_tz` columns.
+        :param forward_fill: Columns whose nulls take the last non-null value above them, by index
+                             or name, e.g. a grouping column only filled on the first row of each
+                             group. Nulls before the first value of a column stay null. Applied
+                             once the cells are converted to the column's dtype, and cannot be
+                             combined with a fill value for the same column.
+        :param column_prefix: The prefix of the names generated for columns without a label,
+                              followed by the column's index: `"__UNNAMED__0"` by default, or
+                              `"col_0"` with `column_prefix="col_"`. Generated names are
+                              deduplicated along with the others, should they collide with a
+                              label or a renamed column.
+        :param auto_header: If `True`, the header row is detected by looking for the first row
+                            mostly made of strings and followed by a row with other types of
+                            values. `header_row` is ignored. If no header row is detected, the
+                            first row is used and a warning is emitted. The detected row is
+                            available through `ExcelSheet.header_row`.
+        :param validate_header: If `True`, the type of each header cell is compared with the type
+                                of its column. Suspicious combinations, which often mean that the
+                                header row was misidentified, emit a warning and are available
+                                through `ExcelSheet.header_warnings`.
+        :param stop_at_empty_row: If `True`, reading stops at the first row in which all cells
+                                  are empty, after the header and `skip_rows`. This is useful for
+                                  sheets in which the data is followed by notes.
+        :param header_depth: The number of rows the header spans, starting at `header_row`.
+                             The labels of each column are joined with `header_separator`.
+                             Empty cells of the upper header rows are filled with the label on
+                             their left, so that merged group headers apply to all their columns.
+        :param header_separator: The separator used to join the labels of multi-row headers.
+        :param keep_header_in_data: If `True`, the header row is also read as the first row of
+                                    the data. Its labels usually make the column dtypes be
+                                    guessed as strings.
+        :param data_start_row: The index of the row at which the data starts, for layouts in
+                               which rows separate the header from the data. Like
+                               `header_row`, indices start at the first row of the sheet, and
+                               it must be greater than `header_row`. `skip_rows` applies after
+                               it. Cannot be used with `keep_header_in_data` or `data_rows`.
+        :param data_rows: The indices of the rows to read as data, in order. Like `header_row`,
+                          indices start at the first row of the sheet, and header rows cannot
+                          be selected. `skip_rows` and `n_rows` apply to the selected rows.
+        :param expected_headers: If set, the header row is the first row containing all of these
+                                 names, and `header_row` is ignored. Useful when the number of
+                                 rows above the header varies. Cannot be used with
+                                 `auto_header` or `column_names`.
+        :param header_search_limit: The number of rows in which `expected_headers` are looked
+                                    for. An error is raised if no header row is found.
+        :param respect_autofilter: If `True`, the rows hidden by the sheet's autofilter are not
+                                   read, as when viewing the filtered sheet in Excel. Rows hidden
+                                   in a sheet without an autofilter are still read. Only
+                                   supported for xlsx files.
+        :param transpose: If `True`, the rows of the sheet are read as columns and its columns as
+                          rows, for sheets laying variables out in rows. All the other options,
+                          such as `header_row`, apply to the transposed sheet. Cannot be used with
+                          `respect_autofilter`.
+        :param cell_range: An A1-style range of cells, e.g. `"B2:F100"`, read instead of the whole
+                           sheet, for tables surrounded by titles or notes. All the other options,
+                           such as `header_row`, apply to the cells of the range: by default, its
+                           first row is the header.
+        :param fill_merged_cells: If `True`, the value of merged cells is read in every cell of the
+                                  merged region, instead of only its top-left cell, e.g. for
+                                  categories merged over several rows. Merged header cells are
+                                  then repeated, and deduplicated as other column names. Only
+                                  supported for xlsx files: a warning is emitted for other
+                                  formats, whose merged cells are left blank.
+        :param max_columns: The maximum number of columns of the sheet, to bound the resources
+                            used to read untrusted files. Not checked if `None`.
+        :param max_rows: The maximum number of data rows read from the sheet, after `skip_rows`
+                         and `n_rows` are applied. Not checked if `None`.
+        :param max_cells: The maximum number of cells of the sheet, counted over its used range:
+                          the smallest rectangle containing all of its non-empty cells. Raises a
+                          `ResourceLimitError` when exceeded, whatever `on_size_limit`. Not
+                          checked if `None`.
+        :param on_size_limit: What to do with a sheet exceeding `max_columns` or `max_rows`:
+                              raise a `SizeLimitExceededError` (`"error"`), or only read its first
+                              columns and rows (`"truncate"`).
+        :param cells: What is read from the cells: their computed value (`"values"`), or their
+                      computed value and their formula (`"value_and_formula"`). In the latter
+                      case, every selected column is followed by a `<name>__formula` string
+                      column, null for the cells without a formula. Cannot be used with
+                      `transpose`, `respect_autofilter` or `data_rows`.
+        :param collect_warnings: If `True`, what silently changes the data while reading the sheet
+                                 is recorded in `ExcelSheet.read_warnings`: columns of mixed
+                                 types coerced to a single dtype, and cells that could not be
+                                 read as their column's dtype and are null.
         """
         return ExcelSheet(
             self._reader.load_sheet(
@@ -256,9 +1079,56 @@ class ExcelReader:
                 skip_rows=skip_rows,
                 n_rows=n_rows,
                 schema_sample_rows=schema_sample_rows,
+                sample_strategy=sample_strategy,
                 dtype_coercion=dtype_coercion,
                 use_columns=use_columns,
                 dtypes=dtypes,
+                timestamp_unit=timestamp_unit,
+                index_column=index_column,
+                bool_as_int8=bool_as_int8,
+                bool_fill=bool_fill,
+                epoch=epoch,
+                assume_timezone=assume_timezone,
+                timezone=timezone,
+                na_values=na_values,
+                empty_string_as_null=empty_string_as_null,
+                trim_strings=trim_strings,
+                n_threads=n_threads,
+                all_columns_dtype=all_columns_dtype,
+                infer_dates=infer_dates,
+                true_values=true_values,
+                false_values=false_values,
+                rename=rename,
+                unknown_renames=unknown_renames,
+                duplicate_columns=duplicate_columns,
+                currency_symbols=currency_symbols,
+                capture_currency=capture_currency,
+                tighten_nullability=tighten_nullability,
+                decimal_separator=decimal_separator,
+                thousands_separator=thousands_separator,
+                fill_values=fill_values,
+                forward_fill=forward_fill,
+                column_prefix=column_prefix,
+                auto_header=auto_header,
+                validate_header=validate_header,
+                stop_at_empty_row=stop_at_empty_row,
+                header_depth=header_depth,
+                header_separator=header_separator,
+                keep_header_in_data=keep_header_in_data,
+                data_start_row=data_start_row,
+                data_rows=data_rows,
+                expected_headers=expected_headers,
+                header_search_limit=header_search_limit,
+                respect_autofilter=respect_autofilter,
+                transpose=transpose,
+                cell_range=cell_range,
+                fill_merged_cells=fill_merged_cells,
+                max_columns=max_columns,
+                max_rows=max_rows,
+                max_cells=max_cells,
+                on_size_limit=on_size_limit,
+                cells=cells,
+                collect_warnings=collect_warnings,
                 eager=False,
             )
         )
@@ -266,13 +1136,49 @@ class ExcelReader:
     def table_names(self, sheet_idx_or_name: str | int | None = None) -> list[str]:
         """The list of table names.
 
-        Will return an empty list if no tables are found.
+        Will return an empty list if no tables are found. Only XLSX files have tables, an
+        `InvalidParametersError` naming the format of the file is raised for other formats.
 
         :param sheet_idx_or_name: If given, will limit the list to the given sheet, will be faster
-        too.
+        too. Raises a `SheetNotFoundError` listing the available sheets if it is not found.
         """
         return self._reader.table_names(sheet_idx_or_name)
 
+    def tables(self, sheet_name: str | None = None) -> list[tuple[str, str, str]]:
+        """The tables of the workbook, with the sheet they belong to and their data range.
+
+        Only XLSX files have tables, see `table_names`. Listing the ranges requires reading the
+        sheets containing tables.
+
+        :param sheet_name: If given, will limit the list to the tables of the given sheet. Raises a
+                           `SheetNotFoundError` listing the available sheets if it is not found.
+        :return: A list of `(table name, sheet name, data range)` tuples. The data range is an
+                 Excel cell range (e.g. `"A2:F100"`), which excludes the header and totals rows.
+        """
+        return self._reader.tables(sheet_name)
+
+    def defined_names(self) -> list[tuple[str, str]]:
+        """The defined names (named ranges) of the workbook.
+
+        :return: A list of `(name, reference)` tuples, e.g. `("SalesData", "Sheet1!$A$1:$D$50")`.
+                 Defined names may also refer to constants or formulas, which cannot be loaded.
+        """
+        return self._reader.defined_names()
+
+    def load_defined_name(self, name: str, **kwargs: Any) -> ExcelSheet:
+        """Loads the cells a defined name refers to lazily, as a sheet.
+
+        :param name: The name, as listed by `defined_names`.
+        :param kwargs: Any other parameter is forwarded to `load_sheet`. The cells are selected
+                       with its `cell_range` parameter, `header_row` refers to the first row of
+                       the defined name.
+        :raises InvalidParametersError: If the name is not defined, or does not refer to a single
+                                        range of cells of a sheet of the workbook, e.g. a
+                                        constant, several sheets or another workbook.
+        """
+        sheet_name, cell_range = self._reader.defined_name_range(name)
+        return self.load_sheet(sheet_name, cell_range=cell_range, **kwargs)
+
     @typing.overload
     def load_table(
         self,
@@ -282,10 +1188,37 @@ class ExcelReader:
         column_names: list[str] | None = None,
         skip_rows: int = 0,
         n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
+        schema_sample_rows: int | float | None = 1_000,
+        sample_strategy: SampleStrategy = "head",
         dtype_coercion: Literal["coerce", "strict"] = "coerce",
         use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
         dtypes: DTypeMap | None = None,
+        timestamp_unit: TimestampUnit = "ms",
+        index_column: int | str | None = None,
+        bool_as_int8: bool = False,
+        bool_fill: bool | None = None,
+        epoch: date | None = None,
+        assume_timezone: str | tzinfo | None = None,
+        timezone: str | tzinfo | None = None,
+        na_values: list[str] | None = None,
+        empty_string_as_null: bool = False,
+        trim_strings: bool = False,
+        n_threads: int | None = None,
+        all_columns_dtype: DType | None = None,
+        infer_dates: bool = False,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        rename: dict[str, str] | None = None,
+        unknown_renames: UnknownRenames = "ignore",
+        duplicate_columns: DuplicateColumns = "underscore",
+        currency_symbols: list[str] | None = None,
+        capture_currency: bool = False,
+        tighten_nullability: bool = False,
+        decimal_separator: str | None = None,
+        thousands_separator: str | None = None,
+        fill_values: dict[str, FillValue] | None = None,
+        forward_fill: list[int | str] | None = None,
+        table_has_header: bool = True,
         eager: Literal[False] = ...,
     ) -> ExcelTable: ...
     @typing.overload
@@ -297,10 +1230,37 @@ class ExcelReader:
         column_names: list[str] | None = None,
         skip_rows: int = 0,
         n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
+        schema_sample_rows: int | float | None = 1_000,
+        sample_strategy: SampleStrategy = "head",
         dtype_coercion: Literal["coerce", "strict"] = "coerce",
         use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
         dtypes: DTypeMap | None = None,
+        timestamp_unit: TimestampUnit = "ms",
+        index_column: int | str | None = None,
+        bool_as_int8: bool = False,
+        bool_fill: bool | None = None,
+        epoch: date | None = None,
+        assume_timezone: str | tzinfo | None = None,
+        timezone: str | tzinfo | None = None,
+        na_values: list[str] | None = None,
+        empty_string_as_null: bool = False,
+        trim_strings: bool = False,
+        n_threads: int | None = None,
+        all_columns_dtype: DType | None = None,
+        infer_dates: bool = False,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        rename: dict[str, str] | None = None,
+        unknown_renames: UnknownRenames = "ignore",
+        duplicate_columns: DuplicateColumns = "underscore",
+        currency_symbols: list[str] | None = None,
+        capture_currency: bool = False,
+        tighten_nullability: bool = False,
+        decimal_separator: str | None = None,
+        thousands_separator: str | None = None,
+        fill_values: dict[str, FillValue] | None = None,
+        forward_fill: list[int | str] | None = None,
+        table_has_header: bool = True,
         eager: Literal[True] = ...,
     ) -> pa.RecordBatch: ...
     def load_table(
@@ -311,10 +1271,37 @@ class ExcelReader:
         column_names: list[str] | None = None,
         skip_rows: int = 0,
         n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
+        schema_sample_rows: int | float | None = 1_000,
+        sample_strategy: SampleStrategy = "head",
         dtype_coercion: Literal["coerce", "strict"] = "coerce",
         use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
         dtypes: DTypeMap | None = None,
+        timestamp_unit: TimestampUnit = "ms",
+        index_column: int | str | None = None,
+        bool_as_int8: bool = False,
+        bool_fill: bool | None = None,
+        epoch: date | None = None,
+        assume_timezone: str | tzinfo | None = None,
+        timezone: str | tzinfo | None = None,
+        na_values: list[str] | None = None,
+        empty_string_as_null: bool = False,
+        trim_strings: bool = False,
+        n_threads: int | None = None,
+        all_columns_dtype: DType | None = None,
+        infer_dates: bool = False,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        rename: dict[str, str] | None = None,
+        unknown_renames: UnknownRenames = "ignore",
+        duplicate_columns: DuplicateColumns = "underscore",
+        currency_symbols: list[str] | None = None,
+        capture_currency: bool = False,
+        tighten_nullability: bool = False,
+        decimal_separator: str | None = None,
+        thousands_separator: str | None = None,
+        fill_values: dict[str, FillValue] | None = None,
+        forward_fill: list[int | str] | None = None,
+        table_has_header: bool = True,
         eager: bool = False,
     ) -> ExcelTable | pa.RecordBatch:
         """Loads a table by name.
@@ -331,14 +1318,24 @@ class ExcelReader:
                           Any rows before the `header_row` are automatically skipped.
                           If `header_row` is `None`, it skips the number of rows from the
                           start of the sheet.
+                          Skipping more rows than the sheet has results in no data row.
         :param schema_sample_rows: Specifies how many rows should be used to determine
-                                   the dtype of a column.
+                                   the dtype of a column. A float between 0 and 1 is a
+                                   fraction of the rows that are read, rounded up, e.g. `0.1`
+                                   for 10% of them.
                                    If `None`, all rows will be used.
+        :param sample_strategy: How the rows used to determine the dtype of a column are chosen:
+                                the first `schema_sample_rows` rows (`"head"`, the default),
+                                `schema_sample_rows` rows evenly spaced over all the rows
+                                (`"spread"`), which catches dirty data at the bottom of the
+                                sheet, or all the rows (`"full"`).
         :param dtype_coercion: Specifies how type coercion should behave. `coerce` (the default)
                                will try to coerce different dtypes in a column to the same one,
                                whereas `strict` will raise an error in case a column contains
                                several dtypes. Note that this only applies to columns whose dtype
-                               is guessed, i.e. not specified via `dtypes`.
+                               is guessed, i.e. not specified via `dtypes`. Error cells such as
+                               `#DIV/0!` are read as null, unless coercion is `strict`: they are
+                               then an error in every column, including those of `dtypes`.
         :param use_columns: Specifies the columns to use. Can either be:
                             - `None` to select all columns
                             - A list of strings and ints, the column names and/or indices
@@ -347,8 +1344,109 @@ class ExcelReader:
                               ranges (e.g. `“A:E”` or `“A,C,E:F”`, which would result in
                               `A,B,C,D,E` and `A,C,E,F`)
                             - A callable, a function that takes a column and returns a boolean
-                              indicating whether the column should be used
+                              indicating whether the column should be used. The column is a
+                              `ColumnInfo`, exposing its `index`, `name` and guessed `dtype`
+                              (e.g. `lambda col: col.name.startswith("metric_")`)
         :param dtypes: An optional dict of dtypes. Keys can be column indices or names
+        :param timestamp_unit: The time unit of `datetime` columns. One of `"ms"` (the default),
+                               `"us"` or `"ns"`. With `"ns"`, datetimes which cannot be
+                               represented (before 1677 or after 2262) are null.
+        :param index_column: An optional column index or name to use as the index of the frame.
+                             The column must be one of the selected columns. It is moved to the
+                             first position and flagged in the field metadata of the arrow
+                             schema, and `to_pandas` uses it as the `DataFrame`'s index.
+        :param bool_as_int8: If `True`, boolean columns are built as `int8` columns containing 0
+                             or 1 rather than as `bool` columns.
+        :param bool_fill: If set, the empty cells of boolean columns take this value instead of
+                          being null.
+        :param epoch: The origin `date` and `datetime` values are relative to. Defaults to the
+                      Unix epoch (1970-01-01). With another epoch, these columns are read as
+                      `int32` days and `int64` timestamps, with the epoch in the
+                      `fastexcel.epoch` metadata of their fields. Values too far from the epoch
+                      to be represented in the column's integer type are null, or an error if
+                      `dtype_coercion` is `"strict"`.
+        :param assume_timezone: The timezone of the naive datetimes of `"datetime_tz"` columns,
+                                e.g. when a column mixes Excel datetimes with ISO 8601 timestamps
+                                carrying a UTC offset. Either `"UTC"`, a UTC offset such as
+                                `"+02:00"`, an IANA timezone name such as `"Europe/Paris"`, a
+                                fixed-offset `datetime.timezone` or a `zoneinfo.ZoneInfo`. IANA
+                                timezones follow daylight saving time: datetimes repeated when
+                                clocks are set back are the earliest ones. Defaults to UTC.
+        :param timezone: The timezone of the timestamps of `"datetime_tz"` columns, accepting the
+                         same values as `assume_timezone`. When set, `"datetime"` columns are
+                         read as `"datetime_tz"` columns, their naive datetimes being in
+                         `assume_timezone`. Timestamps are in UTC by default.
+        :param na_values: Strings read as null in string, int and float columns, e.g. `["-"]`.
+                          They are not ignored when guessing dtypes, so a numeric column
+                          containing them should have its dtype specified via `dtypes`.
+        :param empty_string_as_null: Whether empty strings are read as null in string, int and
+                                     float columns. They are read as empty strings by default.
+        :param trim_strings: Whether leading and trailing whitespace is trimmed from the values of
+                             string columns, before `na_values` and `empty_string_as_null` are
+                             applied, so that a cell only made of spaces can be read as null.
+                             Whitespace includes tabs, line breaks and non-breaking spaces.
+        :param n_threads: The maximum number of threads building the columns of large sheets, e.g.
+                          `1` when reading many sheets from several Python threads at once.
+                          Defaults to the number of CPUs. The threads are shared by the reads
+                          using the same `n_threads`.
+        :param all_columns_dtype: The dtype of every column whose dtype is not specified via
+                                  `dtypes`, e.g. `"string"`. No dtype is guessed from the data
+                                  when set, which is the most robust way to read messy sheets.
+        :param infer_dates: Whether the `"datetime"` columns whose sampled cells are all at
+                            midnight are read as `"date"` columns. Only applies to guessed
+                            dtypes.
+        :param true_values: Strings read as `True` in `"boolean"` columns, compared
+                            case-insensitively. Defaults to `"true"`, `"yes"`, `"y"` and
+                            `"1"`.
+        :param false_values: Strings read as `False` in `"boolean"` columns, compared
+                             case-insensitively. Defaults to `"false"`, `"no"`, `"n"` and
+                             `"0"`. Other strings are null, or an error if `dtype_coercion` is
+                             `"strict"`.
+        :param rename: New names of columns, keyed by their name in the header. Columns are renamed
+                       before duplicate names are deduplicated, and `use_columns`, `dtypes` and
+                       `index_column` refer to the new names.
+        :param unknown_renames: What to do with the columns of `rename` that are not part of the
+                                header: `"ignore"` them (the default) or raise an `"error"`.
+        :param duplicate_columns: How columns with the same name are given a unique name, after
+                                  `rename` is applied: `"underscore"` names them `name`, `name_1`,
+                                  `name_2`... (the default), `"dot"` names them `name`, `name.1`,
+                                  `name.2`... as pandas does, and `"error"` raises a
+                                  `DuplicateColumnError` naming the first duplicate.
+        :param currency_symbols: Currency symbols stripped from the string cells of `"float"`
+                                 columns before they are parsed, e.g. `["$", "€"]`. A sign may
+                                 precede the symbol, as in `"-$5"`, and thousands may be
+                                 grouped with commas, or with the `thousands_separator`.
+                                 Amounts that are not a number once the symbol is stripped are
+                                 null, or an error if `dtype_coercion` is `"strict"`.
+        :param capture_currency: If `True`, the currency symbols found in a `"float"` column are
+                                 stored as a comma-separated list under the
+                                 `fastexcel.currency` key of its arrow field's metadata.
+        :param tighten_nullability: If `True`, the fields of the columns without any null among
+                                    the read rows are non-nullable in `schema`, in the schema of
+                                    `to_arrow_batches` and in `to_arrow`, so that all of them
+                                    agree. This requires a scan of the selected columns.
+        :param decimal_separator: The decimal separator of the numbers found in string cells,
+                                  which are parsed in `"float"` and `"int"` columns, e.g. `","`
+                                  for `"1234,5"`. Defaults to `"."`.
+        :param thousands_separator: The separator grouping the thousands of the numbers found in
+                                    string cells, e.g. `"."` for `"1.234,5"`. Thousands must be
+                                    grouped by three. It must differ from the decimal separator,
+                                    and neither can be a digit.
+        :param fill_values: Values replacing the nulls of columns, by column name. Empty cells and
+                            cells that cannot be converted to the column's dtype are filled.
+                            The value must match the dtype of its column, e.g. `0.0` or `0` for
+                            a float column, `""` for a string column. Ints can fill float
+                            columns, and timezone-aware datetimes fill `datetime_tz` columns.
+        :param forward_fill: Columns whose nulls take the last non-null value above them, by index
+                             or name, e.g. a grouping column only filled on the first row of each
+                             group. Nulls before the first value of a column stay null. Applied
+                             once the cells are converted to the column's dtype, and cannot be
+                             combined with a fill value for the same column.
+        :param table_has_header: Whether the table has header rows. If `False`, the table's
+                                 header rows are read as data rows, and the columns are named
+                                 `__UNNAMED__0`, `__UNNAMED__1`... unless `column_names` or
+                                 `header_row` is set. Tables defined without a header row get
+                                 generated names as well.
         """
         output = self._reader.load_table(  # type:ignore[call-overload,misc]
             name=name,
@@ -357,27 +1455,123 @@ class ExcelReader:
             skip_rows=skip_rows,
             n_rows=n_rows,
             schema_sample_rows=schema_sample_rows,
+            sample_strategy=sample_strategy,
             dtype_coercion=dtype_coercion,
             use_columns=use_columns,
             dtypes=dtypes,
+            timestamp_unit=timestamp_unit,
+            index_column=index_column,
+            bool_as_int8=bool_as_int8,
+            bool_fill=bool_fill,
+            epoch=epoch,
+            assume_timezone=assume_timezone,
+            timezone=timezone,
+            na_values=na_values,
+            empty_string_as_null=empty_string_as_null,
+            trim_strings=trim_strings,
+            n_threads=n_threads,
+            all_columns_dtype=all_columns_dtype,
+            infer_dates=infer_dates,
+            true_values=true_values,
+            false_values=false_values,
+            rename=rename,
+            unknown_renames=unknown_renames,
+            duplicate_columns=duplicate_columns,
+            currency_symbols=currency_symbols,
+            capture_currency=capture_currency,
+            tighten_nullability=tighten_nullability,
+            decimal_separator=decimal_separator,
+            thousands_separator=thousands_separator,
+            fill_values=fill_values,
+            forward_fill=forward_fill,
+            table_has_header=table_has_header,
             eager=eager,
         )
         if eager:
             return output
         return ExcelTable(output)
 
-    def load_sheet_eager(
+    def sample(self, idx_or_name: int | str, n: int = 10, **kwargs: Any) -> pa.RecordBatch:
+        """Reads the first rows of a sheet eagerly, for previews.
+
+        The dtypes are inferred from the returned rows only, so the schema matches the one of a
+        full read using `schema_sample_rows=n`.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet to sample.
+        :param n: The number of data rows to read.
+        :param kwargs: Any other parameter is forwarded to `load_sheet_eager`.
+        """
+        return self.load_sheet_eager(idx_or_name, n_rows=n, schema_sample_rows=n, **kwargs)
+
+    def load_sheet_with_schema(
         self,
         idx_or_name: int | str,
+        schema: pa.Schema,
         *,
-        header_row: int | None = 0,
-        column_names: list[str] | None = None,
-        skip_rows: int = 0,
-        n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | None = None,
-        dtypes: DTypeMap | None = None,
+        missing_columns: MissingColumns = "error",
+        **kwargs: Any,
+    ) -> pa.RecordBatch:
+        """Reads only the columns of a sheet named in a schema, with the schema's types.
+
+        The other columns of the sheet are neither read nor returned. Every column is read with
+        the dtype matching its type in the schema, then cast to that type. The resulting batch
+        contains exactly the schema's columns, in the schema's order, all of them nullable.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        :param schema: The schema of the columns to read.
+        :param missing_columns: What to do with the schema's columns that are not in the sheet:
+                                raise a `ColumnNotFoundError` (`"error"`), or fill them with
+                                nulls of the schema's type (`"null"`).
+        :param kwargs: Any other parameter is forwarded to `load_sheet`, except `use_columns`
+                       and `dtypes` which are set from the schema.
+        """
+        if missing_columns not in ("error", "null"):
+            raise InvalidParametersError(
+                f'expected missing_columns to be "error" or "null", got {missing_columns!r}'
+            )
+        dtypes: DTypeMap = {}
+        for field in schema:
+            dtype = _dtype_for_arrow_type(field.type)
+            if dtype is not None:
+                dtypes[field.name] = dtype
+
+        # The schema may have columns the sheet does not have, whose dtypes are ignored
+        sheet = ExcelSheet(
+            self._reader.load_sheet(
+                idx_or_name,
+                use_columns=lambda column: column.name in schema.names,
+                dtypes=dtypes or None,
+                ignore_unknown_dtypes=True,
+                eager=False,
+                **kwargs,
+            )
+        )
+        if missing_columns == "error":
+            selected_names = {column.name for column in sheet.selected_columns}
+            missing_names = [name for name in schema.names if name not in selected_names]
+            if missing_names:
+                raise ColumnNotFoundError(
+                    f"columns {missing_names} of the schema not found in sheet \"{sheet.name}\""
+                )
+        return sheet.to_arrow_with_schema(schema)
+
+    def load_sheet_as_strings(self, idx_or_name: int | str, **kwargs: Any) -> pa.RecordBatch:
+        """Reads every cell of a sheet as a string, as a last resort for unstructured sheets.
+
+        No row is used as a header: the columns are named after their position, prefixed with
+        `column_prefix`. Since every column is a string column, reading never fails because of
+        the type of a cell. Empty and error cells are null.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        :param kwargs: Any other parameter is forwarded to `load_sheet_eager`, except
+                       `header_row`, `dtypes` and `all_columns_dtype` which are set by this mode.
+        """
+        return self.load_sheet_eager(
+            idx_or_name, header_row=None, all_columns_dtype="string", **kwargs
+        )
+
+    def load_sheet_eager(
+        self, idx_or_name: int | str, **kwargs: Unpack[LoadSheetOptions]
     ) -> pa.RecordBatch:
         """Loads a sheet eagerly by index or name.
 
@@ -386,76 +1580,21 @@ class ExcelReader:
 
         Refer to `load_sheet` for parameter documentation
         """
-        return self._reader.load_sheet(
-            idx_or_name=idx_or_name,
-            header_row=header_row,
-            column_names=column_names,
-            skip_rows=skip_rows,
-            n_rows=n_rows,
-            schema_sample_rows=schema_sample_rows,
-            dtype_coercion=dtype_coercion,
-            use_columns=use_columns,
-            dtypes=dtypes,
-            eager=True,
-        )
+        return self._reader.load_sheet(idx_or_name, **kwargs, eager=True)
 
-    def load_sheet_by_name(
-        self,
-        name: str,
-        *,
-        header_row: int | None = 0,
-        column_names: list[str] | None = None,
-        skip_rows: int = 0,
-        n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
-        dtypes: DTypeMap | None = None,
-    ) -> ExcelSheet:
+    def load_sheet_by_name(self, name: str, **kwargs: Unpack[LoadSheetOptions]) -> ExcelSheet:
         """Loads a sheet by name.
 
         Refer to `load_sheet` for parameter documentation
         """
-        return self.load_sheet(
-            name,
-            header_row=header_row,
-            column_names=column_names,
-            skip_rows=skip_rows,
-            n_rows=n_rows,
-            schema_sample_rows=schema_sample_rows,
-            dtype_coercion=dtype_coercion,
-            use_columns=use_columns,
-            dtypes=dtypes,
-        )
+        return self.load_sheet(name, **kwargs)
 
-    def load_sheet_by_idx(
-        self,
-        idx: int,
-        *,
-        header_row: int | None = 0,
-        column_names: list[str] | None = None,
-        skip_rows: int = 0,
-        n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
-        dtypes: DTypeMap | None = None,
-    ) -> ExcelSheet:
+    def load_sheet_by_idx(self, idx: int, **kwargs: Unpack[LoadSheetOptions]) -> ExcelSheet:
         """Loads a sheet by index.
 
         Refer to `load_sheet` for parameter documentation
         """
-        return self.load_sheet(
-            idx,
-            header_row=header_row,
-            column_names=column_names,
-            skip_rows=skip_rows,
-            n_rows=n_rows,
-            schema_sample_rows=schema_sample_rows,
-            dtype_coercion=dtype_coercion,
-            use_columns=use_columns,
-            dtypes=dtypes,
-        )
+        return self.load_sheet(idx, **kwargs)
 
     def __repr__(self) -> str:
         return self._reader.__repr__()
@@ -471,24 +1610,169 @@ def read_excel(source: Path | str | bytes) -> ExcelReader:
     return ExcelReader(_read_excel(source))
 
 
+def _expand_sources(sources: Iterable[Path | str]) -> list[str]:
+    paths: list[str] = []
+    for source in sources:
+        source = expanduser(source)
+        if any(char in source for char in "*?["):
+            matches = sorted(glob(source))
+            if not matches:
+                raise InvalidParametersError(f"pattern {source!r} did not match any file")
+            paths.extend(matches)
+        else:
+            paths.append(source)
+    return paths
+
+
+def read_many(
+    sources: Iterable[Path | str],
+    sheet_idx_or_name: int | str = 0,
+    *,
+    source_column: str | None = None,
+    **kwargs: Any,
+) -> pa.RecordBatch:
+    """Reads the same sheet from several files and concatenates them in a single `RecordBatch`.
+
+    Columns are aligned by name. Columns missing from some of the files are filled with nulls,
+    and an `UnsupportedColumnTypeCombinationError` is raised if a column has different dtypes in
+    different files.
+
+    :param sources: The paths to the files to read. Glob patterns (e.g. `"2023-*.xlsx"`) are
+                    expanded and their matches are sorted alphabetically.
+    :param sheet_idx_or_name: The index (starting at 0) or the name of the sheet to load in every
+                              file.
+    :param source_column: If given, a column with that name is added, containing the path of the
+                          file every row was read from.
+    :param kwargs: Any other parameter is forwarded to `ExcelReader.load_sheet_eager`.
+    """
+    paths = _expand_sources(sources)
+    if not paths:
+        raise InvalidParametersError("no source to read from")
+    batches = [read_excel(path).load_sheet_eager(sheet_idx_or_name, **kwargs) for path in paths]
+    return _concat_record_batches(batches, sources=paths, source_column=source_column)
+
+
+def read_workbook_as_table(
+    source: Path | str | bytes,
+    *,
+    sheet_names: list[str] | None = None,
+    skip_hidden: bool = False,
+    sheet_column: str | None = "sheet",
+    promote_types: bool = False,
+    **kwargs: Any,
+) -> pa.RecordBatch:
+    """Reads the sheets of a workbook and stacks them in a single `RecordBatch`.
+
+    Columns are aligned by name, in order of first appearance, and columns missing from some of
+    the sheets are filled with nulls for their rows. A column with different dtypes in different
+    sheets raises an `UnsupportedColumnTypeCombinationError`, unless `promote_types` is set.
+
+    :param source: The path to the workbook, or its content as bytes.
+    :param sheet_names: The names of the sheets to read. Defaults to all the sheets of the
+                        workbook, in the workbook's order.
+    :param skip_hidden: Whether hidden and very hidden sheets should be skipped.
+    :param sheet_column: The name of the column added to the result, containing the name of the
+                         sheet every row was read from. No column is added if `None`.
+    :param promote_types: If `True`, columns with different dtypes in different sheets are
+                          promoted to a common dtype, following the rules of `concat_sheets`.
+    :param kwargs: Any other parameter is forwarded to `ExcelReader.load_sheet` for every sheet.
+    """
+    sheets = read_excel(source).load_sheets(sheet_names, skip_hidden=skip_hidden, **kwargs)
+    if not sheets:
+        raise InvalidParametersError("no sheet to read")
+    batches = [sheet.to_arrow() for sheet in sheets.values()]
+    return _concat_record_batches(
+        batches,
+        sources=list(sheets),
+        source_column=sheet_column,
+        promote_types=promote_types,
+    )
+
+
+def concat_sheets(sheets: Iterable[ExcelSheet | ExcelTable]) -> pa.RecordBatch:
+    """Concatenates several sheets or tables in a single `RecordBatch`.
+
+    The resulting schema is the union of the schemas of all sheets: columns are aligned by name,
+    in order of first appearance, and columns missing from a sheet are filled with nulls for that
+    sheet's rows. When a column has different dtypes in different sheets, it is promoted to a
+    common dtype according to the following rules:
+
+    - a `null` column takes the dtype of the other column
+    - `int` and `float` are promoted to `float`
+    - any other combination of dtypes is promoted to `string`
+
+    :param sheets: The sheets or tables to concatenate.
+    """
+    batches = [sheet.to_arrow() for sheet in sheets]
+    if not batches:
+        raise InvalidParametersError("no sheet to concatenate")
+    return _concat_record_batches(batches, promote_types=True)
+
+
+def _to_record_batch(data: pa.RecordBatch | pa.Table) -> pa.RecordBatch:
+    if isinstance(data, pa.RecordBatch):
+        return data
+    # Combining the chunks results in a single batch, unless the table is empty
+    batches = data.combine_chunks().to_batches()
+    return batches[0] if batches else pa.RecordBatch.from_pylist([], schema=data.schema)
+
+
+def merge_record_batches(
+    left: pa.RecordBatch | pa.Table, right: pa.RecordBatch | pa.Table
+) -> tuple[pa.RecordBatch, list[TypePromotion]]:
+    """Concatenates two batches or tables, e.g. read separately from different sheets.
+
+    Columns are aligned by name, in order of first appearance, and columns missing from one of
+    them are filled with nulls of the column's type. Columns with different types are promoted
+    following the rules of `concat_sheets`.
+
+    Returns the concatenated `RecordBatch`, along with a `TypePromotion` for every column whose
+    type was changed. Its `batch_index` is 0 for `left` and 1 for `right`.
+    """
+    return _merge_record_batches(_to_record_batch(left), _to_record_batch(right))
+
+
 __all__ = (
     ## version
     "__version__",
     ## main entrypoint
     "read_excel",
+    "read_many",
+    "read_workbook_as_table",
+    "concat_sheets",
+    "merge_record_batches",
     ## Python types
     "DType",
     "DTypeMap",
+    "ParquetCompression",
+    "ExtraColumns",
+    "MissingColumns",
+    "UnknownRenames",
+    "DuplicateColumns",
+    "SampleStrategy",
+    "SizeLimitOverflow",
+    "CellsMode",
+    "FillValue",
+    "TimestampUnit",
+    "LoadSheetOptions",
     # Excel reader
     "ExcelReader",
     # Excel sheet
     "ExcelSheet",
+    "LazySheet",
     # Column metadata
     "DTypeFrom",
     "ColumnNameFrom",
     "ColumnInfo",
+    "HeaderWarning",
+    "ReadWarning",
+    "ColumnStats",
+    "TypePromotion",
+    "ArrowColumn",
     # Exceptions
     "FastExcelError",
+    "CellError",
+    "SchemaError",
     "CannotRetrieveCellDataError",
     "CalamineCellError",
     "CalamineError",
@@ -496,5 +1780,12 @@ __all__ = (
     "ColumnNotFoundError",
     "ArrowError",
     "InvalidParametersError",
+    "InvalidDtypeError",
+    "UnknownColumnError",
     "UnsupportedColumnTypeCombinationError",
+    "CorruptFileError",
+    "SizeLimitExceededError",
+    "ResourceLimitError",
+    "DuplicateColumnError",
+    "InternalError",
 )
@@ -1,15 +1,40 @@
 from __future__ import annotations
 
 import typing
-from typing import Callable, Literal
+from datetime import date, datetime, timedelta, tzinfo
+from typing import Any, Callable, Iterator, Literal
 
 import pyarrow as pa
+from typing_extensions import Unpack
 
-DType = Literal["null", "int", "float", "string", "boolean", "datetime", "date", "duration"]
+from fastexcel import LoadSheetOptions
+
+DType = Literal[
+    "null",
+    "int",
+    "float",
+    "string",
+    "boolean",
+    "bool_from_int",
+    "bool",
+    "datetime",
+    "datetime_tz",
+    "date",
+    "duration",
+    "excel_serial",
+]
 DTypeMap = dict[str | int, DType]
 ColumnNameFrom = Literal["provided", "looked_up", "generated"]
-DTypeFrom = Literal["provided_by_index", "provided_by_name", "guessed"]
+DTypeFrom = Literal["provided_by_index", "provided_by_name", "provided_for_all", "guessed"]
 SheetVisible = Literal["visible", "hidden", "veryhidden"]
+TimestampUnit = Literal["ms", "us", "ns"]
+ExtraColumns = Literal["drop", "error"]
+UnknownRenames = Literal["ignore", "error"]
+DuplicateColumns = Literal["underscore", "dot", "error"]
+SampleStrategy = Literal["head", "spread", "full"]
+SizeLimitOverflow = Literal["error", "truncate"]
+CellsMode = Literal["values", "value_and_formula"]
+FillValue = bool | int | float | str | date | datetime | timedelta
 
 class ColumnInfo:
     def __init__(
@@ -32,6 +57,72 @@ class ColumnInfo:
     @property
     def dtype_from(self) -> DTypeFrom: ...
 
+class HeaderWarning:
+    @property
+    def column_name(self) -> str: ...
+    @property
+    def column_index(self) -> int: ...
+    @property
+    def header_dtype(self) -> DType: ...
+    @property
+    def column_dtype(self) -> DType: ...
+    @property
+    def message(self) -> str: ...
+
+class ReadWarning:
+    @property
+    def kind(self) -> Literal["mixed_types", "null_on_conversion"]: ...
+    @property
+    def column_name(self) -> str: ...
+    @property
+    def column_index(self) -> int: ...
+    @property
+    def row(self) -> int: ...
+    @property
+    def cell_count(self) -> int: ...
+    @property
+    def dtype(self) -> DType: ...
+    @property
+    def message(self) -> str: ...
+
+class TypePromotion:
+    @property
+    def column_name(self) -> str: ...
+    @property
+    def batch_index(self) -> int: ...
+    @property
+    def from_type(self) -> pa.DataType: ...
+    @property
+    def to_type(self) -> pa.DataType: ...
+
+class ColumnStats:
+    @property
+    def column_name(self) -> str: ...
+    @property
+    def column_index(self) -> int: ...
+    @property
+    def dtype(self) -> DType: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def null_count(self) -> int: ...
+    @property
+    def min(self) -> int | float | date | datetime | timedelta | None: ...
+    @property
+    def max(self) -> int | float | date | datetime | timedelta | None: ...
+    @property
+    def distinct_estimate(self) -> int | None: ...
+
+class ArrowColumn:
+    @property
+    def name(self) -> str:
+        """The name of the column"""
+    def __len__(self) -> int: ...
+    def to_arrow(self) -> pa.Array:
+        """Converts the column to a pyarrow `Array`"""
+    def __arrow_c_array__(self, requested_schema: object | None = None) -> tuple[object, object]:
+        """Exports the column with the Arrow PyCapsule interface"""
+
 class _ExcelSheet:
     @property
     def name(self) -> str:
@@ -49,6 +140,18 @@ class _ExcelSheet:
     def offset(self) -> int:
         """The sheet's offset before data starts"""
     @property
+    def header_row(self) -> int | None:
+        """The index of the header row, or `None` if the column names are not read from the sheet"""
+    @property
+    def origin(self) -> tuple[int, int]:
+        """The (row, column) position in the worksheet of the first cell of the loaded data"""
+    @property
+    def header_warnings(self) -> list[HeaderWarning]:
+        """The warnings emitted when the sheet was loaded with `validate_header=True`"""
+    @property
+    def read_warnings(self) -> list[ReadWarning]:
+        """The warnings collected when the sheet was loaded with `collect_warnings=True`"""
+    @property
     def selected_columns(self) -> list[ColumnInfo]:
         """The sheet's selected columns"""
     @property
@@ -60,8 +163,42 @@ class _ExcelSheet:
     @property
     def visible(self) -> SheetVisible:
         """The visibility of the sheet"""
+    @property
+    def schema(self) -> pa.Schema:
+        """The sheet's arrow schema. Only reads its data if `tighten_nullability` is set"""
+    @property
+    def columns(self) -> list[tuple[str, str]]:
+        """The name and pyarrow type name of the selected columns"""
+    def is_empty(self) -> bool:
+        """Whether the sheet has no data rows below its header"""
     def to_arrow(self) -> pa.RecordBatch:
         """Converts the sheet to a pyarrow `RecordBatch`"""
+    def schema_and_arrow(self) -> tuple[pa.Schema, pa.RecordBatch]:
+        """Converts the sheet to a pyarrow `RecordBatch`, returned along with its schema"""
+    def column(self, idx_or_name: int | str) -> ArrowColumn:
+        """Converts a single selected column to an `ArrowColumn`"""
+    def cell_reference(self, row: int, column: int | str) -> str:
+        """The A1 reference of a loaded cell in the worksheet, e.g. `"AB12"`"""
+    def column_stats(self) -> list[ColumnStats]:
+        """Computes statistics of the selected columns, without converting them to arrow"""
+    def rows(self) -> Iterator[dict[str, Any]]:
+        """Iterates over the sheet's rows, as dicts keyed by column name"""
+    def to_arrow_batches(self, batch_size: int) -> _ExcelSheetBatches:
+        """Iterates over the sheet in pyarrow `RecordBatch`es of at most `batch_size` rows"""
+    def to_arrow_with_schema(
+        self,
+        template: pa.Schema | _ExcelSheet,
+        *,
+        extra_columns: ExtraColumns = "drop",
+    ) -> pa.RecordBatch:
+        """Converts the sheet to a pyarrow `RecordBatch` conforming to a template schema"""
+
+class _ExcelSheetBatches:
+    @property
+    def schema(self) -> pa.Schema:
+        """The schema of every batch"""
+    def __iter__(self) -> _ExcelSheetBatches: ...
+    def __next__(self) -> pa.RecordBatch: ...
 
 class _ExcelTable:
     @property
@@ -91,8 +228,15 @@ class _ExcelTable:
     @property
     def specified_dtypes(self) -> DTypeMap | None:
         """The dtypes specified for the table"""
+    @property
+    def schema(self) -> pa.Schema:
+        """The table's arrow schema. Only reads its data if `tighten_nullability` is set"""
     def to_arrow(self) -> pa.RecordBatch:
         """Converts the table to a pyarrow `RecordBatch`"""
+    def schema_and_arrow(self) -> tuple[pa.Schema, pa.RecordBatch]:
+        """Converts the table to a pyarrow `RecordBatch`, returned along with its schema"""
+    def column(self, idx_or_name: int | str) -> ArrowColumn:
+        """Converts a single selected column to an `ArrowColumn`"""
 
 class _ExcelReader:
     """A class representing an open Excel file and allowing to read its sheets"""
@@ -102,30 +246,18 @@ class _ExcelReader:
         self,
         idx_or_name: str | int,
         *,
-        header_row: int | None = 0,
-        column_names: list[str] | None = None,
-        skip_rows: int = 0,
-        n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
-        dtypes: DTypeMap | None = None,
+        ignore_unknown_dtypes: bool = False,
         eager: Literal[False] = ...,
+        **kwargs: Unpack[LoadSheetOptions],
     ) -> _ExcelSheet: ...
     @typing.overload
     def load_sheet(
         self,
         idx_or_name: str | int,
         *,
-        header_row: int | None = 0,
-        column_names: list[str] | None = None,
-        skip_rows: int = 0,
-        n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | None = None,
-        dtypes: DTypeMap | None = None,
+        ignore_unknown_dtypes: bool = False,
         eager: Literal[True] = ...,
+        **kwargs: Unpack[LoadSheetOptions],
     ) -> pa.RecordBatch: ...
     @typing.overload
     def load_table(
@@ -136,10 +268,37 @@ class _ExcelReader:
         column_names: list[str] | None = None,
         skip_rows: int = 0,
         n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
+        schema_sample_rows: int | float | None = 1_000,
+        sample_strategy: SampleStrategy = "head",
         dtype_coercion: Literal["coerce", "strict"] = "coerce",
         use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
         dtypes: DTypeMap | None = None,
+        timestamp_unit: TimestampUnit = "ms",
+        index_column: int | str | None = None,
+        bool_as_int8: bool = False,
+        bool_fill: bool | None = None,
+        epoch: date | None = None,
+        assume_timezone: str | tzinfo | None = None,
+        timezone: str | tzinfo | None = None,
+        na_values: list[str] | None = None,
+        empty_string_as_null: bool = False,
+        trim_strings: bool = False,
+        n_threads: int | None = None,
+        all_columns_dtype: DType | None = None,
+        infer_dates: bool = False,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        rename: dict[str, str] | None = None,
+        unknown_renames: UnknownRenames = "ignore",
+        duplicate_columns: DuplicateColumns = "underscore",
+        currency_symbols: list[str] | None = None,
+        capture_currency: bool = False,
+        tighten_nullability: bool = False,
+        decimal_separator: str | None = None,
+        thousands_separator: str | None = None,
+        fill_values: dict[str, FillValue] | None = None,
+        forward_fill: list[int | str] | None = None,
+        table_has_header: bool = True,
         eager: Literal[False] = ...,
     ) -> _ExcelTable: ...
     @typing.overload
@@ -151,28 +310,95 @@ class _ExcelReader:
         column_names: list[str] | None = None,
         skip_rows: int = 0,
         n_rows: int | None = None,
-        schema_sample_rows: int | None = 1_000,
+        schema_sample_rows: int | float | None = 1_000,
+        sample_strategy: SampleStrategy = "head",
         dtype_coercion: Literal["coerce", "strict"] = "coerce",
         use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
         dtypes: DTypeMap | None = None,
+        timestamp_unit: TimestampUnit = "ms",
+        index_column: int | str | None = None,
+        bool_as_int8: bool = False,
+        bool_fill: bool | None = None,
+        epoch: date | None = None,
+        assume_timezone: str | tzinfo | None = None,
+        timezone: str | tzinfo | None = None,
+        na_values: list[str] | None = None,
+        empty_string_as_null: bool = False,
+        trim_strings: bool = False,
+        n_threads: int | None = None,
+        all_columns_dtype: DType | None = None,
+        infer_dates: bool = False,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        rename: dict[str, str] | None = None,
+        unknown_renames: UnknownRenames = "ignore",
+        duplicate_columns: DuplicateColumns = "underscore",
+        currency_symbols: list[str] | None = None,
+        capture_currency: bool = False,
+        tighten_nullability: bool = False,
+        decimal_separator: str | None = None,
+        thousands_separator: str | None = None,
+        fill_values: dict[str, FillValue] | None = None,
+        forward_fill: list[int | str] | None = None,
+        table_has_header: bool = True,
         eager: Literal[True] = ...,
     ) -> pa.RecordBatch: ...
     @property
     def sheet_names(self) -> list[str]: ...
+    @property
+    def visible_sheet_names(self) -> list[str]: ...
     def table_names(self, sheet_idx_or_name: str | int | None = None) -> list[str]: ...
+    def tables(self, sheet_name: str | None = None) -> list[tuple[str, str, str]]: ...
+    def defined_names(self) -> list[tuple[str, str]]: ...
+    def defined_name_range(self, name: str) -> tuple[str, str]: ...
+    def column_names(
+        self,
+        idx_or_name: int | str,
+        *,
+        header_row: int | None = 0,
+        header_depth: int = 1,
+        header_separator: str = " / ",
+        column_prefix: str = "__UNNAMED__",
+        duplicate_columns: DuplicateColumns = "underscore",
+    ) -> list[str]: ...
+    def uses_1904_dates(self) -> bool: ...
+    def shared_strings(self) -> list[str]: ...
 
 def read_excel(source: str | bytes) -> _ExcelReader:
     """Reads an excel file and returns an ExcelReader"""
 
+def _concat_record_batches(
+    batches: list[pa.RecordBatch],
+    *,
+    sources: list[str] | None = None,
+    source_column: str | None = None,
+    promote_types: bool = False,
+) -> pa.RecordBatch:
+    """Concatenates several RecordBatches into a single one, using the union of their schemas"""
+
+def _merge_record_batches(
+    left: pa.RecordBatch, right: pa.RecordBatch
+) -> tuple[pa.RecordBatch, list[TypePromotion]]:
+    """Concatenates two RecordBatches, promoting the types of their conflicting columns"""
+
 __version__: str
 
 # Exceptions
 class FastExcelError(Exception): ...
-class UnsupportedColumnTypeCombinationError(FastExcelError): ...
-class CannotRetrieveCellDataError(FastExcelError): ...
-class CalamineCellError(FastExcelError): ...
+class CellError(FastExcelError): ...
+class SchemaError(FastExcelError): ...
+class UnsupportedColumnTypeCombinationError(SchemaError): ...
+class CannotRetrieveCellDataError(CellError): ...
+class CalamineCellError(CellError): ...
 class CalamineError(FastExcelError): ...
 class SheetNotFoundError(FastExcelError): ...
-class ColumnNotFoundError(FastExcelError): ...
+class ColumnNotFoundError(SchemaError): ...
 class ArrowError(FastExcelError): ...
 class InvalidParametersError(FastExcelError): ...
+class InvalidDtypeError(InvalidParametersError): ...
+class UnknownColumnError(ColumnNotFoundError): ...
+class CorruptFileError(CalamineError): ...
+class SizeLimitExceededError(FastExcelError): ...
+class ResourceLimitError(SizeLimitExceededError): ...
+class DuplicateColumnError(SchemaError): ...
+class InternalError(FastExcelError): ...
@@ -21,8 +21,8 @@ def xlrd_read(test_file_path: str):
                 value
 
 
-def fastexcel_read(test_file_path: str):
+def fastexcel_read(test_file_path: str, **kwargs):
     reader = read_excel(test_file_path)
     for sheet_name in reader.sheet_names:
-        sheet = reader.load_sheet_by_name(sheet_name)
+        sheet = reader.load_sheet_by_name(sheet_name, **kwargs)
         sheet.to_arrow()
@@ -3,6 +3,7 @@ Compare read performance with fastexcel, xlrd and different openpyxl options
 """
 
 import pytest
+from large_sheet import write_large_xlsx
 from readers import fastexcel_read, pyxl_read, xlrd_read
 
 
@@ -21,6 +22,14 @@ def formula_xlsx():
     return "./python/tests/benchmarks/fixtures/formulas.xlsx"
 
 
+@pytest.fixture(scope="session")
+def large_xlsx(tmp_path_factory):
+    # 20 columns x 1M rows, well above the number of cells from which columns are read in parallel
+    path = tmp_path_factory.mktemp("benchmarks") / "large.xlsx"
+    write_large_xlsx(path, n_rows=1_000_000, n_columns=20)
+    return str(path)
+
+
 @pytest.mark.benchmark(group="xlsx")
 def test_pyxl(benchmark, plain_data_xlsx):
     benchmark(pyxl_read, plain_data_xlsx)
@@ -41,6 +50,11 @@ def test_fastexcel_xlsx(benchmark, plain_data_xlsx):
     benchmark(fastexcel_read, plain_data_xlsx)
 
 
+@pytest.mark.benchmark(group="xlsx")
+def test_fastexcel_xlsx_single_thread(benchmark, plain_data_xlsx):
+    benchmark(fastexcel_read, plain_data_xlsx, n_threads=1)
+
+
 @pytest.mark.benchmark(group="xlsx")
 def test_pyxl_with_formulas(benchmark, formula_xlsx):
     benchmark(pyxl_read, formula_xlsx)
@@ -49,3 +63,13 @@ def test_pyxl_with_formulas(benchmark, formula_xlsx):
 @pytest.mark.benchmark(group="xlsx")
 def test_fastexcel_with_formulas(benchmark, formula_xlsx):
     benchmark(fastexcel_read, formula_xlsx)
+
+
+@pytest.mark.benchmark(group="xlsx_large")
+def test_fastexcel_large_xlsx(benchmark, large_xlsx):
+    benchmark(fastexcel_read, large_xlsx)
+
+
+@pytest.mark.benchmark(group="xlsx_large")
+def test_fastexcel_large_xlsx_single_thread(benchmark, large_xlsx):
+    benchmark(fastexcel_read, large_xlsx, n_threads=1)
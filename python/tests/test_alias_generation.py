@@ -43,3 +43,63 @@ def test_alias_generation_with_use_columns(use_columns: list[str] | list[int] |
             }
         ).with_columns(pl.col("col_2").str.strptime(pl.Datetime, "%F %T").dt.cast_time_unit("ms")),
     )
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_rename_before_alias_generation(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-textual-booleans.xlsx"))
+    rename = {"active": "name", "unknown": "other"}
+
+    rb = (
+        excel_reader.load_sheet_eager(0, rename=rename)
+        if eager
+        else excel_reader.load_sheet(0, rename=rename).to_arrow()
+    )
+    # The renamed column collides with "name", so it is aliased
+    assert rb.schema.names == ["name", "name_1"]
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match="cannot rename unknown column"):
+        excel_reader.load_sheet(0, rename=rename, unknown_renames="error")
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_column_prefix_for_headerless_sheets(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-textual-booleans.xlsx"))
+    rename = {"col_0": "col_1"}
+
+    rb = (
+        excel_reader.load_sheet_eager(0, header_row=None, column_prefix="col_", rename=rename)
+        if eager
+        else excel_reader.load_sheet(
+            0, header_row=None, column_prefix="col_", rename=rename
+        ).to_arrow()
+    )
+    # The renamed column collides with the generated name of the second column
+    assert rb.schema.names == ["col_1", "col_1_1"]
+    assert excel_reader.column_names(0, header_row=None, column_prefix="field_") == [
+        "field_0",
+        "field_1",
+    ]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_duplicate_columns(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-single-sheet-duplicated-columns.xlsx")
+    )
+
+    rb = (
+        excel_reader.load_sheet_eager(0, duplicate_columns="dot")
+        if eager
+        else excel_reader.load_sheet(0, duplicate_columns="dot").to_arrow()
+    )
+    assert rb.schema.names == ["col", "col.1", "col.2"]
+    assert excel_reader.column_names(0, duplicate_columns="dot") == ["col", "col.1", "col.2"]
+
+    with pytest.raises(fastexcel.DuplicateColumnError, match='duplicate column name "col"'):
+        if eager:
+            excel_reader.load_sheet_eager(0, duplicate_columns="error")
+        else:
+            excel_reader.load_sheet(0, duplicate_columns="error")
+    with pytest.raises(fastexcel.InvalidParametersError, match="unsupported duplicate_columns"):
+        excel_reader.load_sheet(0, duplicate_columns="suffix")  # type: ignore[arg-type]
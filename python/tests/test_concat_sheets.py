@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+import fastexcel
+import pyarrow as pa
+import pytest
+
+from utils import path_for_fixture
+
+
+def test_concat_sheets_same_schema() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    rb = fastexcel.concat_sheets([excel_reader.load_sheet(0), excel_reader.load_sheet(1)])
+
+    assert rb.to_pydict() == {
+        "Month": [1.0, 2.0, 3.0, 4.0],
+        "Year": [2019.0, 2019.0, 2021.0, 2022.0],
+    }
+
+
+def test_concat_sheets_promotes_int_and_float() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    rb = fastexcel.concat_sheets(
+        [
+            excel_reader.load_sheet(0, dtypes={"Month": "int"}),
+            excel_reader.load_sheet(1, dtypes={"Year": "string"}),
+        ]
+    )
+
+    assert rb.schema.field("Month").type == pa.float64()
+    assert rb.schema.field("Year").type == pa.string()
+    assert rb.column("Month").to_pylist() == [1.0, 2.0, 3.0, 4.0]
+
+
+def test_concat_sheets_missing_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    rb = fastexcel.concat_sheets(
+        [excel_reader.load_sheet(0), excel_reader.load_sheet(2, use_columns=["col1", "col3"])]
+    )
+
+    assert rb.to_pydict() == {
+        "Month": [1.0, None, None],
+        "Year": [2019.0, None, None],
+        "col1": [None, 2.0, 3.0],
+        "col3": [None, "hello", "world"],
+    }
+
+
+def test_concat_sheets_empty() -> None:
+    with pytest.raises(fastexcel.InvalidParametersError, match="no sheet to concatenate"):
+        fastexcel.concat_sheets([])
+
+
+def test_to_arrow_with_schema_from_template_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    template = excel_reader.load_sheet(0, dtypes={"Month": "int"})
+
+    rb = excel_reader.load_sheet(1, use_columns=["Year"]).to_arrow_with_schema(template)
+
+    assert rb.schema == pa.schema([("Month", pa.int64()), ("Year", pa.float64())])
+    assert rb.to_pydict() == {"Month": [None, None], "Year": [2021.0, 2022.0]}
+
+
+def test_to_arrow_with_schema_extra_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    schema = pa.schema([("col1", pa.int64())])
+    sheet = excel_reader.load_sheet(2, use_columns=["col1", "col3"])
+
+    assert sheet.to_arrow_with_schema(schema).to_pydict() == {"col1": [2, 3]}
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match='column with name "col3" not found'):
+        sheet.to_arrow_with_schema(schema, extra_columns="error")
+
+
+def test_load_sheet_with_schema() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    schema = pa.schema([("Year", pa.int32()), ("Day", pa.date32())])
+
+    # Day is not part of the sheet
+    with pytest.raises(fastexcel.ColumnNotFoundError, match=r"columns \['Day'\] of the schema"):
+        excel_reader.load_sheet_with_schema("January", schema)
+
+    # Only the columns of the schema are read, Month is ignored
+    rb = excel_reader.load_sheet_with_schema("January", schema, missing_columns="null")
+    assert rb.schema == schema
+    assert rb.to_pydict() == {"Year": [2019], "Day": [None]}
+
+    # The numbers are read as strings rather than cast from floats
+    rb = excel_reader.load_sheet_with_schema("January", pa.schema([("Month", pa.string())]))
+    assert rb.to_pydict() == {"Month": ["1"]}
+
+    # The dtypes apply to the column names of the loaded sheet, e.g. once renamed
+    rb = excel_reader.load_sheet_with_schema(
+        "January",
+        pa.schema([("month", pa.string()), ("Day", pa.date32())]),
+        missing_columns="null",
+        rename={"Month": "month"},
+    )
+    assert rb.to_pydict() == {"month": ["1"], "Day": [None]}
+
+
+def test_merge_record_batches_reports_promotions() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    left = excel_reader.load_sheet_eager(0, dtypes={"Month": "int"})
+    right = pa.table({"Month": [3.5], "Comment": ["late"]})
+
+    rb, promotions = fastexcel.merge_record_batches(left, right)
+
+    assert rb.to_pydict() == {
+        "Month": [1.0, 2.0, 3.5],
+        "Year": [2019.0, 2019.0, None],
+        "Comment": [None, None, "late"],
+    }
+    assert len(promotions) == 1
+    assert promotions[0].column_name == "Month"
+    assert promotions[0].batch_index == 0
+    assert promotions[0].from_type == pa.int64()
+    assert promotions[0].to_type == pa.float64()
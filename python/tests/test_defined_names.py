@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+import fastexcel
+import pytest
+
+from utils import path_for_fixture
+
+
+def test_defined_names() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-defined-names.xlsx"))
+
+    assert excel_reader.defined_names() == [
+        ("External", "[1]Sheet1!$A$1"),
+        ("Rate", "0.05"),
+        ("SalesData", "Sheet1!$B$3:$C$6"),
+        ("ThreeD", "Sheet1:Sheet2!$A$1"),
+    ]
+
+
+def test_load_defined_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-defined-names.xlsx"))
+
+    sheet = excel_reader.load_defined_name("SalesData")
+    assert sheet.name == "Sheet1"
+    assert sheet.to_arrow().to_pydict() == {
+        "product": ["apple", "pear", "plum"],
+        "amount": [3.0, 5.5, 2.0],
+    }
+
+    sheet = excel_reader.load_defined_name("SalesData", header_row=None, n_rows=2)
+    assert sheet.to_arrow().to_pydict() == {
+        "__UNNAMED__0": ["product", "apple"],
+        "__UNNAMED__1": ["amount", "3"],
+    }
+
+
+@pytest.mark.parametrize(
+    "name, expected_message",
+    [
+        ("Rate", "does not refer to cells of a sheet"),
+        ("ThreeD", "refers to several sheets"),
+        ("External", "refers to another workbook"),
+        ("Missing", 'defined name "Missing" not found'),
+    ],
+)
+def test_load_defined_name_errors(name: str, expected_message: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-defined-names.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match=expected_message):
+        excel_reader.load_defined_name(name)
@@ -1,17 +1,22 @@
 from __future__ import annotations
 
-from datetime import date, datetime
+import shutil
+import sys
+from datetime import date, datetime, timedelta, timezone
+from functools import partial
+from pathlib import Path
 from typing import Any, Literal
 
 import fastexcel
 import numpy as np
 import pandas as pd
 import polars as pl
+import pyarrow as pa
 import pytest
 from pandas.testing import assert_frame_equal as pd_assert_frame_equal
 from polars.testing import assert_frame_equal as pl_assert_frame_equal
 
-from utils import path_for_fixture
+from utils import load_sheet, path_for_fixture
 
 
 @pytest.fixture
@@ -60,6 +65,48 @@ def test_sheet_with_mixed_dtypes(expected_data: dict[str, list[Any]]) -> None:
     )
 
 
+def test_sample_rows_as_a_fraction() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    # Half of the 9 rows is rounded up to 5 rows
+    assert (
+        excel_reader.load_sheet(0, schema_sample_rows=0.5).available_columns
+        == excel_reader.load_sheet(0, schema_sample_rows=5).available_columns
+    )
+    assert (
+        excel_reader.load_sheet(0, schema_sample_rows=1.0).available_columns
+        == excel_reader.load_sheet(0, schema_sample_rows=None).available_columns
+    )
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="greater than 0 and at most 1"):
+        excel_reader.load_sheet(0, schema_sample_rows=1.5)
+
+
+def test_sample_strategy() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    def guessed_dtypes(**kwargs: Any) -> dict[str, fastexcel.DType]:
+        sheet = excel_reader.load_sheet(0, schema_sample_rows=5, **kwargs)
+        return {column.name: column.dtype for column in sheet.available_columns}
+
+    # The strings at the bottom of the column are not part of the first 5 rows, but are part of
+    # 5 rows spread over the 9 rows of the sheet
+    assert guessed_dtypes()["Employee ID"] == "float"
+    assert guessed_dtypes(sample_strategy="spread")["Employee ID"] != "float"
+    assert (
+        guessed_dtypes(sample_strategy="spread")["Employee ID"]
+        == guessed_dtypes(sample_strategy="full")["Employee ID"]
+    )
+
+    assert guessed_dtypes(sample_strategy="full") == {
+        column.name: column.dtype
+        for column in excel_reader.load_sheet(0, schema_sample_rows=None).available_columns
+    }
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="unsupported sample_strategy"):
+        excel_reader.load_sheet(0, sample_strategy="tail")  # type: ignore[arg-type]
+
+
 def test_sheet_with_mixed_dtypes_and_sample_rows(expected_data: dict[str, list[Any]]) -> None:
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
 
@@ -120,6 +167,92 @@ def test_sheet_with_mixed_dtypes_and_sample_rows(expected_data: dict[str, list[A
     )
 
 
+def test_sheet_with_mixed_dtypes_read_warnings() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    # Warnings are opt-in
+    assert excel_reader.load_sheet(0).read_warnings == []
+
+    sheet = excel_reader.load_sheet(0, collect_warnings=True)
+    warnings = {warning.column_name: warning for warning in sheet.read_warnings}
+    employee_id = warnings["Employee ID"]
+    assert (employee_id.kind, employee_id.dtype) == ("mixed_types", "string")
+    # The first string is in the 7th row of the sheet
+    assert (employee_id.row, employee_id.cell_count) == (6, 3)
+
+    # Guessing dtypes on 5 rows only, the strings of "Employee ID" are read as null
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=5, collect_warnings=True)
+    assert all(warning.kind == "mixed_types" for warning in sheet.read_warnings)
+    sheet.to_arrow()
+    warnings = {
+        warning.column_name: warning
+        for warning in sheet.read_warnings
+        if warning.kind == "null_on_conversion"
+    }
+    employee_id = warnings["Employee ID"]
+    assert (employee_id.dtype, employee_id.row, employee_id.cell_count) == ("float", 6, 3)
+    assert employee_id.message.startswith(
+        '3 cell(s) of column "Employee ID" could not be read as float and are null'
+    )
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_na_values(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-na-values.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager, dtypes={"value": "float"})
+
+    assert load().to_pydict() == {"name": ["a", "-", "b"], "value": [1.5, None, 3.0]}
+    assert load(na_values=["-"]).to_pydict() == {
+        "name": ["a", None, "b"],
+        "value": [1.5, None, 3.0],
+    }
+    # Sentinels are null even when coercion is strict
+    rb = load(na_values=["-"], dtype_coercion="strict")
+    assert rb.column("name").to_pylist() == ["a", None, "b"]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_trim_strings(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-padded-strings.xlsx"))
+
+    def load(**kwargs: Any) -> list[str | None]:
+        return load_sheet(excel_reader, 0, eager, **kwargs).column("name").to_pylist()
+
+    assert load() == [" alice ", "\u00a0bob\t", "   ", " n/a "]
+    assert load(trim_strings=True) == ["alice", "bob", "", "n/a"]
+    # Values are trimmed before being compared to the null values
+    assert load(trim_strings=True, na_values=["n/a"], empty_string_as_null=True) == [
+        "alice",
+        "bob",
+        None,
+        None,
+    ]
+
+
+def test_error_cells_are_null() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-error-cells.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, collect_warnings=True)
+    rb = sheet.to_arrow()
+    assert rb.schema.field("ratio").type == pa.float64()
+    assert rb.column("ratio").to_pylist() == [1.5, None, 2.5, None]
+    # #N/A is a null-like value, #DIV/0! is not
+    [warning] = sheet.read_warnings
+    assert (warning.kind, warning.row, warning.cell_count) == ("null_on_conversion", 2, 1)
+
+    with pytest.raises(fastexcel.CalamineCellError, match="#DIV/0!"):
+        excel_reader.load_sheet(0, dtype_coercion="strict")
+    # Columns whose dtype is specified read their error cells as null too, unless coercion is
+    # strict
+    assert excel_reader.load_sheet(0, dtypes={"ratio": "float"}).to_arrow().column(
+        "ratio"
+    ).to_pylist() == [1.5, None, 2.5, None]
+    sheet = excel_reader.load_sheet(0, dtypes={"ratio": "float"}, dtype_coercion="strict")
+    with pytest.raises(fastexcel.CalamineCellError, match="#DIV/0!"):
+        sheet.to_arrow()
+
+
 @pytest.mark.parametrize("dtype_by_index", (True, False))
 @pytest.mark.parametrize(
     "dtype,expected_data,expected_pd_dtype,expected_pl_dtype",
@@ -196,6 +329,28 @@ def test_sheet_datetime_conversion(
     assert pl_df["Date"].to_list() == [expected] * 9
 
 
+@pytest.mark.parametrize(
+    "infer_dates,dtypes,expected,expected_pl_dtype",
+    [
+        (False, None, datetime(2023, 7, 21), pl.Datetime),
+        (True, None, date(2023, 7, 21), pl.Date),
+        (True, {"Date": "datetime"}, datetime(2023, 7, 21), pl.Datetime),
+    ],
+)
+def test_sheet_infer_dates(
+    infer_dates: bool,
+    dtypes: fastexcel.DTypeMap | None,
+    expected: Any,
+    expected_pl_dtype: pl.DataType,
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, dtypes=dtypes, infer_dates=infer_dates)
+    pl_df = sheet.to_polars()
+    assert pl_df["Date"].dtype == expected_pl_dtype
+    assert pl_df["Date"].to_list() == [expected] * 9
+
+
 @pytest.mark.parametrize("eager", [True, False])
 @pytest.mark.parametrize("dtype_coercion", ["coerce", None])
 def test_dtype_coercion_behavior__coerce(
@@ -257,3 +412,416 @@ def test_dtype_coercion_behavior__strict_sampling_limit(eager: bool) -> None:
     assert pl_df["Mixed dates"].to_list() == [datetime(2023, 7, 21)] * 6 + [None] * 3
     assert pl_df["Asset ID"].dtype == pl.Float64
     assert pl_df["Asset ID"].to_list() == [84444.0] * 7 + [None] * 2
+
+
+@pytest.mark.parametrize("timestamp_unit", ["ms", "us", "ns"])
+def test_timestamp_unit(timestamp_unit: Literal["ms", "us", "ns"]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, timestamp_unit=timestamp_unit)
+    rb = sheet.to_arrow()
+    assert rb.schema.field("Date").type == pa.timestamp(timestamp_unit)
+    assert rb.column("Date").to_pylist() == [datetime(2023, 7, 21)] * 9
+
+    rb_eager = excel_reader.load_sheet_eager(0, timestamp_unit=timestamp_unit)
+    assert rb_eager.schema.field("Date").type == pa.timestamp(timestamp_unit)
+
+
+def test_invalid_timestamp_unit() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='unsupported timestamp_unit: "s"'):
+        excel_reader.load_sheet(0, timestamp_unit="s")  # type:ignore[arg-type]
+
+
+@pytest.mark.parametrize("bool_fill", [None, False, True])
+def test_bool_fill(bool_fill: bool | None) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
+
+    # The first column has an empty header cell, followed by 0, 1 and 2
+    sheet = excel_reader.load_sheet(
+        0, header_row=None, use_columns=[0], dtypes={0: "boolean"}, bool_fill=bool_fill
+    )
+    expected = [bool_fill, False, True, True]
+    assert sheet.to_arrow().column(0).to_pylist() == expected
+    assert [row["__UNNAMED__0"] for row in sheet.rows()] == expected
+
+
+def test_custom_epoch() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    rb = excel_reader.load_sheet_eager(
+        0, dtypes={"Date": "date"}, epoch=date(2023, 7, 1), use_columns=["Date"], n_rows=1
+    )
+    # 2023-07-21 is 20 days after the epoch
+    assert rb.schema.field("Date").type == pa.int32()
+    assert rb.schema.field("Date").metadata == {b"fastexcel.epoch": b"2023-07-01"}
+    assert rb.column("Date").to_pylist() == [20]
+
+    rb = excel_reader.load_sheet_eager(0, epoch=date(2023, 7, 1), use_columns=["Date"], n_rows=1)
+    assert rb.schema.field("Date").type == pa.int64()
+    assert rb.schema.field("Date").metadata == {b"fastexcel.epoch": b"2023-07-01"}
+    assert rb.column("Date").to_pylist() == [20 * 24 * 3_600_000]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_excel_serial_dtype(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    rb = (
+        excel_reader.load_sheet_eager(0, dtypes={"Date": "excel_serial"})
+        if eager
+        else excel_reader.load_sheet(0, dtypes={"Date": "excel_serial"}).to_arrow()
+    )
+    assert rb.schema.field("Date").type == pa.float64()
+    # 2023-07-21 is 45128 days after 1899-12-30
+    assert rb.column("Date").to_pylist() == [45128.0] * 9
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_offset_datetime_strings(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-offset-datetimes.xlsx"))
+
+    # The last timestamp has a malformed offset, so the column is guessed from the first ones
+    rb = (
+        excel_reader.load_sheet_eager(0, schema_sample_rows=2)
+        if eager
+        else excel_reader.load_sheet(0, schema_sample_rows=2).to_arrow()
+    )
+    assert rb.schema.field("Timestamp").type == pa.timestamp("ms", tz="UTC")
+    assert rb.column("Timestamp").to_pylist() == [
+        datetime(2023, 5, 1, 10, tzinfo=timezone.utc),
+        datetime(2023, 5, 1, 10, tzinfo=timezone.utc),
+        None,
+    ]
+
+    with pytest.raises(fastexcel.UnsupportedColumnTypeCombinationError):
+        excel_reader.load_sheet(
+            0, dtypes={"Timestamp": "datetime_tz"}, dtype_coercion="strict"
+        ).to_arrow()
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_naive_and_offset_datetimes_in_one_column(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-mixed-timezones.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager)
+
+    # Serial and ISO datetimes are naive, and read as UTC by default
+    rb = load()
+    assert rb.schema.field("when").type == pa.timestamp("ms", tz="UTC")
+    assert rb.column("when").to_pylist() == [
+        datetime(2023, 5, 1, 12, tzinfo=timezone.utc),
+        datetime(2023, 5, 1, 10, tzinfo=timezone.utc),
+        datetime(2023, 5, 2, 8, tzinfo=timezone.utc),
+        datetime(2023, 5, 2, 9, tzinfo=timezone.utc),
+    ]
+
+    # Europe/Paris is 2 hours ahead of UTC in May
+    assume_timezones: list[Any] = ["+02:00", timezone(timedelta(hours=2)), "Europe/Paris"]
+    if sys.version_info >= (3, 9):
+        from zoneinfo import ZoneInfo
+
+        assume_timezones.append(ZoneInfo("Europe/Paris"))
+    for assume_timezone in assume_timezones:
+        rb = load(assume_timezone=assume_timezone)
+        assert rb.column("when").to_pylist() == [
+            datetime(2023, 5, 1, 10, tzinfo=timezone.utc),
+            datetime(2023, 5, 1, 10, tzinfo=timezone.utc),
+            datetime(2023, 5, 2, 6, tzinfo=timezone.utc),
+            datetime(2023, 5, 2, 9, tzinfo=timezone.utc),
+        ]
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="unsupported timezone"):
+        load(assume_timezone="Europe/Atlantis")
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_datetime_columns_with_a_timezone(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-iso-datetimes.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager, use_columns=["when"])
+
+    rb = load(timezone="UTC")
+    assert rb.schema.field("when").type == pa.timestamp("ms", tz="UTC")
+    assert rb.column("when").to_pylist()[:2] == [
+        datetime(2023, 1, 1, 12, tzinfo=timezone.utc),
+        datetime(2023, 1, 2, 8, 30, tzinfo=timezone.utc),
+    ]
+
+    # The timezone only changes how the timestamps are displayed
+    rb = load(timezone="+02:00", assume_timezone="+01:00")
+    assert rb.schema.field("when").type == pa.timestamp("ms", tz="+02:00")
+    assert rb.column("when").to_pylist()[0] == datetime(
+        2023, 1, 1, 13, tzinfo=timezone(timedelta(hours=2))
+    )
+
+    # Europe/Paris is 1 hour ahead of UTC in January
+    rb = load(timezone="Europe/Paris", assume_timezone="Europe/Paris")
+    assert rb.schema.field("when").type == pa.timestamp("ms", tz="Europe/Paris")
+    assert rb.column("when").to_pylist()[0] == datetime(2023, 1, 1, 11, tzinfo=timezone.utc)
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="unsupported timezone"):
+        load(timezone="Mars/Olympus_Mons")
+
+
+@pytest.mark.parametrize("dtype", ["bool_from_int", "bool"])
+def test_bool_dtype_from_int_flags(dtype: fastexcel.DType) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    # Employee IDs are neither 0 nor 1
+    sheet = excel_reader.load_sheet(0, dtypes={"Employee ID": dtype}, n_rows=5)
+    rb = sheet.to_arrow()
+    assert rb.schema.field("Employee ID").type == pa.bool_()
+    assert rb.column("Employee ID").to_pylist() == [None] * 5
+
+    strict_sheet = excel_reader.load_sheet(
+        0,
+        dtypes={"Employee ID": dtype},
+        n_rows=5,
+        schema_sample_rows=5,
+        dtype_coercion="strict",
+    )
+    with pytest.raises(
+        fastexcel.UnsupportedColumnTypeCombinationError, match="is neither 0 nor 1"
+    ):
+        strict_sheet.to_arrow()
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_bools_mixed_with_int_flags(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-mixed-bools.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager)
+
+    # Booleans mixed with 0/1 numbers are guessed as booleans, other numbers keep the column
+    # numeric
+    rb = load()
+    assert rb.schema.field("flag").type == pa.bool_()
+    assert rb.column("flag").to_pylist() == [True, True, False, False, True]
+    assert rb.schema.field("count").type == pa.float64()
+
+    rb = load(dtypes={"count": "bool_from_int"})
+    assert rb.column("count").to_pylist() == [True, None, False, False, True]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_bool_as_int8(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    rb = (
+        excel_reader.load_sheet_eager(
+            0, dtypes={"Employee ID": "boolean"}, n_rows=5, bool_as_int8=True
+        )
+        if eager
+        else excel_reader.load_sheet(
+            0, dtypes={"Employee ID": "boolean"}, n_rows=5, bool_as_int8=True
+        ).to_arrow()
+    )
+    assert rb.schema.field("Employee ID").type == pa.int8()
+    assert rb.column("Employee ID").to_pylist() == [1] * 5
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_all_columns_dtype(eager: bool, expected_data: dict[str, list[Any]]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, all_columns_dtype="string", dtypes={"Date": "datetime"})
+    assert [(col.name, col.dtype, col.dtype_from) for col in sheet.available_columns] == [
+        ("Employee ID", "string", "provided_for_all"),
+        ("Employee Name", "string", "provided_for_all"),
+        ("Date", "datetime", "provided_by_name"),
+        ("Details", "string", "provided_for_all"),
+        ("Asset ID", "string", "provided_for_all"),
+        ("Mixed dates", "string", "provided_for_all"),
+        ("Mixed bools", "string", "provided_for_all"),
+    ]
+
+    rb = (
+        excel_reader.load_sheet_eager(0, all_columns_dtype="string", dtypes={"Date": "datetime"})
+        if eager
+        else sheet.to_arrow()
+    )
+    assert rb.to_pydict() == expected_data
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_textual_booleans(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-textual-booleans.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager, dtypes={"active": "boolean"})
+
+    # English values are used by default
+    assert load().column("active").to_pylist() == [True, False, True, None, None]
+    assert load(true_values=["oui"], false_values=["non"]).column("active").to_pylist() == [
+        None,
+        None,
+        True,
+        None,
+        True,
+    ]
+    with pytest.raises(
+        fastexcel.UnsupportedColumnTypeCombinationError, match="is not a known boolean value"
+    ):
+        load(dtype_coercion="strict")
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_values_stored_as_text(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-values-as-text.xlsx"))
+    dtypes: fastexcel.DTypeMap = {"active": "boolean", "amount": "float"}
+
+    rb = (
+        excel_reader.load_sheet_eager(0, dtypes=dtypes)
+        if eager
+        else excel_reader.load_sheet(0, dtypes=dtypes).to_arrow()
+    )
+    assert rb.to_pydict() == {
+        "active": [True, False, True, False],
+        "amount": [12.0, 3.5, None, 7.0],
+    }
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_decimal_and_thousands_separators(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-european-numbers.xlsx"))
+
+    dtypes: fastexcel.DTypeMap = {"amount": "float", "count": "int"}
+    load = partial(load_sheet, excel_reader, 0, eager, dtypes=dtypes)
+
+    assert load(decimal_separator=",", thousands_separator=".").to_pydict() == {
+        "amount": [1234.5, -0.25, None],
+        "count": [1000, 12, 3],
+    }
+    # Without separators, only the numbers formatted the Rust way are parsed
+    assert load().to_pydict() == {"amount": [None, None, None], "count": [None, 12, 3]}
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="cannot be the decimal separator"):
+        load(thousands_separator=".")
+    with pytest.raises(fastexcel.InvalidParametersError, match="cannot be a digit"):
+        load(decimal_separator="0")
+
+
+@pytest.mark.parametrize("eager", [True, False])
+@pytest.mark.parametrize(
+    "fixture, expected",
+    [
+        ("fixture-date-serials.xlsx", [date(2023, 1, 1), date(2023, 3, 15)]),
+        ("fixture-date-serials-1904.xlsx", [date(2027, 1, 2), date(2027, 3, 16)]),
+    ],
+)
+def test_date_dtype_reads_excel_serials(eager: bool, fixture: str, expected: list[date]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture(fixture))
+
+    # Without a dtype, the serials are plain numbers
+    assert excel_reader.load_sheet(0).selected_columns[0].dtype == "float"
+
+    rb = (
+        excel_reader.load_sheet_eager(0, dtypes={"date": "date"})
+        if eager
+        else excel_reader.load_sheet(0, dtypes={"date": "date"}).to_arrow()
+    )
+    assert rb.column("date").to_pylist() == expected
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_extreme_floats_are_read_exactly(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-extreme-floats.xlsx"))
+    # The "text" column contains the same numbers as strings
+    dtypes: fastexcel.DTypeMap = {"value": "float", "text": "float"}
+
+    rb = (
+        excel_reader.load_sheet_eager(0, dtypes=dtypes)
+        if eager
+        else excel_reader.load_sheet(0, dtypes=dtypes).to_arrow()
+    )
+
+    expected = [
+        1.7976931348623157e308,
+        -1.7976931348623157e308,
+        5e-324,
+        2.2250738585072014e-308,
+        0.1,
+        # Halfway between two floats, rounded to the even one
+        9007199254740992.0,
+    ]
+    assert rb.column("value").to_pylist() == expected
+    assert rb.column("text").to_pylist() == expected
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_fill_values(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-invalid-cell-value.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager)
+
+    # The error cells are filled, unknown columns are ignored
+    rb = load(fill_values={"Date": datetime(2000, 1, 1), "Unknown": 0})
+    assert rb.to_pydict() == {
+        "Title": ["A", "B", "C", "D"],
+        "Date": [
+            datetime(2000, 1, 1),
+            datetime(2000, 1, 1),
+            datetime(2021, 1, 1),
+            datetime(2021, 5, 5),
+        ],
+    }
+    assert rb.column("Date").null_count == 0
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='column "Title" of dtype string'):
+        load(fill_values={"Title": 0})
+
+    # Ints fill float columns
+    sheet = fastexcel.read_excel(path_for_fixture("sheet-with-na.xlsx")).load_sheet(
+        0, fill_values={"Amount": 0}
+    )
+    assert sheet.to_arrow().to_pydict() == {"Title": ["A", "B"], "Amount": [0.0, 100.0]}
+    assert [row["Amount"] for row in sheet.rows()] == [0.0, 100.0]
+
+
+@pytest.mark.parametrize(
+    "fixture, expected",
+    [
+        ("fixture-date-serials.xlsx", False),
+        ("fixture-date-serials-1904.xlsx", True),
+        ("dates.ods", False),
+    ],
+)
+def test_uses_1904_dates(fixture: str, expected: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture(fixture))
+
+    assert excel_reader.uses_1904_dates() is expected
+
+
+def test_date_system_is_read_when_opening_the_workbook(tmp_path: Path) -> None:
+    path = tmp_path / "dates.xlsx"
+    shutil.copy(path_for_fixture("fixture-date-serials-1904.xlsx"), path)
+    excel_reader = fastexcel.read_excel(path)
+
+    # Loading sheets does not reopen the workbook
+    path.unlink()
+    rb = excel_reader.load_sheet_eager(0, dtypes={"date": "date"})
+    assert rb.column("date").to_pylist() == [date(2027, 1, 2), date(2027, 3, 16)]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_iso_and_serial_datetimes_in_one_column(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-iso-datetimes.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager)
+
+    # The first cell of each column is a serial datetime, the other ones ISO 8601 strings
+    rb = load()
+    assert rb.schema == pa.schema([("when", pa.timestamp("ms")), ("day", pa.timestamp("ms"))])
+    assert rb.to_pydict() == {
+        "when": [
+            datetime(2023, 1, 1, 12),
+            datetime(2023, 1, 2, 8, 30),
+            datetime(2023, 1, 3),
+            datetime(2023, 1, 4, 10),
+        ],
+        "day": [datetime(2023, 1, 1), datetime(2023, 1, 2), datetime(2023, 1, 3, 23), None],
+    }
+
+    rb = load(dtypes={"day": "date"}, use_columns=["day"])
+    assert rb.to_pydict() == {"day": [date(2023, 1, 1), date(2023, 1, 2), date(2023, 1, 3), None]}
@@ -81,3 +81,14 @@ def test_sheet_with_offset_header_row_and_durations() -> None:
         timedelta(hours=1, minutes=18, seconds=43),
         timedelta(hours=7, minutes=16, seconds=51),
     ]
+
+
+def test_sheet_with_offset_header_row_auto_header() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("single-sheet-skip-rows-durations.xlsx"))
+    sheet = excel_reader.load_sheet(0, auto_header=True)
+
+    assert sheet.header_row == 9
+    assert sheet.to_polars()["Tot. Time Away From System"].to_list() == [
+        timedelta(hours=1, minutes=18, seconds=43),
+        timedelta(hours=7, minutes=16, seconds=51),
+    ]
@@ -1,4 +1,5 @@
 import fastexcel
+import pyarrow as pa
 import pytest
 
 from utils import path_for_fixture
@@ -11,3 +12,42 @@ def test_empty(path: str) -> None:
 
     assert sheet.to_pandas().empty
     assert sheet.to_polars().is_empty()
+
+
+@pytest.mark.parametrize("path", ("empty.ods", "empty.xlsx"))
+def test_empty_sheet_is_empty(path: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture(path))
+    sheet = excel_reader.load_sheet(0)
+
+    assert sheet.is_empty()
+    # The header row is beyond the end of the data
+    assert sheet.height == 0
+    assert sheet.total_height == 0
+    assert sheet.to_arrow().num_rows == 0
+
+
+def test_sheet_with_only_a_header_is_empty() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-data-followed-by-notes.xlsx"))
+
+    # The last row is the header, and there is no data below it
+    sheet = excel_reader.load_sheet(0, header_row=5)
+    assert sheet.is_empty()
+    rb = sheet.to_arrow()
+    assert rb.num_rows == 0
+    assert rb.schema.names == ["values are made up", "__UNNAMED__1"]
+
+    assert not excel_reader.load_sheet(0).is_empty()
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_header_only_sheet_keeps_the_specified_dtypes(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-data-followed-by-notes.xlsx"))
+    kwargs = {"header_row": 5, "dtypes": {0: "float", 1: "string"}}
+
+    rb = (
+        excel_reader.load_sheet_eager(0, **kwargs)
+        if eager
+        else excel_reader.load_sheet(0, **kwargs).to_arrow()
+    )
+    assert rb.num_rows == 0
+    assert rb.schema.types == [pa.float64(), pa.string()]
@@ -60,6 +60,11 @@ Context:
     "exc_class, expected_docstring",
     [
         (fastexcel.FastExcelError, "The base class for all fastexcel errors"),
+        (fastexcel.CellError, "The base class for errors related to the content of a cell"),
+        (
+            fastexcel.SchemaError,
+            "The base class for errors related to the columns and their dtypes",
+        ),
         (
             fastexcel.UnsupportedColumnTypeCombinationError,
             "Column contains an unsupported type combination",
@@ -74,7 +79,74 @@ Context:
         (fastexcel.SheetNotFoundError, "Sheet was not found"),
         (fastexcel.ArrowError, "Generic arrow error"),
         (fastexcel.InvalidParametersError, "Provided parameters are invalid"),
+        (fastexcel.InvalidDtypeError, "Provided dtype is not supported"),
+        (
+            fastexcel.UnknownColumnError,
+            "A dtype was specified for a column that does not exist",
+        ),
+        (fastexcel.CorruptFileError, "The file is corrupt or incomplete"),
+        (fastexcel.SizeLimitExceededError, "The sheet has more columns or rows than allowed"),
+        (fastexcel.ResourceLimitError, "The sheet has more cells than allowed"),
+        (fastexcel.DuplicateColumnError, "Several columns have the same name"),
+        (fastexcel.InternalError, "Internal fastexcel error"),
     ],
 )
 def test_docstrings(exc_class: type[Exception], expected_docstring: str) -> None:
     assert exc_class.__doc__ == expected_docstring
+
+
+@pytest.mark.parametrize(
+    "exc_class, expected_base",
+    [
+        (fastexcel.CellError, fastexcel.FastExcelError),
+        (fastexcel.SchemaError, fastexcel.FastExcelError),
+        (fastexcel.CannotRetrieveCellDataError, fastexcel.CellError),
+        (fastexcel.CalamineCellError, fastexcel.CellError),
+        (fastexcel.UnsupportedColumnTypeCombinationError, fastexcel.SchemaError),
+        (fastexcel.ColumnNotFoundError, fastexcel.SchemaError),
+        (fastexcel.UnknownColumnError, fastexcel.SchemaError),
+        (fastexcel.InvalidDtypeError, fastexcel.InvalidParametersError),
+        (fastexcel.SheetNotFoundError, fastexcel.FastExcelError),
+        (fastexcel.CorruptFileError, fastexcel.CalamineError),
+        (fastexcel.SizeLimitExceededError, fastexcel.FastExcelError),
+        (fastexcel.ResourceLimitError, fastexcel.SizeLimitExceededError),
+        (fastexcel.DuplicateColumnError, fastexcel.SchemaError),
+        (fastexcel.InternalError, fastexcel.FastExcelError),
+    ],
+)
+def test_exception_hierarchy(exc_class: type[Exception], expected_base: type[Exception]) -> None:
+    assert issubclass(exc_class, expected_base)
+
+
+def test_invalid_dtype() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidDtypeError, match='unsupported dtype: "int32"') as exc_info:
+        excel_reader.load_sheet(0, dtypes={"Month": "int32"})  # type:ignore[dict-item]
+    # Still catchable as an invalid parameter
+    assert isinstance(exc_info.value, fastexcel.InvalidParametersError)
+
+
+@pytest.mark.parametrize(
+    "unknown_column, expected_message",
+    [("nope", 'column with name "nope"'), (42, "column at index 42")],
+)
+def test_dtype_for_unknown_column(unknown_column: str | int, expected_message: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    with pytest.raises(
+        fastexcel.UnknownColumnError,
+        match=f"dtype specified for unknown {expected_message}",
+    ) as exc_info:
+        excel_reader.load_sheet(0, dtypes={unknown_column: "int"})
+    assert isinstance(exc_info.value, fastexcel.ColumnNotFoundError)
+
+
+def test_truncated_file() -> None:
+    with open(path_for_fixture("fixture-single-sheet.xlsx"), "rb") as f:
+        content = f.read()
+
+    with pytest.raises(fastexcel.CorruptFileError, match="The file may be incomplete") as exc_info:
+        fastexcel.read_excel(content[: len(content) // 2])
+    # Still catchable as a calamine error
+    assert isinstance(exc_info.value, fastexcel.CalamineError)
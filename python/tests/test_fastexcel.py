@@ -1,16 +1,18 @@
 from __future__ import annotations
 
 from datetime import datetime
+from functools import partial
 from typing import Any
 
 import fastexcel
 import pandas as pd
 import polars as pl
+import pyarrow as pa
 import pytest
 from pandas.testing import assert_frame_equal as pd_assert_frame_equal
 from polars.testing import assert_frame_equal as pl_assert_frame_equal
 
-from utils import path_for_fixture
+from utils import load_sheet, path_for_fixture
 
 
 def test_single_sheet():
@@ -35,6 +37,83 @@ def test_single_sheet():
     pl_assert_frame_equal(sheet_by_idx.to_polars(), pl_expected)
 
 
+
+def test_load_first_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    sheet = excel_reader.load_first_sheet(n_rows=1)
+
+    assert sheet.name == excel_reader.sheet_names[0]
+    assert sheet.to_arrow() == excel_reader.load_sheet(0, n_rows=1).to_arrow()
+
+
+def test_column_names() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    for sheet_name in excel_reader.sheet_names:
+        assert excel_reader.column_names(sheet_name) == [
+            column.name for column in excel_reader.load_sheet(sheet_name).available_columns
+        ]
+
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-row-header.xlsx"))
+    assert excel_reader.column_names(0, header_depth=2) == [
+        "Region",
+        "Sales / Q1",
+        "Sales / Q2",
+        "Costs",
+    ]
+    assert excel_reader.column_names(0, header_row=None) == [
+        "__UNNAMED__0",
+        "__UNNAMED__1",
+        "__UNNAMED__2",
+        "__UNNAMED__3",
+    ]
+
+
+def test_sample() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    rb = excel_reader.sample("January", 1)
+
+    assert rb.num_rows == 1
+    full_rb = excel_reader.load_sheet_eager("January", schema_sample_rows=1)
+    assert rb.schema == full_rb.schema
+    assert rb == full_rb.slice(0, 1)
+
+
+def test_load_sheets_by_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    sheets = excel_reader.load_sheets(["February", "January"], n_rows=1)
+
+    assert list(sheets) == ["February", "January"]
+    assert sheets["January"].to_arrow() == excel_reader.load_sheet(0, n_rows=1).to_arrow()
+
+    with pytest.raises(
+        fastexcel.SheetNotFoundError,
+        match="'March', 'April'. Available sheets: 'January', 'February', 'With unnamed columns'",
+    ):
+        excel_reader.load_sheets(["January", "March", "April"])
+
+
+def test_load_sheets_lazily() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    sheets = excel_reader.load_sheets(lazy=True, n_rows=1)
+
+    assert list(sheets) == excel_reader.sheet_names
+    assert not any(sheet.is_loaded for sheet in sheets.values())
+
+    january = sheets["January"]
+    assert january.schema.names == ["Month", "Year"]
+    assert january.is_loaded
+    assert not sheets["February"].is_loaded
+    # The sheet is loaded and converted once
+    assert january.load() is january.load()
+    assert january.to_arrow() is january.to_arrow()
+    assert january.to_arrow() == excel_reader.load_sheet(0, n_rows=1).to_arrow()
+
+
 def test_single_sheet_bytes():
     with open(path_for_fixture("fixture-single-sheet.xlsx"), "rb") as f:
         excel_reader = fastexcel.read_excel(f.read())
@@ -94,6 +173,126 @@ def test_single_sheet_with_types():
     )
 
 
+
+def test_single_sheet_rows() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
+    sheet = excel_reader.load_sheet(0, dtypes={"__UNNAMED__0": "int"})
+
+    rows = list(sheet.rows())
+
+    assert rows == [
+        {
+            "__UNNAMED__0": idx,
+            "bools": idx != 1,
+            "dates": datetime(2022, 3, 2, 5, 43, 4),
+            "floats": floats,
+        }
+        for idx, floats in enumerate([12.35, 42.69, 1234567])
+    ]
+    # Rows are consistent with the arrow conversion
+    assert rows == sheet.to_arrow().to_pylist()
+
+
+def test_single_sheet_column_stats() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
+    sheet = excel_reader.load_sheet(0, dtypes={"__UNNAMED__0": "int"})
+
+    stats = {column_stats.column_name: column_stats for column_stats in sheet.column_stats()}
+
+    assert [(s.column_name, s.dtype, s.count, s.null_count) for s in stats.values()] == [
+        ("__UNNAMED__0", "int", 3, 0),
+        ("bools", "boolean", 3, 0),
+        ("dates", "datetime", 3, 0),
+        ("floats", "float", 3, 0),
+    ]
+    assert (stats["__UNNAMED__0"].min, stats["__UNNAMED__0"].max) == (0, 2)
+    assert (stats["floats"].min, stats["floats"].max) == (12.35, 1234567)
+    assert stats["dates"].min == stats["dates"].max == datetime(2022, 3, 2, 5, 43, 4)
+    assert (stats["bools"].min, stats["bools"].distinct_estimate) == (None, None)
+    assert stats["floats"].distinct_estimate is None
+
+
+def test_column_stats_of_string_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-transposed.xlsx"))
+    sheet = excel_reader.load_sheet(0, header_row=None, dtypes="string")
+
+    stats = sheet.column_stats()
+
+    assert [(s.count, s.null_count, s.distinct_estimate) for s in stats] == [
+        (2, 0, 2),
+        (2, 0, 2),
+        (2, 0, 2),
+        (2, 0, 2),
+    ]
+    assert stats[0].min is stats[0].max is None
+
+
+def test_single_sheet_schema_and_arrow() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0, dtypes={"Month": "int"})
+
+    schema, rb = sheet.schema_and_arrow()
+
+    assert schema == rb.schema
+    # The lazily computed schema has the same fields, but considers them all nullable
+    assert [(f.name, f.type) for f in sheet.schema] == [(f.name, f.type) for f in schema]
+    assert schema.field("Month").type == pa.int64()
+    assert rb.to_pydict() == {"Month": [1, 2], "Year": [2019.0, 2020.0]}
+
+
+def test_tighten_nullability() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    # Without tighten_nullability, only the RecordBatch has non-nullable fields
+    sheet = excel_reader.load_sheet(0)
+    assert all(field.nullable for field in sheet.schema)
+    assert not any(field.nullable for field in sheet.to_arrow().schema)
+
+    sheet = excel_reader.load_sheet(0, tighten_nullability=True)
+    schema = sheet.schema
+
+    assert not any(field.nullable for field in schema)
+    assert all(batch.schema == schema for batch in sheet.to_arrow_batches(1))
+    assert sheet.to_arrow().schema == schema
+
+    # A column without values stays nullable
+    null_sheet = fastexcel.read_excel(path_for_fixture("null-column.xlsx")).load_sheet(
+        0, tighten_nullability=True
+    )
+    assert null_sheet.schema.field("nullonly").nullable is True
+    assert null_sheet.to_arrow().schema == null_sheet.schema
+
+
+def test_sheet_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0, dtypes={"Month": "int"})
+
+    assert sheet.columns == [("Month", "int64"), ("Year", "double")]
+    assert sheet.columns == [(f.name, str(f.type)) for f in sheet.schema]
+
+
+def test_size_limits() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    with pytest.raises(
+        fastexcel.SizeLimitExceededError, match="the sheet has 2 columns, more than max_columns=1"
+    ):
+        excel_reader.load_sheet(0, max_columns=1)
+    with pytest.raises(
+        fastexcel.SizeLimitExceededError, match="the sheet has 2 rows, more than max_rows=1"
+    ):
+        excel_reader.load_sheet_eager(0, max_rows=1)
+
+    sheet = excel_reader.load_sheet(0, max_columns=1, max_rows=1, on_size_limit="truncate")
+    assert sheet.to_arrow().to_pydict() == {"Month": [1.0]}
+    # Exceeding max_cells is an error, even when truncating
+    with pytest.raises(fastexcel.ResourceLimitError, match="more than max_cells=5"):
+        excel_reader.load_sheet(0, max_cells=5, on_size_limit="truncate")
+    assert excel_reader.load_sheet(0, max_cells=6).height == 2
+
+    # Sheets within the limits are read as-is
+    assert excel_reader.load_sheet(0, max_columns=2, max_rows=2).height == 2
+
+
 def test_multiple_sheets():
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
     assert excel_reader.sheet_names == ["January", "February", "With unnamed columns"]
@@ -211,6 +410,189 @@ def test_sheets_with_empty_rows_before_header():
     pl_assert_frame_equal(sheet_by_idx.to_polars(), pl_expected)
 
 
+@pytest.mark.parametrize("eager", [True, False])
+def test_stop_at_empty_row(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-data-followed-by-notes.xlsx"))
+
+    # Without stop_at_empty_row, the notes following the data are read as well
+    assert excel_reader.load_sheet(0).height == 5
+
+    rb = (
+        excel_reader.load_sheet_eager(0, stop_at_empty_row=True)
+        if eager
+        else excel_reader.load_sheet(0, stop_at_empty_row=True).to_arrow()
+    )
+    assert rb.to_pydict() == {"name": ["a", "b"], "value": [1.0, 2.0]}
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_data_rows(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-data-followed-by-notes.xlsx"))
+
+    rb = (
+        excel_reader.load_sheet_eager(0, data_rows=[2, 1])
+        if eager
+        else excel_reader.load_sheet(0, data_rows=[2, 1]).to_arrow()
+    )
+    assert rb.to_pydict() == {"name": ["b", "a"], "value": [2.0, 1.0]}
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="row 10"):
+        excel_reader.load_sheet(0, data_rows=[1, 10])
+
+
+@pytest.mark.parametrize("eager", [True, False])
+@pytest.mark.parametrize("from_bytes", [True, False])
+def test_respect_autofilter(eager: bool, from_bytes: bool) -> None:
+    path = path_for_fixture("fixture-autofilter.xlsx")
+    if from_bytes:
+        with open(path, "rb") as f:
+            excel_reader = fastexcel.read_excel(f.read())
+    else:
+        excel_reader = fastexcel.read_excel(path)
+
+    # Without respect_autofilter, the rows hidden by the autofilter are read as well
+    assert excel_reader.load_sheet(0).height == 5
+
+    rb = (
+        excel_reader.load_sheet_eager(0, respect_autofilter=True)
+        if eager
+        else excel_reader.load_sheet(0, respect_autofilter=True).to_arrow()
+    )
+    assert rb.to_pydict() == {"name": ["a", "c", "e"], "value": [1.0, 3.0, 5.0]}
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_transpose(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-transposed.xlsx"))
+
+    rb = (
+        excel_reader.load_sheet_eager(0, transpose=True)
+        if eager
+        else excel_reader.load_sheet(0, transpose=True).to_arrow()
+    )
+    assert rb.to_pydict() == {"name": ["a", "b", "c"], "value": [1.0, 2.0, 3.0]}
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="respect_autofilter"):
+        excel_reader.load_sheet(0, transpose=True, respect_autofilter=True)
+
+
+def test_respect_autofilter_requires_xlsx() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("dates.ods"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="only supported for xlsx"):
+        excel_reader.load_sheet(0, respect_autofilter=True)
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_keep_header_in_data(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-data-followed-by-notes.xlsx"))
+    expected = {"name": ["name", "a", "b"], "value": ["value", "1", "2"]}
+
+    if eager:
+        rb = excel_reader.load_sheet_eager(0, n_rows=3, keep_header_in_data=True)
+    else:
+        sheet = excel_reader.load_sheet(0, n_rows=3, keep_header_in_data=True)
+        assert sheet.header_row == 0
+        rb = sheet.to_arrow()
+    assert rb.to_pydict() == expected
+
+    with pytest.raises(fastexcel.InvalidParametersError):
+        excel_reader.load_sheet(0, header_row=None, keep_header_in_data=True)
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_multi_row_header(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-row-header.xlsx"))
+    expected = {
+        "Region": ["EU", "US"],
+        "Sales / Q1": [1.0, 4.0],
+        "Sales / Q2": [2.0, 5.0],
+        "Costs": [3.0, 6.0],
+    }
+
+    if eager:
+        rb = excel_reader.load_sheet_eager(0, header_depth=2)
+        assert rb.to_pydict() == expected
+    else:
+        sheet = excel_reader.load_sheet(0, header_depth=2)
+        assert sheet.header_row == 0
+        assert sheet.to_arrow().to_pydict() == expected
+
+    sheet = excel_reader.load_sheet(0, header_depth=2, header_separator="_")
+    assert [column.name for column in sheet.selected_columns] == [
+        "Region",
+        "Sales_Q1",
+        "Sales_Q2",
+        "Costs",
+    ]
+
+    with pytest.raises(fastexcel.InvalidParametersError):
+        excel_reader.load_sheet(0, header_row=None, header_depth=2)
+
+
+@pytest.mark.parametrize("eager", [True, False])
+@pytest.mark.parametrize("sheet_name,expected_header_row", [("Sheet1", 1), ("Sheet3", 0)])
+def test_sheets_with_auto_header(sheet_name: str, expected_header_row: int, eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+    expected = {"Month": [1.0, 2.0], "Year": [2019.0, 2020.0]}
+
+    if eager:
+        rb = excel_reader.load_sheet_eager(sheet_name, auto_header=True)
+        pl_assert_frame_equal(pl.from_arrow(rb), pl.DataFrame(expected))  # type:ignore[arg-type]
+    else:
+        sheet = excel_reader.load_sheet(sheet_name, auto_header=True)
+        assert sheet.header_row == expected_header_row
+        pl_assert_frame_equal(sheet.to_polars(), pl.DataFrame(expected))
+
+
+def test_sheet_with_auto_header_fallback() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+
+    with pytest.warns(UserWarning, match='could not detect the header row of sheet "Sheet2"'):
+        sheet = excel_reader.load_sheet("Sheet2", auto_header=True)
+    assert sheet.header_row == 0
+
+
+@pytest.mark.parametrize("eager", [True, False])
+@pytest.mark.parametrize("sheet_name,expected_header_row", [("Sheet1", 1), ("Sheet3", 0)])
+def test_sheets_with_expected_headers(
+    sheet_name: str, expected_header_row: int, eager: bool
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+    expected = {"Month": [1.0, 2.0], "Year": [2019.0, 2020.0]}
+
+    if eager:
+        rb = excel_reader.load_sheet_eager(sheet_name, expected_headers=["Year", "Month"])
+        pl_assert_frame_equal(pl.from_arrow(rb), pl.DataFrame(expected))  # type:ignore[arg-type]
+    else:
+        sheet = excel_reader.load_sheet(sheet_name, expected_headers=["Year", "Month"])
+        assert sheet.header_row == expected_header_row
+        pl_assert_frame_equal(sheet.to_polars(), pl.DataFrame(expected))
+
+
+def test_expected_headers_not_found() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match='could not find the header row of sheet "Sheet2"'
+    ):
+        excel_reader.load_sheet("Sheet2", expected_headers=["Month"])
+    # The header of Sheet1 is on its second row
+    with pytest.raises(fastexcel.InvalidParametersError, match="no row in the first 1 rows"):
+        excel_reader.load_sheet("Sheet1", expected_headers=["Month"], header_search_limit=1)
+    with pytest.raises(fastexcel.InvalidParametersError, match="cannot be used with"):
+        excel_reader.load_sheet("Sheet1", expected_headers=["Month"], auto_header=True)
+
+
+def test_auto_header_with_column_names() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match="`auto_header` cannot be used with `column_names`"
+    ):
+        excel_reader.load_sheet("Sheet2", auto_header=True, column_names=["foo", "bar", "baz"])
+
+
 def test_sheets_with_custom_headers():
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
     assert excel_reader.sheet_names == ["Sheet1", "Sheet2", "Sheet3"]
@@ -414,15 +796,17 @@ def test_sheet_with_pagination_out_of_bound():
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
     assert excel_reader.sheet_names == ["Sheet1"]
 
-    with pytest.raises(
-        fastexcel.InvalidParametersError, match="Too many rows skipped. Max height is 4"
-    ):
-        excel_reader.load_sheet(
-            0,
-            skip_rows=1000000,
-            header_row=None,
-            column_names=["This", "Is", "Amazing", "Stuff"],
-        )
+    # Skipping more rows than the sheet has results in an empty batch
+    sheet = excel_reader.load_sheet(
+        0,
+        skip_rows=1000000,
+        header_row=None,
+        column_names=["This", "Is", "Amazing", "Stuff"],
+    )
+    assert sheet.height == 0
+    rb = sheet.to_arrow()
+    assert rb.num_rows == 0
+    assert rb.schema.names == ["This", "Is", "Amazing", "Stuff"]
 
     sheet = excel_reader.load_sheet(
         0,
@@ -536,3 +920,226 @@ def test_null_values_in_cells() -> None:
 def test_null_column_is_nullable() -> None:
     sheet = fastexcel.read_excel(path_for_fixture("null-column.xlsx")).load_sheet(0)
     assert sheet.to_arrow().schema.field("nullonly").nullable is True
+
+
+def test_validate_header() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+
+    # The header of Sheet1 is on the second row, so the first one is not suspicious
+    sheet = excel_reader.load_sheet("Sheet1", header_row=1, validate_header=True)
+    assert sheet.header_warnings == []
+
+    # Sheet2 has no header, its first row contains data
+    with pytest.warns(UserWarning, match="the header row may be misidentified"):
+        sheet = excel_reader.load_sheet("Sheet2", validate_header=True)
+    assert [
+        (warning.column_index, warning.header_dtype, warning.column_dtype)
+        for warning in sheet.header_warnings
+    ] == [(0, "float", "float"), (1, "float", "float"), (2, "float", "float")]
+
+    # Not validated by default
+    assert excel_reader.load_sheet("Sheet2").header_warnings == []
+
+
+def test_single_column_export() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+
+    column = sheet.column("Year")
+    assert column.name == "Year"
+    assert len(column) == 2
+    # The column implements the Arrow PyCapsule interface
+    assert pa.array(column) == column.to_arrow() == pa.array([2019.0, 2020.0])
+    assert sheet.column(0).to_arrow() == sheet.to_arrow().column("Month")
+
+    with pytest.raises(fastexcel.ColumnNotFoundError):
+        sheet.column("Day")
+
+    table = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx")).load_table("users")
+    assert pa.array(table.column("LastName")) == table.to_arrow().column("LastName")
+
+
+@pytest.mark.parametrize("from_bytes", [True, False])
+def test_shared_strings(from_bytes: bool) -> None:
+    path = path_for_fixture("fixture-shared-strings.xlsx")
+    if from_bytes:
+        with open(path, "rb") as f:
+            excel_reader = fastexcel.read_excel(f.read())
+    else:
+        excel_reader = fastexcel.read_excel(path)
+
+    # Duplicates are dropped, rich text runs are concatenated without their phonetic hints
+    assert excel_reader.shared_strings() == ["name", "apple", "pear", "rich text", ""]
+
+    # Strings stored inline are not part of the shared strings table
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-autofilter.xlsx"))
+    assert excel_reader.shared_strings() == []
+
+    excel_reader = fastexcel.read_excel(path_for_fixture("dates.ods"))
+    with pytest.raises(fastexcel.InvalidParametersError, match="only supported for xlsx files"):
+        excel_reader.shared_strings()
+
+
+def test_load_sheet_as_strings() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-invalid-cell-value.xlsx"))
+
+    rb = excel_reader.load_sheet_as_strings(0)
+
+    # The header is part of the data, and error cells are null
+    assert rb.schema == pa.schema([("__UNNAMED__0", pa.string()), ("__UNNAMED__1", pa.string())])
+    assert rb.to_pydict() == {
+        "__UNNAMED__0": ["Title", "A", "B", "C", "D"],
+        "__UNNAMED__1": ["Date", None, None, "2021-01-01 00:00:00", "2021-05-05 00:00:00"],
+    }
+
+    rb = excel_reader.load_sheet_as_strings(0, skip_rows=1, n_rows=1, column_prefix="col_")
+    assert rb.to_pydict() == {"col_0": ["A"], "col_1": [None]}
+
+
+def test_repr_shows_dimensions_and_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, dtypes={"Month": "int"})
+    assert repr(sheet) == "ExcelSheet<January, 2x2, [Month:int, Year:float]>"
+    assert repr(excel_reader.load_sheet(0, n_rows=1, use_columns=["Year"])) == (
+        "ExcelSheet<January, 1x1, [Year:float]>"
+    )
+
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+    assert repr(excel_reader.load_table("users")) == (
+        "ExcelTable<sheet1/users, 3x4, "
+        "[User Id:float, FirstName:string, LastName:string, Date:datetime]>"
+    )
+
+
+def test_data_start_row() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    # The first data row is treated as a gap between the header and the data
+    sheet = excel_reader.load_sheet(0, data_start_row=2)
+    assert sheet.to_arrow().to_pydict() == {"Month": [2.0], "Year": [2020.0]}
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match="must be greater than the header rows"
+    ):
+        excel_reader.load_sheet(0, header_row=1, data_start_row=1)
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="can only be used with a header"):
+        excel_reader.load_sheet(0, header_row=None, data_start_row=1)
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_cell_range(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-cell-range.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager)
+
+    # The table is surrounded by a title, a note and a total
+    assert load(cell_range="B3:C5").to_pydict() == {"name": ["a", "b"], "value": [1.0, 2.0]}
+    assert load(cell_range="B3:C10", n_rows=1).to_pydict() == {"name": ["a"], "value": [1.0]}
+    assert load(cell_range="B4:C5", header_row=None).to_pydict() == {
+        "__UNNAMED__0": ["a", "b"],
+        "__UNNAMED__1": [1.0, 2.0],
+    }
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="end of cell_range is before"):
+        load(cell_range="C5:B3")
+    with pytest.raises(fastexcel.InvalidParametersError, match="invalid cell_range"):
+        load(cell_range="B3")
+
+
+def test_origin_and_cell_reference() -> None:
+    # A title in A1, and a table in Y3:AB6 with columns id, name, price and total
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-wide-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, header_row=2)
+    assert sheet.origin == (3, 0)
+    assert sheet.cell_reference(0, "total") == "AB4"
+    assert sheet.cell_reference(2, 26) == "AA6"
+    assert sheet.to_arrow()["total"][2].as_py() == 8.0
+
+    sheet = excel_reader.load_sheet(0, header_row=2, skip_rows=1)
+    assert sheet.origin == (4, 0)
+    assert sheet.cell_reference(0, "total") == "AB5"
+
+    sheet = excel_reader.load_sheet(0, cell_range="Y3:AB6")
+    assert sheet.origin == (3, 24)
+    assert sheet.cell_reference(1, "name") == "Z5"
+
+    # Selected data rows keep their row in the worksheet
+    sheet = excel_reader.load_sheet(0, header_row=2, data_rows=[5, 3])
+    assert sheet.origin == (5, 0)
+    assert [sheet.cell_reference(row, "total") for row in range(2)] == ["AB6", "AB4"]
+
+    # The rows of a transposed sheet are the columns of the worksheet
+    sheet = excel_reader.load_sheet(0, cell_range="Y3:AB6", transpose=True)
+    assert sheet.origin == (2, 25)
+    assert sheet.cell_reference(2, 1) == "AB4"
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="row 3 is out of bounds"):
+        sheet.cell_reference(3, 1)
+    with pytest.raises(fastexcel.ColumnNotFoundError):
+        sheet.cell_reference(0, "missing")
+
+
+def test_n_threads() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    assert (
+        excel_reader.load_sheet(0, n_threads=1).to_arrow()
+        == excel_reader.load_sheet(0, n_threads=4).to_arrow()
+    )
+    with pytest.raises(fastexcel.InvalidParametersError, match="must be at least 1"):
+        excel_reader.load_sheet(0, n_threads=0)
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_fill_merged_cells(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-merged-cells.xlsx"))
+
+    load = partial(load_sheet, excel_reader, 0, eager)
+
+    assert load().column("category").to_pylist() == ["fruit", None, "vegetable", None]
+    rb = load(fill_merged_cells=True)
+    assert rb.to_pydict() == {
+        "category": ["fruit", "fruit", "vegetable", "vegetable"],
+        "item": ["apple", "pear", "leek", "kale"],
+        "price": [1.5, 2.0, 3.0, 3.0],
+    }
+    # The merged cells are filled before the dtypes are guessed
+    rb = load(fill_merged_cells=True, skip_rows=3)
+    assert rb.schema.field("category").type == pa.string()
+    assert rb.column("category").to_pylist() == ["vegetable"]
+
+
+def test_fill_merged_cells_unsupported_format() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("dates.ods"))
+
+    with pytest.warns(UserWarning, match="merged cells can only be filled for xlsx files"):
+        sheet = excel_reader.load_sheet(0, fill_merged_cells=True)
+    assert sheet.to_arrow() == excel_reader.load_sheet(0).to_arrow()
+
+
+def test_forward_fill() -> None:
+    # The category column is only filled on the first row of each group
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-merged-cells.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, forward_fill=["category", 2])
+    assert sheet.to_arrow().to_pydict() == {
+        "category": ["fruit", "fruit", "vegetable", "vegetable"],
+        "item": ["apple", "pear", "leek", "kale"],
+        "price": [1.5, 2.0, 3.0, 3.0],
+    }
+    # Batches continue the previous one
+    batches = list(sheet.to_arrow_batches(batch_size=1))
+    assert pa.Table.from_batches(batches).to_pydict() == sheet.to_arrow().to_pydict()
+    # So do rows
+    assert [row["category"] for row in sheet.rows()] == ["fruit", "fruit", "vegetable", "vegetable"]
+    assert [row["price"] for row in sheet.rows()] == [1.5, 2.0, 3.0, 3.0]
+    assert (
+        excel_reader.load_sheet_eager(0, forward_fill=["category"]).column("category").to_pylist()
+        == ["fruit", "fruit", "vegetable", "vegetable"]
+    )
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="cannot both be forward-filled"):
+        excel_reader.load_sheet(0, forward_fill=["category"], fill_values={"category": "none"})
@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+import fastexcel
+import pyarrow as pa
+import pytest
+
+from utils import path_for_fixture
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_value_and_formula(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-formulas.xlsx"))
+
+    if eager:
+        rb = excel_reader.load_sheet_eager(0, cells="value_and_formula")
+    else:
+        sheet = excel_reader.load_sheet(0, cells="value_and_formula")
+        assert sheet.schema.names == [
+            "price",
+            "price__formula",
+            "total",
+            "total__formula_1",
+            "total__formula",
+            "total__formula__formula",
+        ]
+        rb = sheet.to_arrow()
+
+    assert rb.schema.field("total__formula_1").type == pa.string()
+    assert rb.to_pydict() == {
+        "price": [2.0, 3.0, 5.0],
+        "price__formula": [None, None, "SUM(A2:A3)"],
+        "total": [4.0, 10.0, 10.0],
+        # The sheet already has a "total__formula" column
+        "total__formula_1": ["A2*2", None, "A4*2"],
+        "total__formula": ["note", None, None],
+        "total__formula__formula": [None, None, None],
+    }
+
+
+def test_value_and_formula_of_selected_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-formulas.xlsx"))
+    sheet = excel_reader.load_sheet(0, use_columns=["total"], cells="value_and_formula")
+
+    assert sheet.to_arrow().to_pydict() == {
+        "total": [4.0, 10.0, 10.0],
+        "total__formula_1": ["A2*2", None, "A4*2"],
+    }
+    batches = list(sheet.to_arrow_batches(batch_size=2))
+    assert [batch.num_rows for batch in batches] == [2, 1]
+    assert pa.Table.from_batches(batches).to_pydict() == sheet.to_arrow().to_pydict()
+    assert list(sheet.rows()) == [
+        {"total": 4.0, "total__formula_1": "A2*2"},
+        {"total": 10.0, "total__formula_1": None},
+        {"total": 10.0, "total__formula_1": "A4*2"},
+    ]
+
+
+def test_value_and_formula_invalid_parameters() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-formulas.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="cannot be used with `transpose`"):
+        excel_reader.load_sheet(0, cells="value_and_formula", transpose=True)
+    with pytest.raises(fastexcel.InvalidParametersError, match="unsupported cells"):
+        excel_reader.load_sheet(0, cells="formulas")  # type: ignore[arg-type]
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import fastexcel
+import pandas as pd
+import pytest
+from pandas.testing import assert_frame_equal as pd_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+@pytest.mark.parametrize("index_column", ["Year", 1])
+def test_index_column(index_column: str | int) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0, index_column=index_column)
+
+    assert [col.name for col in sheet.selected_columns] == ["Year", "Month"]
+
+    rb = sheet.to_arrow()
+    assert rb.schema.names == ["Year", "Month"]
+    assert rb.schema.field("Year").metadata == {b"fastexcel.index_column": b"true"}
+    assert rb.schema.field("Month").metadata is None
+
+    pd_assert_frame_equal(
+        sheet.to_pandas(),
+        pd.DataFrame({"Month": [1.0, 2.0]}, index=pd.Index([2019.0, 2020.0], name="Year")),
+    )
+
+
+def test_index_column_eager() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    rb = excel_reader.load_sheet_eager(0, index_column="Year")
+
+    assert rb.schema.names == ["Year", "Month"]
+    assert rb.schema.field("Year").metadata == {b"fastexcel.index_column": b"true"}
+
+
+def test_index_column_must_be_selected() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match='column with name "Month"'):
+        excel_reader.load_sheet(0, use_columns=["Year"], index_column="Month")
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match="column at index 42"):
+        excel_reader.load_sheet(0, index_column=42)
@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import fastexcel
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+from utils import path_for_fixture
+
+
+def test_sheet_to_parquet_defaults(tmp_path: Path) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(1)
+    output = tmp_path / "sheet.parquet"
+
+    sheet.to_parquet(output)
+
+    metadata = pq.read_metadata(output)
+    assert metadata.num_rows == 3
+    assert metadata.num_row_groups == 1
+    assert metadata.row_group(0).column(0).compression == "ZSTD"
+    assert pq.read_table(output).to_pydict() == sheet.to_arrow().to_pydict()
+
+
+def test_sheet_to_parquet_row_group_size_and_compression(tmp_path: Path) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    output = tmp_path / "sheet.parquet"
+
+    excel_reader.load_sheet(1).to_parquet(output, row_group_size=2, compression="snappy")
+
+    metadata = pq.read_metadata(output)
+    assert metadata.num_row_groups == 2
+    assert metadata.row_group(0).num_rows == 2
+    assert metadata.row_group(1).num_rows == 1
+    assert metadata.row_group(0).column(0).compression == "SNAPPY"
+
+
+def test_table_to_parquet(tmp_path: Path) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+    table = excel_reader.load_table("users")
+    output = tmp_path / "table.parquet"
+
+    table.to_parquet(output)
+
+    assert pq.read_table(output).to_pydict() == table.to_arrow().to_pydict()
+
+
+def test_sheet_to_arrow_batches() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(1)
+
+    batches = list(sheet.to_arrow_batches(2))
+
+    assert [batch.num_rows for batch in batches] == [2, 1]
+    assert batches[0].schema == batches[1].schema == sheet.schema
+    assert pa.Table.from_batches(batches).to_pydict() == sheet.to_arrow().to_pydict()
+
+
+def test_sheet_to_parquet_in_batches(tmp_path: Path) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(1)
+    output = tmp_path / "sheet.parquet"
+
+    sheet.to_parquet(output, batch_size=2)
+
+    metadata = pq.read_metadata(output)
+    assert metadata.num_row_groups == 1
+    assert metadata.row_group(0).num_rows == 3
+    assert pq.read_table(output).to_pydict() == sheet.to_arrow().to_pydict()
+
+
+def test_sheet_to_parquet_in_batches_fills_row_groups(tmp_path: Path) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(1)
+    output = tmp_path / "sheet.parquet"
+
+    sheet.to_parquet(output, batch_size=1, row_group_size=2)
+
+    metadata = pq.read_metadata(output)
+    assert [metadata.row_group(idx).num_rows for idx in range(metadata.num_row_groups)] == [2, 1]
+    assert pq.read_table(output).to_pydict() == sheet.to_arrow().to_pydict()
+
+
+def test_to_arrow_batches_requires_a_positive_batch_size() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="`batch_size` must be greater"):
+        excel_reader.load_sheet(1).to_arrow_batches(0)
@@ -0,0 +1,67 @@
+from __future__ import annotations
+
+import shutil
+from pathlib import Path
+
+import fastexcel
+import pandas as pd
+import pyarrow as pa
+import pytest
+from pandas.testing import assert_frame_equal as pd_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+def test_read_many_with_glob(tmp_path: Path) -> None:
+    # Globbing the fixtures directory would pick up any fixture added later
+    for fixture in ["fixture-multi-sheet.xlsx", "fixture-single-sheet.xlsx"]:
+        shutil.copy(path_for_fixture(fixture), tmp_path / fixture)
+    rb = fastexcel.read_many([str(tmp_path / "fixture-*sheet.xlsx")], "January")
+
+    pd_assert_frame_equal(
+        rb.to_pandas(),
+        pd.DataFrame({"Month": [1.0, 1.0, 2.0], "Year": [2019.0, 2019.0, 2020.0]}),
+    )
+
+
+def test_read_many_with_source_column() -> None:
+    paths = [
+        path_for_fixture("fixture-multi-sheet.xlsx"),
+        path_for_fixture("fixture-single-sheet.xlsx"),
+    ]
+    rb = fastexcel.read_many(paths, 0, source_column="source")
+
+    assert rb.schema.field("source").type == pa.string()
+    assert rb.column("source").to_pylist() == [paths[0], paths[1], paths[1]]
+
+
+def test_read_many_missing_columns_are_null() -> None:
+    rb = fastexcel.read_many(
+        [
+            path_for_fixture("fixture-single-sheet.xlsx"),
+            path_for_fixture("fixture-single-sheet-with-types.xlsx"),
+        ]
+    )
+
+    assert rb.schema.names == ["Month", "Year", "__UNNAMED__0", "bools", "dates", "floats"]
+    assert rb.num_rows == 5
+    assert rb.column("Month").to_pylist() == [1.0, 2.0, None, None, None]
+    assert rb.column("bools").to_pylist() == [None, None, True, False, True]
+
+
+def test_read_many_forwards_parameters() -> None:
+    rb = fastexcel.read_many(
+        [
+            path_for_fixture("fixture-multi-sheet.xlsx"),
+            path_for_fixture("fixture-single-sheet.xlsx"),
+        ],
+        use_columns=["Year"],
+    )
+
+    assert rb.schema.names == ["Year"]
+    assert rb.column("Year").to_pylist() == [2019.0, 2019.0, 2020.0]
+
+
+def test_read_many_pattern_without_match() -> None:
+    with pytest.raises(fastexcel.InvalidParametersError, match="did not match any file"):
+        fastexcel.read_many([path_for_fixture("does-not-exist-*.xlsx")])
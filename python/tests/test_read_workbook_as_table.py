@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+import fastexcel
+import pyarrow as pa
+import pytest
+
+from utils import path_for_fixture
+
+
+def test_read_workbook_as_table_stacks_sheets() -> None:
+    rb = fastexcel.read_workbook_as_table(
+        path_for_fixture("fixture-monthly-sheets.xlsx"),
+        sheet_names=["January", "February"],
+    )
+
+    assert rb.to_pydict() == {
+        "item": ["apples", "pears", "apples"],
+        "quantity": [3.0, 5.0, 4.0],
+        "sheet": ["January", "January", "February"],
+    }
+
+
+def test_read_workbook_as_table_conflicting_dtypes() -> None:
+    path = path_for_fixture("fixture-monthly-sheets.xlsx")
+
+    # "quantity" is a float column in January and February, but a string one in March
+    with pytest.raises(
+        fastexcel.UnsupportedColumnTypeCombinationError, match='column "quantity" is of type'
+    ):
+        fastexcel.read_workbook_as_table(path, skip_hidden=True)
+
+    rb = fastexcel.read_workbook_as_table(
+        path, skip_hidden=True, promote_types=True, sheet_column="month"
+    )
+
+    assert rb.schema.field("quantity").type == pa.string()
+    assert rb.to_pydict() == {
+        "item": ["apples", "pears", "apples", "pears"],
+        "quantity": ["3.0", "5.0", "4.0", "unknown"],
+        "month": ["January", "January", "February", "March"],
+        "comment": [None, None, None, "late delivery"],
+    }
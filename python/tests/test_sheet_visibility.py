@@ -11,3 +11,14 @@ def test_sheet_visibilities() -> None:
     assert reader.load_sheet(0).visible == "visible"
     assert reader.load_sheet(1).visible == "hidden"
     assert reader.load_sheet(2).visible == "veryhidden"
+
+
+def test_load_sheets_skip_hidden() -> None:
+    reader = fastexcel.read_excel(path_for_fixture("fixture-sheets-different-visibilities.xlsx"))
+
+    all_sheets = reader.load_sheets()
+    assert list(all_sheets) == reader.sheet_names
+    assert [sheet.visible for sheet in all_sheets.values()] == ["visible", "hidden", "veryhidden"]
+
+    visible_sheets = reader.load_sheets(skip_hidden=True)
+    assert list(visible_sheets) == reader.visible_sheet_names == [reader.sheet_names[0]]
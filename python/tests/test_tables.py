@@ -29,6 +29,45 @@ def test_table_names_with_sheet_name(path: str) -> None:
 
     assert table_names == []
 
+    with pytest.raises(fastexcel.SheetNotFoundError, match='Available sheets: "sheet1"'):
+        excel_reader.table_names("sheet3")
+
+
+def test_tables() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+
+    # The table's reference is A6:D9, the first row being the header
+    assert excel_reader.tables() == [("users", "sheet1", "A7:D9")]
+    assert excel_reader.tables("sheet2") == []
+    with pytest.raises(fastexcel.SheetNotFoundError, match='sheet with name "sheet3" not found'):
+        excel_reader.tables("sheet3")
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match="tables are not supported for ODS files"
+    ):
+        fastexcel.read_excel(path_for_fixture("dates.ods")).tables()
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_table_with_duplicate_and_blank_column_names(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("table-with-duplicate-column-names.xlsx")
+    )
+
+    rb = (
+        excel_reader.load_table("measures", eager=True)
+        if eager
+        else excel_reader.load_table("measures").to_arrow()
+    )
+    # Names are deduplicated and generated the same way as for sheets
+    assert rb.schema.names == ["id", "value", "value_1", "__UNNAMED__3"]
+    assert rb.to_pydict() == {
+        "id": [1.0, 2.0],
+        "value": [10.0, 11.0],
+        "value_1": [20.0, 21.0],
+        "__UNNAMED__3": [30.0, 31.0],
+    }
+
 
 @pytest.mark.parametrize("path", ("sheet-with-tables.xlsx",))
 def test_load_table(path: str) -> None:
@@ -101,3 +140,40 @@ def test_load_table(path: str) -> None:
     assert isinstance(pl_df, pl.DataFrame)
     pl_assert_frame_equal(pl_df, expected_pl)
     pd_assert_frame_equal(table_eager.to_pandas(), expected_pd)
+
+
+def test_table_schema_and_arrow() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+    users_tbl = excel_reader.load_table("users")
+
+    schema, rb = users_tbl.schema_and_arrow()
+
+    assert schema == rb.schema
+    # The lazily computed schema has the same fields, but considers them all nullable
+    assert [(f.name, f.type) for f in users_tbl.schema] == [(f.name, f.type) for f in schema]
+    assert rb.num_rows == users_tbl.height
+
+
+def test_table_tighten_nullability() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+    users_tbl = excel_reader.load_table("users", tighten_nullability=True)
+
+    assert users_tbl.to_arrow().schema == users_tbl.schema
+
+
+def test_load_table_without_header() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+
+    # The header row of the table is read as a data row
+    table = excel_reader.load_table("users", table_has_header=False, use_columns=[1, 2])
+    assert table.height == 4
+    assert table.to_arrow().to_pydict() == {
+        "__UNNAMED__1": ["FirstName", "Peter", "John", "Hans"],
+        "__UNNAMED__2": ["LastName", "Müller", "Meier", "Fricker"],
+    }
+
+    # It can still be used as header explicitly
+    table = excel_reader.load_table(
+        "users", table_has_header=False, header_row=0, use_columns=["FirstName"]
+    )
+    assert table.to_arrow().to_pydict() == {"FirstName": ["Peter", "John", "Hans"]}
@@ -1,6 +1,21 @@
+from __future__ import annotations
+
 from os.path import dirname
 from os.path import join as path_join
+from typing import Any
+
+import fastexcel
+import pyarrow as pa
 
 
 def path_for_fixture(fixture_file: str) -> str:
     return path_join(dirname(__file__), "fixtures", fixture_file)
+
+
+def load_sheet(
+    excel_reader: fastexcel.ExcelReader, idx_or_name: int | str, eager: bool, **kwargs: Any
+) -> pa.RecordBatch:
+    """Loads a sheet with `load_sheet_eager` if `eager`, or with `load_sheet` otherwise"""
+    if eager:
+        return excel_reader.load_sheet_eager(idx_or_name, **kwargs)
+    return excel_reader.load_sheet(idx_or_name, **kwargs).to_arrow()
@@ -1,19 +1,44 @@
-use std::sync::Arc;
+use std::{fmt::Debug, sync::Arc};
 
 use arrow::{
     array::{Array, NullArray, RecordBatch},
-    datatypes::{Field, Schema},
+    compute::concat,
+    datatypes::Schema,
 };
-use calamine::{Data as CalData, DataRef as CalDataRef, DataType, Range};
+use calamine::{CellType, Data as CalData, DataRef as CalDataRef, DataType, Range};
+use chrono::NaiveTime;
+use pyo3::{PyObject, Python, ToPyObject};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::{
     error::{ErrorContext, FastExcelErrorKind, FastExcelResult},
     types::{
         dtype::{get_dtype_for_column, DType, DTypeCoercion},
-        python::excelsheet::column_info::ColumnInfo,
+        python::excelsheet::{
+            column_info::{ColumnInfo, CURRENCY_METADATA_KEY},
+            column_stats::has_null_cells,
+        },
+        read_options::{FillValue, ReadOptions},
     },
+    utils::{schema::SampleRows, thread_pool},
 };
 
+use self::array_impls::{
+    create_boolean_array, create_boolean_from_int_array, create_date_array, create_datetime_array,
+    create_datetime_tz_array, create_duration_array, create_excel_serial_array, create_float_array,
+    create_int_array, create_string_array, fill_nulls, forward_fill,
+};
+use self::cell_values::{
+    cell_to_bool, cell_to_bool_from_int, cell_to_date, cell_to_datetime, cell_to_excel_serial,
+    cell_to_float, cell_to_int, cell_to_string, cell_to_utc_datetime, invalid_bool_from_int_error,
+    invalid_bool_value_error, invalid_currency_amount_error, invalid_offset_datetime_error,
+    is_currency_amount, strict_cell_error,
+};
+
+/// Rows containing fewer cells than this are read on a single thread, as dispatching their columns
+/// to the thread pool would cost more than it saves
+const PARALLEL_READ_MIN_CELLS: usize = 100_000;
+
 pub(crate) enum ExcelSheetData<'r> {
     Owned(Range<CalData>),
     Ref(Range<CalDataRef<'r>>),
@@ -34,6 +59,14 @@ impl ExcelSheetData<'_> {
         }
     }
 
+    /// The absolute position of the first cell of the data in the sheet
+    pub(crate) fn start(&self) -> Option<(u32, u32)> {
+        match self {
+            ExcelSheetData::Owned(range) => range.start(),
+            ExcelSheetData::Ref(range) => range.start(),
+        }
+    }
+
     pub(super) fn get_as_string(&self, pos: (usize, usize)) -> Option<String> {
         match self {
             ExcelSheetData::Owned(range) => range.get(pos).and_then(|data| data.as_string()),
@@ -41,22 +74,118 @@ impl ExcelSheetData<'_> {
         }
     }
 
+    /// See `row_to_py_values`
+    pub(crate) fn row_to_py_values(
+        &self,
+        row: usize,
+        columns: &[ColumnInfo],
+        dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
+        py: Python<'_>,
+    ) -> FastExcelResult<Vec<PyObject>> {
+        match self {
+            ExcelSheetData::Owned(data) => {
+                row_to_py_values(data, row, columns, dtype_coercion, read_options, py)
+            }
+            ExcelSheetData::Ref(data) => {
+                row_to_py_values(data, row, columns, dtype_coercion, read_options, py)
+            }
+        }
+    }
+
+    /// The schema of the record batches read from `offset` to `limit`, with every field
+    /// nullable unless `tighten_nullability` is set. Currency symbols are captured over all these
+    /// rows
+    pub(crate) fn schema(
+        &self,
+        columns: &[ColumnInfo],
+        offset: usize,
+        limit: usize,
+        read_options: &ReadOptions,
+    ) -> Schema {
+        let schema = selected_columns_to_schema(columns, read_options);
+        let schema = if read_options.capture_currency {
+            match self {
+                ExcelSheetData::Owned(data) => {
+                    with_currency_metadata(schema, columns, data, offset, limit, read_options)
+                }
+                ExcelSheetData::Ref(data) => {
+                    with_currency_metadata(schema, columns, data, offset, limit, read_options)
+                }
+            }
+        } else {
+            schema
+        };
+        self.with_nullability(schema, columns, offset, limit, read_options)
+    }
+
+    /// Marks the fields of the columns without null cells from `offset` to `limit` as
+    /// non-nullable if `tighten_nullability` is set. See `with_tight_nullability`
+    pub(crate) fn with_nullability(
+        &self,
+        schema: Schema,
+        columns: &[ColumnInfo],
+        offset: usize,
+        limit: usize,
+        read_options: &ReadOptions,
+    ) -> Schema {
+        if !read_options.tighten_nullability {
+            return schema;
+        }
+        match self {
+            ExcelSheetData::Owned(data) => {
+                with_tight_nullability(schema, columns, data, offset, limit, read_options)
+            }
+            ExcelSheetData::Ref(data) => {
+                with_tight_nullability(schema, columns, data, offset, limit, read_options)
+            }
+        }
+    }
+
     pub(crate) fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: SampleRows,
         col: usize,
         dtype_coercion: &DTypeCoercion,
     ) -> FastExcelResult<DType> {
         match self {
             ExcelSheetData::Owned(data) => {
-                get_dtype_for_column(data, start_row, end_row, col, dtype_coercion)
+                get_dtype_for_column(data, sample_rows, col, dtype_coercion)
             }
             ExcelSheetData::Ref(data) => {
-                get_dtype_for_column(data, start_row, end_row, col, dtype_coercion)
+                get_dtype_for_column(data, sample_rows, col, dtype_coercion)
             }
         }
     }
+
+    pub(crate) fn has_only_dates(
+        &self,
+        sample_rows: SampleRows,
+        col: usize,
+        read_options: &ReadOptions,
+    ) -> bool {
+        match self {
+            ExcelSheetData::Owned(data) => has_only_dates(data, sample_rows, col, read_options),
+            ExcelSheetData::Ref(data) => has_only_dates(data, sample_rows, col, read_options),
+        }
+    }
+}
+
+/// Whether the non-empty cells of the sampled rows of a datetime column are all at midnight, i.e.
+/// whether they are dates stored as datetimes
+pub(crate) fn has_only_dates<DT: CellType + DataType>(
+    data: &Range<DT>,
+    sample_rows: SampleRows,
+    col: usize,
+    read_options: &ReadOptions,
+) -> bool {
+    sample_rows
+        .rows()
+        .filter_map(|row| data.get((row, col)))
+        .filter(|cell| !cell.is_empty() && !cell.is_error())
+        .all(|cell| {
+            cell_to_datetime(cell, read_options).is_some_and(|dt| dt.time() == NaiveTime::MIN)
+        })
 }
 
 impl From<Range<CalData>> for ExcelSheetData<'_> {
@@ -71,35 +200,378 @@ impl<'a> From<Range<CalDataRef<'a>>> for ExcelSheetData<'a> {
     }
 }
 
+/// Conversions of a single cell, shared by the arrow arrays and the Python row values
+pub(crate) mod cell_values {
+    use std::fmt::Debug;
+
+    use calamine::{CellType, DataType, ExcelDateTime, ExcelDateTimeType};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use crate::{
+        error::{FastExcelError, FastExcelErrorKind},
+        types::{
+            dtype::{is_null_error, parse_iso_datetime, parse_offset_datetime},
+            read_options::{ReadOptions, TimestampUnit},
+        },
+    };
+
+    /// The error for an error cell that is not considered as null, such as `#DIV/0!`, when type
+    /// coercion is strict. See `is_null_error`
+    pub(crate) fn strict_cell_error<DT: CellType + DataType>(cell: &DT) -> Option<FastExcelError> {
+        cell.get_error()
+            .filter(|err| !is_null_error(err))
+            .map(|err| FastExcelErrorKind::CalamineCellError(err.to_owned()).into())
+    }
+
+    /// Converts a cell of a boolean column. String cells are looked up in the boolean values of
+    /// `read_options`
+    pub(crate) fn cell_to_bool<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<bool> {
+        if let Some(b) = cell.get_bool() {
+            Some(b)
+        } else if let Some(i) = cell.get_int() {
+            Some(i != 0)
+        } else if let Some(value) = cell.get_string() {
+            read_options.bool_from_str(value)
+        }
+        // clippy formats else if let Some(blah) = ... { Some(x) } else { None } to the .map form
+        else {
+            cell.get_float().map(|f| f != 0.0)
+        }
+    }
+
+    /// Converts a boolean cell, or a numeric cell containing 0 or 1. Returns `None` for any
+    /// other cell
+    pub(crate) fn cell_to_bool_from_int<DT: CellType + DataType>(cell: &DT) -> Option<bool> {
+        if cell.is_bool() {
+            return cell.get_bool();
+        }
+        match (cell.is_int() || cell.is_float())
+            .then(|| cell.as_f64())
+            .flatten()
+        {
+            Some(0.0) => Some(false),
+            Some(1.0) => Some(true),
+            _ => None,
+        }
+    }
+
+    /// The error for a non-empty cell of a "bool_from_int" column that contains neither a boolean
+    /// nor 0/1, when type coercion is strict
+    pub(crate) fn invalid_bool_from_int_error<DT: Debug>(
+        row: usize,
+        col: usize,
+        cell: &DT,
+    ) -> FastExcelError {
+        FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
+            "type coercion is strict and cell at ({row}, {col}) is neither 0 nor 1: {cell:?}"
+        ))
+        .into()
+    }
+
+    pub(crate) fn invalid_bool_value_error<DT: Debug>(
+        row: usize,
+        col: usize,
+        cell: &DT,
+    ) -> FastExcelError {
+        FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
+            "type coercion is strict and cell at ({row}, {col}) is not a known boolean value: {cell:?}"
+        ))
+        .into()
+    }
+
+    /// Returns whether a cell is a string starting or ending with one of the currency symbols of
+    /// `read_options`
+    pub(crate) fn is_currency_amount<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> bool {
+        cell.get_string()
+            .is_some_and(|value| read_options.currency_amount(value).is_some())
+    }
+
+    /// Converts a cell of a float column. String cells starting or ending with one of the
+    /// currency symbols of `read_options` are parsed without their symbol, and the other ones
+    /// with the separators of `read_options`
+    pub(crate) fn cell_to_float<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<f64> {
+        match cell.get_string() {
+            Some(value) if read_options.is_na_value(value) => None,
+            Some(value) => match read_options.currency_amount(value) {
+                Some((_, amount)) => amount,
+                None => read_options.parse_float(value),
+            },
+            None => cell.as_f64(),
+        }
+    }
+
+    /// Converts a cell of an int column. String cells are parsed with the separators of
+    /// `read_options`. Floats outside of the range of an i64 are null rather than saturated
+    pub(crate) fn cell_to_int<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<i64> {
+        // -2^63 and 2^63 are exactly representable as floats, unlike i64::MAX
+        const I64_FLOAT_RANGE: std::ops::Range<f64> = i64::MIN as f64..-(i64::MIN as f64);
+        match cell.get_string() {
+            Some(value) if read_options.is_na_value(value) => None,
+            Some(value) => read_options.parse_int(value),
+            None => match cell.get_float() {
+                Some(value) if !I64_FLOAT_RANGE.contains(&value) => None,
+                _ => cell.as_i64(),
+            },
+        }
+    }
+
+    /// The error for a cell of a "float" column containing a currency symbol followed or preceded
+    /// by something other than a number, when type coercion is strict
+    pub(crate) fn invalid_currency_amount_error<DT: Debug>(
+        row: usize,
+        col: usize,
+        cell: &DT,
+    ) -> FastExcelError {
+        FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
+            "type coercion is strict and cell at ({row}, {col}) is not a currency amount: {cell:?}"
+        ))
+        .into()
+    }
+
+    /// Converts a cell of a string column. Strings that are null values of `read_options` are
+    /// null
+    pub(crate) fn cell_to_string<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<String> {
+        if cell.is_string() {
+            cell.get_string()
+                .map(|value| read_options.trim_string(value))
+                .filter(|value| !read_options.is_na_value(value))
+                .map(str::to_string)
+        } else if cell.is_datetime() {
+            cell.get_datetime()
+                .and_then(|dt| dt.as_datetime())
+                .map(|dt| dt.to_string())
+        } else if cell.is_datetime_iso() {
+            cell.get_datetime_iso().map(str::to_string)
+        } else if cell.is_bool() {
+            cell.get_bool().map(|v| v.to_string())
+        } else {
+            cell.as_string()
+        }
+    }
+
+    /// Converts a cell to a UTC datetime. String cells must contain an ISO 8601 timestamp with a
+    /// UTC offset. Datetime cells, which have no offset, are converted as for datetime columns
+    /// and considered to be in the `assume_timezone` of `read_options`
+    pub(crate) fn cell_to_utc_datetime<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<NaiveDateTime> {
+        if let Some(value) = cell.get_string() {
+            parse_offset_datetime(value)
+        } else if let Some(value) = cell.get_datetime_iso().and_then(parse_offset_datetime) {
+            Some(value)
+        } else {
+            cell_to_datetime(cell, read_options).map(|dt| read_options.naive_to_utc(dt))
+        }
+    }
+
+    /// The error for a non-empty cell of a "datetime_tz" column that cannot be converted to a
+    /// UTC datetime (e.g. because of a malformed offset), when type coercion is strict
+    pub(crate) fn invalid_offset_datetime_error<DT: Debug>(
+        row: usize,
+        col: usize,
+        cell: &DT,
+    ) -> FastExcelError {
+        FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
+            "type coercion is strict and cell at ({row}, {col}) is not a timestamp with a UTC \
+             offset: {cell:?}"
+        ))
+        .into()
+    }
+
+    /// The error for a date or datetime cell that is too far from the epoch to be represented in
+    /// the integer type of its column, when type coercion is strict
+    pub(crate) fn out_of_epoch_range_error<DT: Debug>(
+        row: usize,
+        col: usize,
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> FastExcelError {
+        FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
+            "type coercion is strict and cell at ({row}, {col}) is too far from the epoch {} to \
+             be represented: {cell:?}",
+            read_options.epoch()
+        ))
+        .into()
+    }
+
+    /// Converts a cell of a datetime column. Numeric cells are Excel serials, e.g. dates that
+    /// lost their formatting, read in the date system of the workbook. ISO 8601 dates are read at
+    /// midnight, so that they match the serial datetimes of the same column
+    pub(crate) fn cell_to_datetime<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<NaiveDateTime> {
+        if cell.is_int() || cell.is_float() {
+            cell.as_f64().and_then(|serial| {
+                ExcelDateTime::new(serial, ExcelDateTimeType::DateTime, read_options.date1904)
+                    .as_datetime()
+            })
+        } else if let Some(value) = cell.get_datetime_iso() {
+            parse_iso_datetime(value)
+        } else {
+            cell.as_datetime()
+        }
+    }
+
+    /// Converts a cell of a date column. Numeric and ISO 8601 cells are converted as for datetime
+    /// columns
+    pub(crate) fn cell_to_date<DT: CellType + DataType>(
+        cell: &DT,
+        read_options: &ReadOptions,
+    ) -> Option<NaiveDate> {
+        if cell.is_int() || cell.is_float() || cell.is_datetime_iso() {
+            cell_to_datetime(cell, read_options).map(|dt| dt.date())
+        } else {
+            cell.as_date()
+        }
+    }
+
+    /// The number of days between a date and the epoch of `read_options`, as stored in date
+    /// arrays. `None` if it does not fit in an i32
+    pub(crate) fn days_since_epoch(date: NaiveDate, read_options: &ReadOptions) -> Option<i32> {
+        i32::try_from(date.signed_duration_since(read_options.epoch()).num_days()).ok()
+    }
+
+    /// The timestamp of a datetime relative to the epoch of `read_options`, in its timestamp unit,
+    /// as stored in timestamp arrays. `None` if it does not fit in an i64, e.g. before 1677 or
+    /// after 2262 for nanoseconds relative to the Unix epoch
+    pub(crate) fn timestamp_since_epoch(
+        datetime: NaiveDateTime,
+        read_options: &ReadOptions,
+    ) -> Option<i64> {
+        let delta = datetime.signed_duration_since(NaiveDateTime::from(read_options.epoch()));
+        match read_options.timestamp_unit {
+            TimestampUnit::Millisecond => Some(delta.num_milliseconds()),
+            TimestampUnit::Microsecond => delta.num_microseconds(),
+            TimestampUnit::Nanosecond => delta.num_nanoseconds(),
+        }
+    }
+
+    /// Converts a cell to the raw serial number Excel uses to store dates and datetimes. Numeric
+    /// cells are taken as-is, since they are serial numbers without a date format.
+    pub(crate) fn cell_to_excel_serial<DT: CellType + DataType>(cell: &DT) -> Option<f64> {
+        if let Some(excel_datetime) = cell.get_datetime() {
+            Some(excel_datetime.as_f64())
+        } else if cell.is_int() || cell.is_float() {
+            cell.as_f64()
+        } else {
+            None
+        }
+    }
+}
+
 mod array_impls {
     use std::sync::Arc;
 
-    use arrow::array::{
-        Array, BooleanArray, Date32Array, DurationMillisecondArray, Float64Array, Int64Array,
-        StringArray, TimestampMillisecondArray,
+    use arrow::{
+        array::{
+            Array, BooleanArray, Date32Array, DurationMillisecondArray, Float64Array, Int32Array,
+            Int64Array, Int8Array, Scalar, StringArray, TimestampMicrosecondArray,
+            TimestampMillisecondArray, TimestampNanosecondArray, UInt32Array,
+        },
+        compute::{is_null, kernels::zip::zip, take},
+        datatypes::DataType as ArrowDataType,
     };
+    use std::{fmt::Debug, iter};
+
     use calamine::{CellType, DataType, Range};
-    use chrono::NaiveDate;
 
-    pub(crate) fn create_boolean_array<DT: CellType + DataType>(
+    use super::cell_values::{
+        cell_to_bool, cell_to_bool_from_int, cell_to_date, cell_to_datetime, cell_to_excel_serial,
+        cell_to_float, cell_to_int, cell_to_string, cell_to_utc_datetime, days_since_epoch,
+        invalid_bool_from_int_error, invalid_bool_value_error, invalid_currency_amount_error,
+        invalid_offset_datetime_error, is_currency_amount, out_of_epoch_range_error,
+        timestamp_since_epoch,
+    };
+    use crate::{
+        error::{FastExcelErrorKind, FastExcelResult},
+        types::{
+            dtype::DTypeCoercion,
+            read_options::{FillValue, ReadOptions, TimestampUnit},
+        },
+    };
+
+    /// Builds a `BooleanArray`, or an `Int8Array` of 0/1 if `as_int8` is true
+    fn boolean_values_to_array<I: IntoIterator<Item = Option<bool>>>(
+        values: I,
+        as_int8: bool,
+    ) -> Arc<dyn Array> {
+        let values = values.into_iter();
+        if as_int8 {
+            Arc::new(Int8Array::from_iter(values.map(|v| v.map(i8::from))))
+        } else {
+            Arc::new(BooleanArray::from_iter(values))
+        }
+    }
+
+    /// Builds a boolean array. Empty cells take the value of `bool_fill`, or are null if it is
+    /// `None`. String cells that are not one of the boolean values of `read_options` are null, or
+    /// an error if `dtype_coercion` is strict.
+    pub(crate) fn create_boolean_array<DT: CellType + Debug + DataType>(
         data: &Range<DT>,
         col: usize,
         offset: usize,
         limit: usize,
-    ) -> Arc<dyn Array> {
-        Arc::new(BooleanArray::from_iter((offset..limit).map(|row| {
-            data.get((row, col)).and_then(|cell| {
-                if let Some(b) = cell.get_bool() {
-                    Some(b)
-                } else if let Some(i) = cell.get_int() {
-                    Some(i != 0)
-                }
-                // clippy formats else if let Some(blah) = ... { Some(x) } else { None } to the .map form
-                else {
-                    cell.get_float().map(|f| f != 0.0)
-                }
+        dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        let values = (offset..limit)
+            .map(|row| match data.get((row, col)) {
+                None => Ok(read_options.bool_fill),
+                Some(cell) if cell.is_empty() => Ok(read_options.bool_fill),
+                Some(cell) => match cell_to_bool(cell, read_options) {
+                    None if cell.is_string() && matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                        Err(invalid_bool_value_error(row, col, cell))
+                    }
+                    value => Ok(value),
+                },
             })
-        })))
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(boolean_values_to_array(values, read_options.bool_as_int8))
+    }
+
+    /// Builds a boolean array from cells containing 0 or 1. Boolean cells are taken as-is, empty
+    /// cells take the value of `fill`, and any other cell is null, or an error if
+    /// `dtype_coercion` is strict.
+    pub(crate) fn create_boolean_from_int_array<DT: CellType + Debug + DataType>(
+        data: &Range<DT>,
+        col: usize,
+        offset: usize,
+        limit: usize,
+        dtype_coercion: &DTypeCoercion,
+        fill: Option<bool>,
+        as_int8: bool,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        let values = (offset..limit)
+            .map(|row| match data.get((row, col)) {
+                None => Ok(fill),
+                Some(cell) if cell.is_empty() => Ok(fill),
+                Some(cell) => match cell_to_bool_from_int(cell) {
+                    Some(b) => Ok(Some(b)),
+                    None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                        Err(invalid_bool_from_int_error(row, col, cell))
+                    }
+                    None => Ok(None),
+                },
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(boolean_values_to_array(values, as_int8))
     }
 
     pub(crate) fn create_int_array<DT: CellType + DataType>(
@@ -107,21 +579,38 @@ mod array_impls {
         col: usize,
         offset: usize,
         limit: usize,
+        read_options: &ReadOptions,
     ) -> Arc<dyn Array> {
-        Arc::new(Int64Array::from_iter(
-            (offset..limit).map(|row| data.get((row, col)).and_then(|cell| cell.as_i64())),
-        ))
+        Arc::new(Int64Array::from_iter((offset..limit).map(|row| {
+            data.get((row, col))
+                .and_then(|cell| cell_to_int(cell, read_options))
+        })))
     }
 
-    pub(crate) fn create_float_array<DT: CellType + DataType>(
+    /// Builds a `Float64Array`. String cells containing a currency amount that is not a number
+    /// are null, or an error if `dtype_coercion` is strict.
+    pub(crate) fn create_float_array<DT: CellType + Debug + DataType>(
         data: &Range<DT>,
         col: usize,
         offset: usize,
         limit: usize,
-    ) -> Arc<dyn Array> {
-        Arc::new(Float64Array::from_iter(
-            (offset..limit).map(|row| data.get((row, col)).and_then(|cell| cell.as_f64())),
-        ))
+        dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        let values = (offset..limit)
+            .map(|row| match data.get((row, col)) {
+                None => Ok(None),
+                Some(cell) => match cell_to_float(cell, read_options) {
+                    None if matches!(dtype_coercion, DTypeCoercion::Strict)
+                        && is_currency_amount(cell, read_options) =>
+                    {
+                        Err(invalid_currency_amount_error(row, col, cell))
+                    }
+                    value => Ok(value),
+                },
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(Arc::new(Float64Array::from(values)))
     }
 
     pub(crate) fn create_string_array<DT: CellType + DataType>(
@@ -129,23 +618,11 @@ mod array_impls {
         col: usize,
         offset: usize,
         limit: usize,
+        read_options: &ReadOptions,
     ) -> Arc<dyn Array> {
         Arc::new(StringArray::from_iter((offset..limit).map(|row| {
-            data.get((row, col)).and_then(|cell| {
-                if cell.is_string() {
-                    cell.get_string().map(str::to_string)
-                } else if cell.is_datetime() {
-                    cell.get_datetime()
-                        .and_then(|dt| dt.as_datetime())
-                        .map(|dt| dt.to_string())
-                } else if cell.is_datetime_iso() {
-                    cell.get_datetime_iso().map(str::to_string)
-                } else if cell.is_bool() {
-                    cell.get_bool().map(|v| v.to_string())
-                } else {
-                    cell.as_string()
-                }
-            })
+            data.get((row, col))
+                .and_then(|cell| cell_to_string(cell, read_options))
         })))
     }
 
@@ -153,33 +630,149 @@ mod array_impls {
         caldt.as_duration().map(|d| d.num_milliseconds())
     }
 
-    pub(crate) fn create_date_array<DT: CellType + DataType>(
+    /// Builds a date array from days converted with `days_since_epoch`, or an i32 array when the
+    /// epoch is not the Unix one
+    fn date_array<I: IntoIterator<Item = Option<i32>>>(
+        days: I,
+        read_options: &ReadOptions,
+    ) -> Arc<dyn Array> {
+        if read_options.has_custom_epoch() {
+            Arc::new(Int32Array::from_iter(days))
+        } else {
+            Arc::new(Date32Array::from_iter(days))
+        }
+    }
+
+    /// Builds an array of the number of days since `epoch`. Dates that are too far from the
+    /// epoch to fit in an i32 are considered as null, or an error if `dtype_coercion` is strict
+    pub(crate) fn create_date_array<DT: CellType + Debug + DataType>(
         data: &Range<DT>,
         col: usize,
         offset: usize,
         limit: usize,
+        dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        let days = (offset..limit)
+            .map(|row| {
+                let Some((cell, date)) = data
+                    .get((row, col))
+                    .and_then(|cell| cell_to_date(cell, read_options).map(|date| (cell, date)))
+                else {
+                    return Ok(None);
+                };
+                match days_since_epoch(date, read_options) {
+                    None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                        Err(out_of_epoch_range_error(row, col, cell, read_options))
+                    }
+                    days => Ok(days),
+                }
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(date_array(days, read_options))
+    }
+
+    /// Builds a timestamp array in the unit of `read_options` from timestamps converted with
+    /// `timestamp_since_epoch`, or an i64 array when the epoch is not the Unix one
+    fn timestamp_array<I: IntoIterator<Item = Option<i64>>>(
+        timestamps: I,
+        read_options: &ReadOptions,
+        timezone: Option<&str>,
     ) -> Arc<dyn Array> {
-        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-        Arc::new(Date32Array::from_iter((offset..limit).map(|row| {
-            data.get((row, col))
-                .and_then(|caldate| caldate.as_date())
-                .and_then(|date| i32::try_from(date.signed_duration_since(epoch).num_days()).ok())
-        })))
+        if read_options.has_custom_epoch() {
+            return Arc::new(Int64Array::from_iter(timestamps));
+        }
+        match read_options.timestamp_unit {
+            TimestampUnit::Millisecond => Arc::new(
+                TimestampMillisecondArray::from_iter(timestamps).with_timezone_opt(timezone),
+            ),
+            TimestampUnit::Microsecond => Arc::new(
+                TimestampMicrosecondArray::from_iter(timestamps).with_timezone_opt(timezone),
+            ),
+            TimestampUnit::Nanosecond => Arc::new(
+                TimestampNanosecondArray::from_iter(timestamps).with_timezone_opt(timezone),
+            ),
+        }
+    }
+
+    /// Builds an array of timestamps relative to the epoch in the unit of `read_options`.
+    /// Datetimes that are too far from the epoch to be represented in an i64 (e.g. before 1677
+    /// or after 2262 for nanoseconds relative to the Unix epoch) are considered as null, or an
+    /// error if `dtype_coercion` is strict
+    pub(crate) fn create_datetime_array<DT: CellType + Debug + DataType>(
+        data: &Range<DT>,
+        col: usize,
+        offset: usize,
+        limit: usize,
+        dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        let timestamps = (offset..limit)
+            .map(|row| {
+                let Some((cell, dt)) = data
+                    .get((row, col))
+                    .and_then(|cell| cell_to_datetime(cell, read_options).map(|dt| (cell, dt)))
+                else {
+                    return Ok(None);
+                };
+                match timestamp_since_epoch(dt, read_options) {
+                    None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                        Err(out_of_epoch_range_error(row, col, cell, read_options))
+                    }
+                    timestamp => Ok(timestamp),
+                }
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(timestamp_array(timestamps, read_options, None))
     }
 
-    pub(crate) fn create_datetime_array<DT: CellType + DataType>(
+    /// Builds an array of UTC timestamps relative to `epoch` in the given unit, from strings
+    /// containing ISO 8601 timestamps with a UTC offset. Cells that cannot be converted are null,
+    /// or an error if `dtype_coercion` is strict.
+    pub(crate) fn create_datetime_tz_array<DT: CellType + Debug + DataType>(
+        data: &Range<DT>,
+        col: usize,
+        offset: usize,
+        limit: usize,
+        dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        let timestamps = (offset..limit)
+            .map(|row| match data.get((row, col)) {
+                None => Ok(None),
+                Some(cell) if cell.is_empty() => Ok(None),
+                Some(cell) => match cell_to_utc_datetime(cell, read_options) {
+                    Some(dt) => match timestamp_since_epoch(dt, read_options) {
+                        None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                            Err(out_of_epoch_range_error(row, col, cell, read_options))
+                        }
+                        timestamp => Ok(timestamp),
+                    },
+                    None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                        Err(invalid_offset_datetime_error(row, col, cell))
+                    }
+                    None => Ok(None),
+                },
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(timestamp_array(
+            timestamps,
+            read_options,
+            Some(&read_options.timezone_name()),
+        ))
+    }
+
+    /// Builds an array of the raw serial numbers Excel uses to store dates and datetimes (days
+    /// since 1899-12-30, or 1904-01-01 for workbooks using the 1904 date system).
+    pub(crate) fn create_excel_serial_array<DT: CellType + DataType>(
         data: &Range<DT>,
         col: usize,
         offset: usize,
         limit: usize,
     ) -> Arc<dyn Array> {
-        Arc::new(TimestampMillisecondArray::from_iter((offset..limit).map(
-            |row| {
-                data.get((row, col))
-                    .and_then(|caldt| caldt.as_datetime())
-                    .map(|dt| dt.and_utc().timestamp_millis())
-            },
-        )))
+        Arc::new(Float64Array::from_iter(
+            (offset..limit).map(|row| data.get((row, col)).and_then(cell_to_excel_serial)),
+        ))
     }
 
     pub(crate) fn create_duration_array<DT: CellType + DataType>(
@@ -192,44 +785,278 @@ mod array_impls {
             (offset..limit).map(|row| data.get((row, col)).and_then(duration_type_to_i64)),
         ))
     }
-}
 
-/// Creates a function that will dispatch ExcelData to the generic create_x_array implementation
-macro_rules! create_array_function {
-    ($func_name:ident) => {
-        pub(crate) fn $func_name(
-            data: &ExcelSheetData,
-            col: usize,
-            offset: usize,
-            limit: usize,
-        ) -> Arc<dyn Array> {
-            match data {
-                ExcelSheetData::Owned(range) => array_impls::$func_name(range, col, offset, limit),
-                ExcelSheetData::Ref(range) => array_impls::$func_name(range, col, offset, limit),
+    /// Replaces the nulls of an array with `fill_value`, converted as the cells of the array's
+    /// column. The fill value must match the dtype of the column
+    pub(crate) fn fill_nulls(
+        array: Arc<dyn Array>,
+        fill_value: &FillValue,
+        read_options: &ReadOptions,
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        if array.null_count() == 0 {
+            return Ok(array);
+        }
+        let fill_array = match fill_value {
+            FillValue::Bool(value) => {
+                boolean_values_to_array([Some(*value)], read_options.bool_as_int8)
+            }
+            FillValue::Int(value) if array.data_type() == &ArrowDataType::Int64 => {
+                Arc::new(Int64Array::from(vec![*value]))
             }
+            FillValue::Int(value) => Arc::new(Float64Array::from(vec![*value as f64])),
+            FillValue::Float(value) => Arc::new(Float64Array::from(vec![*value])),
+            FillValue::String(value) => Arc::new(StringArray::from(vec![value.as_str()])),
+            FillValue::Date(date) => date_array(
+                iter::once(days_since_epoch(*date, read_options)),
+                read_options,
+            ),
+            FillValue::DateTime(dt) => timestamp_array(
+                iter::once(timestamp_since_epoch(*dt, read_options)),
+                read_options,
+                None,
+            ),
+            FillValue::DateTimeTz(dt) => timestamp_array(
+                iter::once(timestamp_since_epoch(*dt, read_options)),
+                read_options,
+                Some(&read_options.timezone_name()),
+            ),
+            FillValue::Duration(duration) => Arc::new(DurationMillisecondArray::from(vec![
+                duration.num_milliseconds(),
+            ])),
+        };
+        is_null(array.as_ref())
+            .and_then(|mask| zip(&mask, &Scalar::new(fill_array), &array))
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+    }
+
+    /// Replaces every null of an array with the last non-null value above it. Nulls before the
+    /// first value of the array stay null
+    pub(crate) fn forward_fill(array: Arc<dyn Array>) -> FastExcelResult<Arc<dyn Array>> {
+        if array.null_count() == 0 {
+            return Ok(array);
         }
+        let mut last_valid = None;
+        let indices = UInt32Array::from_iter((0..array.len()).map(|idx| {
+            if array.is_valid(idx) {
+                last_valid = Some(idx as u32);
+            }
+            last_valid
+        }));
+        take(array.as_ref(), &indices, None)
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+    }
+}
+
+/// Creates the arrow array for a column, based on its dtype
+pub(crate) fn create_array_for_column<DT: CellType + Debug + DataType>(
+    data: &Range<DT>,
+    column_info: &ColumnInfo,
+    offset: usize,
+    limit: usize,
+    dtype_coercion: &DTypeCoercion,
+    read_options: &ReadOptions,
+) -> FastExcelResult<Arc<dyn Array>> {
+    let col = column_info.index();
+    if matches!(dtype_coercion, DTypeCoercion::Strict) {
+        check_error_cells(data, col, offset, limit)
+            .with_context(|| format!("could not read column \"{}\"", column_info.name()))?;
+    }
+    let array: Arc<dyn Array> = match column_info.dtype() {
+        DType::Null => Arc::new(NullArray::new(limit - offset)),
+        DType::Int => create_int_array(data, col, offset, limit, read_options),
+        DType::Float => create_float_array(data, col, offset, limit, dtype_coercion, read_options)
+            .with_context(|| {
+                format!("could not read column \"{}\" as float", column_info.name())
+            })?,
+        DType::String => create_string_array(data, col, offset, limit, read_options),
+        DType::Bool => create_boolean_array(data, col, offset, limit, dtype_coercion, read_options)
+            .with_context(|| {
+                format!(
+                    "could not read column \"{}\" as boolean",
+                    column_info.name()
+                )
+            })?,
+        DType::BoolFromInt => create_boolean_from_int_array(
+            data,
+            col,
+            offset,
+            limit,
+            dtype_coercion,
+            read_options.bool_fill,
+            read_options.bool_as_int8,
+        )
+        .with_context(|| format!("could not read column \"{}\" as bool", column_info.name()))?,
+        DType::DateTime => {
+            create_datetime_array(data, col, offset, limit, dtype_coercion, read_options)
+                .with_context(|| {
+                    format!(
+                        "could not read column \"{}\" as datetime",
+                        column_info.name()
+                    )
+                })?
+        }
+        DType::DateTimeTz => {
+            create_datetime_tz_array(data, col, offset, limit, dtype_coercion, read_options)
+                .with_context(|| {
+                    format!(
+                        "could not read column \"{}\" as datetime_tz",
+                        column_info.name()
+                    )
+                })?
+        }
+        DType::Date => create_date_array(data, col, offset, limit, dtype_coercion, read_options)
+            .with_context(|| format!("could not read column \"{}\" as date", column_info.name()))?,
+        DType::Duration => create_duration_array(data, col, offset, limit),
+        DType::ExcelSerial => create_excel_serial_array(data, col, offset, limit),
     };
+    if read_options.is_forward_filled(column_info) {
+        return forward_fill(array)
+            .with_context(|| format!("could not forward-fill column \"{}\"", column_info.name()));
+    }
+    match read_options.fill_value(column_info) {
+        Some(fill_value) => fill_nulls(array, fill_value, read_options).with_context(|| {
+            format!(
+                "could not fill the nulls of column \"{}\"",
+                column_info.name()
+            )
+        }),
+        None => Ok(array),
+    }
 }
 
-create_array_function!(create_boolean_array);
-create_array_function!(create_string_array);
-create_array_function!(create_int_array);
-create_array_function!(create_float_array);
-create_array_function!(create_datetime_array);
-create_array_function!(create_date_array);
-create_array_function!(create_duration_array);
-
-pub(crate) use array_impls::create_boolean_array as create_boolean_array_from_range;
-pub(crate) use array_impls::create_date_array as create_date_array_from_range;
-pub(crate) use array_impls::create_datetime_array as create_datetime_array_from_range;
-pub(crate) use array_impls::create_duration_array as create_duration_array_from_range;
-pub(crate) use array_impls::create_float_array as create_float_array_from_range;
-pub(crate) use array_impls::create_int_array as create_int_array_from_range;
-pub(crate) use array_impls::create_string_array as create_string_array_from_range;
+/// Fails on the first error cell of a column that is not considered as null, such as `#DIV/0!`.
+/// Only called when type coercion is strict, as these cells are read as null otherwise
+fn check_error_cells<DT: CellType + DataType>(
+    data: &Range<DT>,
+    col: usize,
+    offset: usize,
+    limit: usize,
+) -> FastExcelResult<()> {
+    let (start_row, start_col) = data.start().unwrap_or((0, 0));
+    (offset..limit).try_for_each(|row| {
+        match data.get((row, col)).and_then(strict_cell_error) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+        .with_cell_position((start_row as usize + row, start_col as usize + col))
+    })
+}
+
+/// Converts the cells of a row to Python objects, based on the dtype of their column. As with
+/// arrow arrays, cells that cannot be converted to their column's dtype are `None`
+pub(crate) fn row_to_py_values<DT: CellType + Debug + DataType>(
+    data: &Range<DT>,
+    row: usize,
+    columns: &[ColumnInfo],
+    dtype_coercion: &DTypeCoercion,
+    read_options: &ReadOptions,
+    py: Python<'_>,
+) -> FastExcelResult<Vec<PyObject>> {
+    let bool_to_py = |value: Option<bool>| {
+        if read_options.bool_as_int8 {
+            value.map(i8::from).to_object(py)
+        } else {
+            value.to_object(py)
+        }
+    };
+
+    let cell_to_py = |column_info: &ColumnInfo| -> FastExcelResult<PyObject> {
+        let col = column_info.index();
+        if matches!(dtype_coercion, DTypeCoercion::Strict) {
+            check_error_cells(data, col, row, row + 1)
+                .with_context(|| format!("could not read column \"{}\"", column_info.name()))?;
+        }
+        let Some(cell) = data.get((row, col)).filter(|cell| !cell.is_empty()) else {
+            return Ok(match column_info.dtype() {
+                DType::Bool | DType::BoolFromInt => bool_to_py(read_options.bool_fill),
+                _ => py.None(),
+            });
+        };
+        Ok(match column_info.dtype() {
+            DType::Null => py.None(),
+            DType::Int => cell_to_int(cell, read_options).to_object(py),
+            DType::Float => match cell_to_float(cell, read_options) {
+                None if matches!(dtype_coercion, DTypeCoercion::Strict)
+                    && is_currency_amount(cell, read_options) =>
+                {
+                    return Err(invalid_currency_amount_error(row, col, cell)).with_context(|| {
+                        format!("could not read column \"{}\" as float", column_info.name())
+                    })
+                }
+                value => value.to_object(py),
+            },
+            DType::String => cell_to_string(cell, read_options).to_object(py),
+            DType::Bool => match cell_to_bool(cell, read_options) {
+                None if cell.is_string() && matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                    return Err(invalid_bool_value_error(row, col, cell)).with_context(|| {
+                        format!(
+                            "could not read column \"{}\" as boolean",
+                            column_info.name()
+                        )
+                    })
+                }
+                value => bool_to_py(value),
+            },
+            DType::BoolFromInt => match cell_to_bool_from_int(cell) {
+                None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                    return Err(invalid_bool_from_int_error(row, col, cell)).with_context(|| {
+                        format!("could not read column \"{}\" as bool", column_info.name())
+                    })
+                }
+                value => bool_to_py(value),
+            },
+            DType::DateTime => cell_to_datetime(cell, read_options).to_object(py),
+            DType::DateTimeTz => match cell_to_utc_datetime(cell, read_options) {
+                None if matches!(dtype_coercion, DTypeCoercion::Strict) => {
+                    return Err(invalid_offset_datetime_error(row, col, cell)).with_context(|| {
+                        format!(
+                            "could not read column \"{}\" as datetime_tz",
+                            column_info.name()
+                        )
+                    })
+                }
+                value => value.map(|dt| dt.and_utc()).to_object(py),
+            },
+            DType::Date => cell_to_date(cell, read_options).to_object(py),
+            DType::Duration => cell.as_duration().to_object(py),
+            DType::ExcelSerial => cell_to_excel_serial(cell).to_object(py),
+        })
+    };
+    let fill_value_to_py = |fill_value: &FillValue, dtype: &DType| match fill_value {
+        FillValue::Bool(value) => bool_to_py(Some(*value)),
+        FillValue::Int(value) if dtype == &DType::Int => value.to_object(py),
+        FillValue::Int(value) => (*value as f64).to_object(py),
+        FillValue::Float(value) => value.to_object(py),
+        FillValue::String(value) => value.to_object(py),
+        FillValue::Date(value) => value.to_object(py),
+        FillValue::DateTime(value) => value.to_object(py),
+        FillValue::DateTimeTz(value) => value.and_utc().to_object(py),
+        FillValue::Duration(value) => value.to_object(py),
+    };
+
+    columns
+        .iter()
+        .map(|column_info| {
+            let value = cell_to_py(column_info)?;
+            Ok(match read_options.fill_value(column_info) {
+                Some(fill_value) if value.is_none(py) => {
+                    fill_value_to_py(fill_value, column_info.dtype())
+                }
+                _ => value,
+            })
+        })
+        .collect()
+}
 
 /// Converts a list of ColumnInfo to an arrow Schema
-pub(crate) fn selected_columns_to_schema(columns: &[ColumnInfo]) -> Schema {
-    let fields: Vec<_> = columns.iter().map(Into::<Field>::into).collect();
+pub(crate) fn selected_columns_to_schema(
+    columns: &[ColumnInfo],
+    read_options: &ReadOptions,
+) -> Schema {
+    let fields: Vec<_> = columns
+        .iter()
+        .map(|column_info| column_info.field(read_options))
+        .collect();
     Schema::new(fields)
 }
 
@@ -248,44 +1075,1435 @@ pub(crate) fn record_batch_from_name_array_iterator<
     } else {
         // We use `try_from_iter_with_nullable` because `try_from_iter` relies on `array.null_count() > 0;`
         // to determine if the array is nullable. This is not the case for `NullArray` which has no nulls.
-        RecordBatch::try_from_iter_with_nullable(iter.map(|(field_name, array)| {
+        let rb = RecordBatch::try_from_iter_with_nullable(iter.map(|(field_name, array)| {
             let nullable = array.is_nullable();
             (field_name, array, nullable)
         }))
         .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
-        .with_context(|| "could not create RecordBatch from iterable")
+        .with_context(|| "could not create RecordBatch from iterable")?;
+
+        // The fields are built from the arrays, so the metadata of the schema's fields (e.g. the
+        // index column marker) must be carried over
+        let fields: Vec<_> = rb
+            .schema_ref()
+            .fields()
+            .iter()
+            .zip(schema.fields())
+            .map(|(field, schema_field)| {
+                field
+                    .as_ref()
+                    .clone()
+                    .with_metadata(schema_field.metadata().clone())
+            })
+            .collect();
+        rb.with_schema(Arc::new(Schema::new(fields)))
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            .with_context(|| "could not set the metadata of the RecordBatch's fields")
+    }
+}
+
+/// Returns the distinct currency symbols found in a range of a column, in order of appearance
+fn detected_currencies<'a, DT: CellType + DataType>(
+    data: &Range<DT>,
+    col: usize,
+    offset: usize,
+    limit: usize,
+    read_options: &'a ReadOptions,
+) -> Vec<&'a str> {
+    let mut currencies = Vec::new();
+    for (symbol, _) in (offset..limit).filter_map(|row| {
+        data.get((row, col))
+            .and_then(|cell| cell.get_string())
+            .and_then(|value| read_options.currency_amount(value))
+    }) {
+        if !currencies.contains(&symbol) {
+            currencies.push(symbol);
+        }
     }
+    currencies
 }
 
-/// Creates an arrow `RecordBatch` from `ExcelSheetData`. Expects the following parameters:
+/// Stores the currency symbols found in the float columns in the metadata of their field, as a
+/// comma-separated list
+fn with_currency_metadata<DT: CellType + DataType>(
+    schema: Schema,
+    columns: &[ColumnInfo],
+    data: &Range<DT>,
+    offset: usize,
+    limit: usize,
+    read_options: &ReadOptions,
+) -> Schema {
+    let fields: Vec<_> = schema
+        .fields()
+        .iter()
+        .zip(columns)
+        .map(|(field, column_info)| {
+            let field = field.as_ref().clone();
+            if column_info.dtype() != &DType::Float {
+                return field;
+            }
+            let currencies =
+                detected_currencies(data, column_info.index(), offset, limit, read_options);
+            if currencies.is_empty() {
+                return field;
+            }
+            let mut metadata = field.metadata().clone();
+            metadata.insert(CURRENCY_METADATA_KEY.to_owned(), currencies.join(","));
+            field.with_metadata(metadata)
+        })
+        .collect();
+    Schema::new(fields)
+}
+
+/// Marks the fields of the columns without null cells from `offset` to `limit` as non-nullable.
+/// Null columns stay nullable
+pub(crate) fn with_tight_nullability<DT: CellType + DataType>(
+    schema: Schema,
+    columns: &[ColumnInfo],
+    data: &Range<DT>,
+    offset: usize,
+    limit: usize,
+    read_options: &ReadOptions,
+) -> Schema {
+    let fields: Vec<_> = schema
+        .fields()
+        .iter()
+        .zip(columns)
+        .map(|(field, column_info)| {
+            let nullable = column_info.dtype() == &DType::Null
+                || has_null_cells(data, column_info, offset, limit, read_options);
+            field.as_ref().clone().with_nullable(nullable)
+        })
+        .collect();
+    Schema::new(fields)
+}
+
+/// Sets the nullability of the fields of a record batch to the one of the fields of `schema`, so
+/// that both agree even if filled nulls left a nullable column without nulls
+pub(crate) fn with_nullability_of(
+    rb: RecordBatch,
+    schema: &Schema,
+) -> FastExcelResult<RecordBatch> {
+    let fields: Vec<_> = rb
+        .schema_ref()
+        .fields()
+        .iter()
+        .zip(schema.fields())
+        .map(|(field, schema_field)| {
+            field
+                .as_ref()
+                .clone()
+                .with_nullable(schema_field.is_nullable())
+        })
+        .collect();
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), rb.columns().to_vec())
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+}
+
+/// Creates an arrow `RecordBatch` from a calamine `Range`. Expects the following parameters:
 /// * `columns`: a slice of `ColumnInfo`, representing the columns that should be extracted from the range
-/// * `data`: the sheets data, as an `ExcelSheetData`
+/// * `data`: the sheet's data, as a calamine `Range`
 /// * `offset`: the row index at which to start
 /// * `limit`: the row index at which to stop (excluded)
+/// * `dtype_coercion`: whether cells that do not match their column's dtype should be an error
+/// * `read_options`: options impacting how cells are converted to arrow arrays
+pub(crate) fn record_batch_from_range_and_columns<DT: CellType + Debug + DataType + Sync>(
+    columns: &[ColumnInfo],
+    data: &Range<DT>,
+    offset: usize,
+    limit: usize,
+    dtype_coercion: &DTypeCoercion,
+    read_options: &ReadOptions,
+) -> FastExcelResult<RecordBatch> {
+    let mut schema = selected_columns_to_schema(columns, read_options);
+    if read_options.capture_currency {
+        schema = with_currency_metadata(schema, columns, data, offset, limit, read_options);
+    }
+    let arrays =
+        create_arrays_for_columns(columns, data, offset, limit, dtype_coercion, read_options)?;
+
+    record_batch_from_name_array_iterator(columns.iter().map(ColumnInfo::name).zip(arrays), schema)
+}
+
+/// Fills the nulls at the top of the forward-filled columns of `rb` with `previous_row`, the
+/// last row of the batch above it. `rb` and `previous_row` must contain `columns`
+pub(crate) fn forward_fill_from_previous_batch(
+    rb: RecordBatch,
+    previous_row: &RecordBatch,
+    columns: &[ColumnInfo],
+    read_options: &ReadOptions,
+) -> FastExcelResult<RecordBatch> {
+    if rb.num_rows() == 0
+        || !columns
+            .iter()
+            .any(|column_info| read_options.is_forward_filled(column_info))
+    {
+        return Ok(rb);
+    }
+    let arrays = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, column_info)| {
+            let array = rb.column(idx);
+            if !read_options.is_forward_filled(column_info) || array.is_valid(0) {
+                return Ok(Arc::clone(array));
+            }
+            concat(&[previous_row.column(idx).as_ref(), array.as_ref()])
+                .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+                .and_then(forward_fill)
+                .map(|array| array.slice(1, rb.num_rows()))
+        })
+        .collect::<FastExcelResult<Vec<_>>>()?;
+    RecordBatch::try_new(rb.schema(), arrays)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+}
+
+/// Creates the arrow arrays of `columns`, in order. Columns are built in parallel when the rows to
+/// read contain enough cells, in the thread pool of the `n_threads` of `read_options`
+fn create_arrays_for_columns<DT: CellType + Debug + DataType + Sync>(
+    columns: &[ColumnInfo],
+    data: &Range<DT>,
+    offset: usize,
+    limit: usize,
+    dtype_coercion: &DTypeCoercion,
+    read_options: &ReadOptions,
+) -> FastExcelResult<Vec<Arc<dyn Array>>> {
+    let create_array = |column_info: &ColumnInfo| {
+        create_array_for_column(
+            data,
+            column_info,
+            offset,
+            limit,
+            dtype_coercion,
+            read_options,
+        )
+    };
+    let cell_count = columns.len() * limit.saturating_sub(offset);
+    if cell_count < PARALLEL_READ_MIN_CELLS
+        || columns.len() < 2
+        || read_options.n_threads == Some(1)
+    {
+        return columns.iter().map(create_array).collect();
+    }
+    thread_pool::install(read_options.n_threads, || {
+        columns.par_iter().map(create_array).collect()
+    })?
+}
+
+/// Creates an arrow `RecordBatch` from `ExcelSheetData`. See `record_batch_from_range_and_columns`
+/// for the expected parameters
 pub(crate) fn record_batch_from_data_and_columns(
     columns: &[ColumnInfo],
     data: &ExcelSheetData,
     offset: usize,
     limit: usize,
+    dtype_coercion: &DTypeCoercion,
+    read_options: &ReadOptions,
 ) -> FastExcelResult<RecordBatch> {
-    let schema = selected_columns_to_schema(columns);
-    let iter = columns.iter().map(|column_info| {
-        let col_idx = column_info.index();
-        let dtype = *column_info.dtype();
-        (
-            column_info.name.as_str(),
-            match dtype {
-                DType::Null => Arc::new(NullArray::new(limit - offset)),
-                DType::Int => create_int_array(data, col_idx, offset, limit),
-                DType::Float => create_float_array(data, col_idx, offset, limit),
-                DType::String => create_string_array(data, col_idx, offset, limit),
-                DType::Bool => create_boolean_array(data, col_idx, offset, limit),
-                DType::DateTime => create_datetime_array(data, col_idx, offset, limit),
-                DType::Date => create_date_array(data, col_idx, offset, limit),
-                DType::Duration => create_duration_array(data, col_idx, offset, limit),
-            },
+    match data {
+        ExcelSheetData::Owned(range) => record_batch_from_range_and_columns(
+            columns,
+            range,
+            offset,
+            limit,
+            dtype_coercion,
+            read_options,
+        ),
+        ExcelSheetData::Ref(range) => record_batch_from_range_and_columns(
+            columns,
+            range,
+            offset,
+            limit,
+            dtype_coercion,
+            read_options,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use arrow::array::{
+        BooleanArray, Date32Array, Float64Array, Int32Array, Int64Array, Int8Array, StringArray,
+        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+    };
+    use arrow::datatypes::DataType as ArrowDataType;
+    use calamine::{Cell, CellErrorType, ExcelDateTime, ExcelDateTimeType};
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::types::{
+        idx_or_name::IdxOrName,
+        python::excelsheet::column_info::{ColumnNameFrom, DTypeFrom},
+        read_options::{TimestampUnit, UNIX_EPOCH},
+    };
+
+    #[rstest]
+    #[case(DType::Float)]
+    #[case(DType::String)]
+    fn error_cells_fail_strict_columns(#[case] dtype: DType) {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.5)),
+            Cell::new((1, 0), CalData::Error(CellErrorType::NA)),
+            Cell::new((2, 0), CalData::Error(CellErrorType::Div0)),
+        ]);
+        let column_info = ColumnInfo::new(
+            "ratio".to_owned(),
+            0,
+            ColumnNameFrom::LookedUp,
+            dtype,
+            DTypeFrom::ProvidedByName,
+        );
+        let read_options = ReadOptions::default();
+
+        // Error cells are null when dtypes are coerced
+        let array = create_array_for_column(
+            &range,
+            &column_info,
+            0,
+            3,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+        assert_eq!(array.null_count(), 2);
+        // #N/A is a null-like value, #DIV/0! is not
+        assert!(create_array_for_column(
+            &range,
+            &column_info,
+            0,
+            2,
+            &DTypeCoercion::Strict,
+            &read_options
         )
-    });
+        .is_ok());
+        let err = create_array_for_column(
+            &range,
+            &column_info,
+            0,
+            3,
+            &DTypeCoercion::Strict,
+            &read_options,
+        )
+        .unwrap_err();
+        assert!(matches!(err.kind, FastExcelErrorKind::CalamineCellError(_)));
+    }
+
+    #[test]
+    fn int_array_distinguishes_zero_from_empty() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Int(0)),
+            Cell::new((1, 0), CalData::Empty),
+            Cell::new((2, 0), CalData::Int(5)),
+            Cell::new((3, 0), CalData::Float(0.0)),
+        ]);
+
+        let array = create_int_array(&range, 0, 0, 4, &ReadOptions::default());
+
+        assert_eq!(
+            array.as_ref(),
+            &Int64Array::from(vec![Some(0), None, Some(5), Some(0)]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn int_array_nulls_floats_beyond_the_i64_range() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(-9_223_372_036_854_775_808.0)),
+            Cell::new((1, 0), CalData::Float(9_223_372_036_854_775_808.0)),
+            Cell::new((2, 0), CalData::Float(1e20)),
+            Cell::new((3, 0), CalData::Float(f64::NAN)),
+            Cell::new((4, 0), CalData::String("99999999999999999999".to_owned())),
+        ]);
+
+        let array = create_int_array(&range, 0, 0, 5, &ReadOptions::default());
+
+        assert_eq!(
+            array.as_ref(),
+            &Int64Array::from(vec![Some(i64::MIN), None, None, None, None]) as &dyn Array
+        );
+        // Guessing dtypes, integers followed by huge floats are floats
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Int(i64::MAX)),
+            Cell::new((1, 0), CalData::Float(1e20)),
+        ]);
+        assert_eq!(
+            get_dtype_for_column(&range, SampleRows::new(0, 2), 0, &DTypeCoercion::Coerce).unwrap(),
+            DType::Float
+        );
+    }
+
+    // 2023-07-21 12:00:00
+    const DATETIME_SERIAL: f64 = 45128.5;
+    const DATETIME_MILLIS: i64 = 1_689_940_800_000;
+
+    #[rstest]
+    #[case(TimestampUnit::Millisecond, Arc::new(TimestampMillisecondArray::from(vec![Some(DATETIME_MILLIS), None])))]
+    #[case(TimestampUnit::Microsecond, Arc::new(TimestampMicrosecondArray::from(vec![Some(DATETIME_MILLIS * 1_000), None])))]
+    #[case(TimestampUnit::Nanosecond, Arc::new(TimestampNanosecondArray::from(vec![Some(DATETIME_MILLIS * 1_000_000), None])))]
+    fn datetime_array_uses_the_requested_unit(
+        #[case] unit: TimestampUnit,
+        #[case] expected: Arc<dyn Array>,
+    ) {
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::DateTime(ExcelDateTime::new(
+                    DATETIME_SERIAL,
+                    ExcelDateTimeType::DateTime,
+                    false,
+                )),
+            ),
+            Cell::new((1, 0), CalData::Empty),
+        ]);
+
+        let read_options = ReadOptions {
+            timestamp_unit: unit,
+            ..Default::default()
+        };
+
+        let array =
+            create_datetime_array(&range, 0, 0, 2, &DTypeCoercion::Coerce, &read_options).unwrap();
+
+        assert_eq!(array.as_ref(), expected.as_ref());
+    }
+
+    #[test]
+    fn date_and_datetime_arrays_use_the_epoch() {
+        let range = Range::from_sparse(vec![Cell::new(
+            (0, 0),
+            CalData::DateTime(ExcelDateTime::new(
+                DATETIME_SERIAL,
+                ExcelDateTimeType::DateTime,
+                false,
+            )),
+        )]);
+        let read_options = ReadOptions {
+            epoch: NaiveDate::from_ymd_opt(2023, 7, 1),
+            ..Default::default()
+        };
+
+        let array =
+            create_datetime_array(&range, 0, 0, 1, &DTypeCoercion::Coerce, &read_options).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &Int64Array::from(vec![(20 * 24 + 12) * 3_600_000]) as &dyn Array
+        );
+
+        let array =
+            create_date_array(&range, 0, 0, 1, &DTypeCoercion::Coerce, &read_options).unwrap();
+        assert_eq!(array.as_ref(), &Int32Array::from(vec![20]) as &dyn Array);
+    }
+
+    #[test]
+    fn datetimes_too_far_from_the_epoch_fail_strict_columns() {
+        let range = Range::from_sparse(vec![Cell::new(
+            (0, 0),
+            CalData::DateTimeIso("1600-01-01T00:00:00".to_owned()),
+        )]);
+        let read_options = ReadOptions {
+            epoch: NaiveDate::from_ymd_opt(2023, 7, 1),
+            timestamp_unit: TimestampUnit::Nanosecond,
+            ..Default::default()
+        };
+
+        let array =
+            create_datetime_array(&range, 0, 0, 1, &DTypeCoercion::Coerce, &read_options).unwrap();
+        assert_eq!(array.as_ref(), &Int64Array::from(vec![None]) as &dyn Array);
+
+        let err = create_datetime_array(&range, 0, 0, 1, &DTypeCoercion::Strict, &read_options)
+            .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[rstest]
+    #[case(false, NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())]
+    #[case(true, NaiveDate::from_ymd_opt(2027, 1, 2).unwrap())]
+    fn date_array_reads_numbers_as_excel_serials(#[case] date1904: bool, #[case] date: NaiveDate) {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Int(44927)),
+            Cell::new((1, 0), CalData::Float(44927.5)),
+        ]);
+        let read_options = ReadOptions {
+            date1904,
+            ..Default::default()
+        };
+
+        let array =
+            create_date_array(&range, 0, 0, 2, &DTypeCoercion::Coerce, &read_options).unwrap();
+        let days = i32::try_from(date.signed_duration_since(UNIX_EPOCH).num_days()).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &Date32Array::from(vec![days, days]) as &dyn Array
+        );
+
+        let array =
+            create_datetime_array(&range, 0, 1, 2, &DTypeCoercion::Coerce, &read_options).unwrap();
+        let noon = i64::from(days) * 86_400_000 + 12 * 3_600_000;
+        assert_eq!(
+            array.as_ref(),
+            &TimestampMillisecondArray::from(vec![noon]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn datetime_tz_array_converts_offsets_to_utc() {
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::String("1970-01-01T02:00:00+02:00".to_owned()),
+            ),
+            Cell::new(
+                (1, 0),
+                CalData::String("1970-01-01T00:00:01-00:30".to_owned()),
+            ),
+            Cell::new((2, 0), CalData::Empty),
+            Cell::new((3, 0), CalData::String("1970-01-01T00:00:00+2".to_owned())),
+        ]);
 
-    record_batch_from_name_array_iterator(iter, schema)
+        let array = create_datetime_tz_array(
+            &range,
+            0,
+            0,
+            4,
+            &DTypeCoercion::Coerce,
+            &ReadOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &TimestampMillisecondArray::from(vec![Some(0), Some(1_801_000), None, None])
+                .with_timezone("UTC") as &dyn Array
+        );
+
+        // With strict type coercion, the malformed offset is an error
+        let err = create_datetime_tz_array(
+            &range,
+            0,
+            0,
+            4,
+            &DTypeCoercion::Strict,
+            &ReadOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[test]
+    fn naive_datetimes_of_datetime_tz_columns_are_in_the_assumed_timezone() {
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::String("1970-01-01T02:00:00+02:00".to_owned()),
+            ),
+            // 1970-01-01 02:00:00
+            Cell::new(
+                (1, 0),
+                CalData::DateTime(ExcelDateTime::new(
+                    25_569.0 + 1.0 / 12.0,
+                    ExcelDateTimeType::DateTime,
+                    false,
+                )),
+            ),
+        ]);
+        let read_options = ReadOptions {
+            assume_timezone: Some("+02:00".parse().unwrap()),
+            ..Default::default()
+        };
+
+        let array =
+            create_datetime_tz_array(&range, 0, 0, 2, &DTypeCoercion::Coerce, &read_options)
+                .unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &TimestampMillisecondArray::from(vec![Some(0), Some(0)]).with_timezone("UTC")
+                as &dyn Array
+        );
+    }
+
+    #[test]
+    fn excel_serial_array_keeps_raw_serials() {
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::DateTime(ExcelDateTime::new(
+                    DATETIME_SERIAL,
+                    ExcelDateTimeType::DateTime,
+                    false,
+                )),
+            ),
+            Cell::new((1, 0), CalData::Float(45128.25)),
+            Cell::new((2, 0), CalData::Int(45128)),
+            Cell::new((3, 0), CalData::String("45128".to_string())),
+            Cell::new((4, 0), CalData::Empty),
+        ]);
+
+        let array = create_excel_serial_array(&range, 0, 0, 5);
+
+        assert_eq!(
+            array.as_ref(),
+            &Float64Array::from(vec![
+                Some(DATETIME_SERIAL),
+                Some(45128.25),
+                Some(45128.0),
+                None,
+                None
+            ]) as &dyn Array
+        );
+    }
+
+    fn int_flags_range() -> Range<CalData> {
+        Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Int(1)),
+            Cell::new((1, 0), CalData::Int(0)),
+            Cell::new((2, 0), CalData::Float(1.0)),
+            Cell::new((3, 0), CalData::Empty),
+            Cell::new((4, 0), CalData::Int(2)),
+        ])
+    }
+
+    #[test]
+    fn boolean_from_int_array_maps_zero_and_one() {
+        let array = create_boolean_from_int_array(
+            &int_flags_range(),
+            0,
+            0,
+            4,
+            &DTypeCoercion::Strict,
+            None,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            array.as_ref(),
+            &BooleanArray::from(vec![Some(true), Some(false), Some(true), None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn boolean_from_int_array_other_values() {
+        let array = create_boolean_from_int_array(
+            &int_flags_range(),
+            0,
+            3,
+            5,
+            &DTypeCoercion::Coerce,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &BooleanArray::from(vec![None, None]) as &dyn Array
+        );
+
+        let err = create_boolean_from_int_array(
+            &int_flags_range(),
+            0,
+            3,
+            5,
+            &DTypeCoercion::Strict,
+            None,
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[test]
+    fn boolean_arrays_as_int8() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Bool(true)),
+            Cell::new((1, 0), CalData::Bool(false)),
+            Cell::new((2, 0), CalData::Empty),
+        ]);
+        let expected = Int8Array::from(vec![Some(1), Some(0), None]);
+
+        let read_options = ReadOptions {
+            bool_as_int8: true,
+            ..Default::default()
+        };
+        let array =
+            create_boolean_array(&range, 0, 0, 3, &DTypeCoercion::Coerce, &read_options).unwrap();
+        assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+        let array = create_boolean_from_int_array(
+            &int_flags_range(),
+            0,
+            1,
+            4,
+            &DTypeCoercion::Strict,
+            None,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &Int8Array::from(vec![Some(0), Some(1), None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn row_values_are_converted_using_the_column_dtype() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(42.0)),
+            Cell::new((0, 1), CalData::Float(1.5)),
+            Cell::new((0, 2), CalData::String("hello".to_string())),
+            Cell::new((0, 3), CalData::Int(1)),
+            Cell::new((0, 4), CalData::Empty),
+        ]);
+        let dtypes = [
+            DType::Int,
+            DType::Float,
+            DType::String,
+            DType::BoolFromInt,
+            DType::Float,
+        ];
+        let columns: Vec<ColumnInfo> = dtypes
+            .into_iter()
+            .enumerate()
+            .map(|(idx, dtype)| {
+                ColumnInfo::new(
+                    format!("col{idx}"),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    dtype,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+
+        Python::with_gil(|py| {
+            let values = row_to_py_values(
+                &range,
+                0,
+                &columns,
+                &DTypeCoercion::Coerce,
+                &ReadOptions::default(),
+                py,
+            )
+            .unwrap();
+
+            assert_eq!(values[0].extract::<i64>(py).unwrap(), 42);
+            assert_eq!(values[1].extract::<f64>(py).unwrap(), 1.5);
+            assert_eq!(values[2].extract::<String>(py).unwrap(), "hello");
+            assert!(values[3].extract::<bool>(py).unwrap());
+            assert!(values[4].is_none(py));
+        });
+    }
+
+    #[test]
+    fn boolean_arrays_fill_empty_cells() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Bool(true)),
+            Cell::new((1, 0), CalData::Empty),
+            Cell::new((2, 0), CalData::String("nope".to_string())),
+        ]);
+
+        let read_options = ReadOptions {
+            bool_fill: Some(false),
+            ..Default::default()
+        };
+        let array =
+            create_boolean_array(&range, 0, 0, 3, &DTypeCoercion::Coerce, &read_options).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &BooleanArray::from(vec![Some(true), Some(false), None]) as &dyn Array
+        );
+
+        let array = create_boolean_from_int_array(
+            &int_flags_range(),
+            0,
+            2,
+            5,
+            &DTypeCoercion::Coerce,
+            Some(false),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &BooleanArray::from(vec![Some(true), Some(false), None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn arrays_fill_nulls_with_the_fill_value_of_their_column() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.5)),
+            Cell::new((0, 1), CalData::String("a".to_owned())),
+            Cell::new((1, 0), CalData::String("nope".to_owned())),
+            Cell::new((2, 0), CalData::Empty),
+            Cell::new((2, 1), CalData::String("c".to_owned())),
+            Cell::new((3, 2), CalData::Empty),
+        ]);
+        let column = |idx: usize, dtype: DType| {
+            ColumnInfo::new(
+                format!("col{idx}"),
+                idx,
+                ColumnNameFrom::LookedUp,
+                dtype,
+                DTypeFrom::Guessed,
+            )
+        };
+        let read_options = ReadOptions {
+            timestamp_unit: TimestampUnit::Microsecond,
+            fill_values: HashMap::from([
+                ("col0".to_owned(), FillValue::Int(0)),
+                ("col1".to_owned(), FillValue::String(String::new())),
+                (
+                    "col2".to_owned(),
+                    FillValue::DateTime(
+                        NaiveDate::from_ymd_opt(1970, 1, 2)
+                            .unwrap()
+                            .and_hms_opt(0, 0, 0)
+                            .unwrap(),
+                    ),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let create_array = |column_info: &ColumnInfo| {
+            create_array_for_column(
+                &range,
+                column_info,
+                0,
+                4,
+                &DTypeCoercion::Coerce,
+                &read_options,
+            )
+            .unwrap()
+        };
+
+        // Cells that cannot be converted are filled as well as empty ones
+        assert_eq!(
+            create_array(&column(0, DType::Float)).as_ref(),
+            &Float64Array::from(vec![1.5, 0.0, 0.0, 0.0]) as &dyn Array
+        );
+        assert_eq!(
+            create_array(&column(1, DType::String)).as_ref(),
+            &StringArray::from(vec!["a", "", "c", ""]) as &dyn Array
+        );
+        assert_eq!(
+            create_array(&column(2, DType::DateTime)).as_ref(),
+            &TimestampMicrosecondArray::from(vec![86_400_000_000; 4]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn boolean_arrays_parse_string_values() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::String("Y".to_string())),
+            Cell::new((1, 0), CalData::String("N".to_string())),
+            Cell::new((2, 0), CalData::Bool(true)),
+            Cell::new((3, 0), CalData::String("maybe".to_string())),
+        ]);
+        let read_options = ReadOptions {
+            true_values: vec!["Y".to_string()],
+            false_values: vec!["N".to_string()],
+            ..Default::default()
+        };
+
+        let array =
+            create_boolean_array(&range, 0, 0, 4, &DTypeCoercion::Coerce, &read_options).unwrap();
+        assert_eq!(
+            array.as_ref(),
+            &BooleanArray::from(vec![Some(true), Some(false), Some(true), None]) as &dyn Array
+        );
+
+        let err = create_boolean_array(&range, 0, 0, 4, &DTypeCoercion::Strict, &read_options)
+            .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[test]
+    fn record_batch_nullability_is_based_on_every_row() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.0)),
+            Cell::new((0, 1), CalData::Float(2.0)),
+            Cell::new((1, 0), CalData::Float(3.0)),
+            Cell::new((1, 1), CalData::Empty),
+        ]);
+        let columns: Vec<ColumnInfo> = ["full", "with_nulls"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                ColumnInfo::new(
+                    name.to_owned(),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    DType::Float,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+        let read_options = ReadOptions::default();
+
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            2,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+
+        assert!(!rb.schema_ref().field(0).is_nullable());
+        assert!(rb.schema_ref().field(1).is_nullable());
+        // The schema computed without reading the data considers every field nullable
+        assert!(selected_columns_to_schema(&columns, &read_options)
+            .fields()
+            .iter()
+            .all(|field| field.is_nullable()));
+    }
+
+    #[test]
+    fn tightened_nullability_is_the_same_in_the_schema_and_the_record_batch() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.0)),
+            Cell::new((0, 1), CalData::Float(2.0)),
+            Cell::new((1, 0), CalData::Float(3.0)),
+            Cell::new((1, 1), CalData::Empty),
+        ]);
+        let columns: Vec<ColumnInfo> = [("full", DType::Float), ("filled", DType::Float)]
+            .into_iter()
+            .chain([("empty", DType::Null)])
+            .enumerate()
+            .map(|(idx, (name, dtype))| {
+                ColumnInfo::new(
+                    name.to_owned(),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    dtype,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+        let read_options = ReadOptions {
+            tighten_nullability: true,
+            fill_values: HashMap::from([("filled".to_owned(), FillValue::Float(0.0))]),
+            ..Default::default()
+        };
+
+        let schema = ExcelSheetData::from(range.clone()).schema(&columns, 0, 2, &read_options);
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            2,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+        // The filled column has no null once read, but had null cells
+        assert!(!rb.schema_ref().field(1).is_nullable());
+        let rb = with_nullability_of(rb, &schema).unwrap();
+
+        let nullability: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| field.is_nullable())
+            .collect();
+        assert_eq!(nullability, [false, true, true]);
+        assert_eq!(rb.schema_ref().as_ref(), &schema);
+    }
+
+    #[test]
+    fn datetimes_without_a_timestamp_in_the_unit_are_nullable() {
+        // 1600-01-01 is more than 292 years before the Unix epoch, so it has no i64 timestamp in
+        // nanoseconds
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::DateTimeIso("1600-01-01T00:00:00".to_owned()),
+            ),
+            Cell::new(
+                (1, 0),
+                CalData::DateTimeIso("2020-01-01T00:00:00".to_owned()),
+            ),
+        ]);
+        let columns = vec![ColumnInfo::new(
+            "datetime".to_owned(),
+            0,
+            ColumnNameFrom::LookedUp,
+            DType::DateTime,
+            DTypeFrom::Guessed,
+        )];
+        let read_options = ReadOptions {
+            timestamp_unit: TimestampUnit::Nanosecond,
+            tighten_nullability: true,
+            ..Default::default()
+        };
+
+        let schema = ExcelSheetData::from(range.clone()).schema(&columns, 0, 2, &read_options);
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            2,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .and_then(|rb| with_nullability_of(rb, &schema))
+        .unwrap();
+
+        assert!(schema.field(0).is_nullable());
+        assert_eq!(rb.column(0).null_count(), 1);
+    }
+
+    /// The smallest positive subnormal f64
+    const MIN_SUBNORMAL: f64 = 5e-324;
+    /// The largest subnormal f64
+    const MAX_SUBNORMAL: f64 = 2.225_073_858_507_201e-308;
+
+    #[rstest]
+    #[case(CalData::Float(f64::MAX), f64::MAX)]
+    #[case(CalData::Float(-f64::MAX), -f64::MAX)]
+    #[case(CalData::Float(f64::MIN_POSITIVE), f64::MIN_POSITIVE)]
+    #[case(CalData::Float(MIN_SUBNORMAL), MIN_SUBNORMAL)]
+    #[case(CalData::Float(-0.0), -0.0)]
+    #[case(CalData::Int(1 << 53), 9_007_199_254_740_992.0)]
+    #[case(CalData::String("1.7976931348623157e308".to_owned()), f64::MAX)]
+    #[case(CalData::String("4.9406564584124654e-324".to_owned()), MIN_SUBNORMAL)]
+    #[case(CalData::String("2.2250738585072009e-308".to_owned()), MAX_SUBNORMAL)]
+    #[case(CalData::String("0.1".to_owned()), 0.1)]
+    // Halfway between two floats, rounded to the even one
+    #[case(CalData::String("9007199254740993".to_owned()), 9_007_199_254_740_992.0)]
+    #[case(CalData::String("-0".to_owned()), -0.0)]
+    fn float_array_preserves_every_bit(#[case] cell: CalData, #[case] expected: f64) {
+        let range = Range::from_sparse(vec![Cell::new((0, 0), cell)]);
+
+        let array = create_float_array(
+            &range,
+            0,
+            0,
+            1,
+            &DTypeCoercion::Coerce,
+            &ReadOptions::default(),
+        )
+        .unwrap();
+
+        let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(array.value(0).to_bits(), expected.to_bits());
+    }
+
+    #[rstest]
+    #[case("4,9406564584124654e-324", MIN_SUBNORMAL)]
+    #[case("179.769.313.486.231.570.000.000,5e285", f64::MAX)]
+    #[case("€2,2250738585072014e-308", f64::MIN_POSITIVE)]
+    #[case("-€0,1", -0.1)]
+    fn float_array_preserves_every_bit_with_separators(#[case] value: &str, #[case] expected: f64) {
+        let range = Range::from_sparse(vec![Cell::new((0, 0), CalData::String(value.to_owned()))]);
+        let read_options = ReadOptions {
+            currency_symbols: vec!["€".to_owned()],
+            decimal_separator: Some(','),
+            thousands_separator: Some('.'),
+            ..Default::default()
+        };
+
+        let array =
+            create_float_array(&range, 0, 0, 1, &DTypeCoercion::Coerce, &read_options).unwrap();
+
+        let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(array.value(0).to_bits(), expected.to_bits());
+    }
+
+    #[test]
+    fn float_arrays_parse_currency_amounts() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::String("$1,234.56".to_owned())),
+            Cell::new((1, 0), CalData::String("12 €".to_owned())),
+            Cell::new((2, 0), CalData::Float(3.0)),
+            Cell::new((3, 0), CalData::String("$abc".to_owned())),
+        ]);
+        let columns = vec![ColumnInfo::new(
+            "amount".to_owned(),
+            0,
+            ColumnNameFrom::LookedUp,
+            DType::Float,
+            DTypeFrom::ProvidedByName,
+        )];
+        let read_options = ReadOptions {
+            currency_symbols: vec!["$".to_owned(), "€".to_owned()],
+            capture_currency: true,
+            ..Default::default()
+        };
+
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            4,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+        assert_eq!(
+            rb.column(0).as_ref(),
+            &Float64Array::from(vec![Some(1234.56), Some(12.0), Some(3.0), None]) as &dyn Array
+        );
+        assert_eq!(
+            rb.schema_ref()
+                .field(0)
+                .metadata()
+                .get(CURRENCY_METADATA_KEY)
+                .map(String::as_str),
+            Some("$,€")
+        );
+
+        let err = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            4,
+            &DTypeCoercion::Strict,
+            &read_options,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[test]
+    fn forward_filled_columns_take_the_last_value_above_their_nulls() {
+        // The first row only has a value in a column that is not read
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 2), CalData::String("other".to_owned())),
+            Cell::new((1, 0), CalData::String("a".to_owned())),
+            Cell::new((1, 1), CalData::Float(1.0)),
+            Cell::new((2, 0), CalData::Empty),
+            Cell::new((3, 0), CalData::String("b".to_owned())),
+            Cell::new((3, 1), CalData::String("nope".to_owned())),
+            Cell::new((4, 1), CalData::Float(2.0)),
+        ]);
+        let columns = vec![
+            ColumnInfo::new(
+                "group".to_owned(),
+                0,
+                ColumnNameFrom::LookedUp,
+                DType::String,
+                DTypeFrom::Guessed,
+            ),
+            ColumnInfo::new(
+                "value".to_owned(),
+                1,
+                ColumnNameFrom::LookedUp,
+                DType::Float,
+                DTypeFrom::Guessed,
+            ),
+        ];
+        let read_options = ReadOptions {
+            forward_fill: vec![IdxOrName::Name("group".to_owned()), IdxOrName::Idx(1)],
+            ..Default::default()
+        };
+        let read_rows = |offset, limit| {
+            record_batch_from_range_and_columns(
+                &columns,
+                &range,
+                offset,
+                limit,
+                &DTypeCoercion::Coerce,
+                &read_options,
+            )
+            .unwrap()
+        };
+
+        // The first row is null in both columns, and the string of the value column is coerced to
+        // null before being filled
+        let rb = read_rows(0, 5);
+        assert_eq!(
+            rb.column(0).as_ref(),
+            &StringArray::from(vec![None, Some("a"), Some("a"), Some("b"), Some("b")])
+                as &dyn Array
+        );
+        assert_eq!(
+            rb.column(1).as_ref(),
+            &Float64Array::from(vec![None, Some(1.0), Some(1.0), Some(1.0), Some(2.0)])
+                as &dyn Array
+        );
+
+        // Batches continue the previous one
+        let first_batch = read_rows(0, 2);
+        let second_batch = forward_fill_from_previous_batch(
+            read_rows(2, 5),
+            &first_batch.slice(1, 1),
+            &columns,
+            &read_options,
+        )
+        .unwrap();
+        assert_eq!(
+            second_batch.columns(),
+            rb.slice(2, 3).columns(),
+            "batches differ from the whole column"
+        );
+    }
+
+    #[test]
+    fn na_values_are_null_in_string_and_numeric_arrays() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::String("a".to_owned())),
+            Cell::new((0, 1), CalData::Float(1.5)),
+            Cell::new((1, 0), CalData::String("-".to_owned())),
+            Cell::new((1, 1), CalData::String("-".to_owned())),
+            Cell::new((2, 0), CalData::String(String::new())),
+            Cell::new((2, 1), CalData::String(String::new())),
+        ]);
+        let column = |name: &str, index, dtype| {
+            ColumnInfo::new(
+                name.to_owned(),
+                index,
+                ColumnNameFrom::LookedUp,
+                dtype,
+                DTypeFrom::ProvidedByName,
+            )
+        };
+        let columns = vec![
+            column("name", 0, DType::String),
+            column("value", 1, DType::Float),
+        ];
+        let read_options = ReadOptions {
+            na_values: vec!["-".to_owned()],
+            ..Default::default()
+        };
+
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            3,
+            &DTypeCoercion::Strict,
+            &read_options,
+        )
+        .unwrap();
+        assert_eq!(
+            rb.column(0).as_ref(),
+            &StringArray::from(vec![Some("a"), None, Some("")]) as &dyn Array
+        );
+        assert_eq!(
+            rb.column(1).as_ref(),
+            &Float64Array::from(vec![Some(1.5), None, None]) as &dyn Array
+        );
+
+        let read_options = ReadOptions {
+            empty_string_as_null: true,
+            ..read_options
+        };
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            3,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+        assert_eq!(
+            rb.column(0).as_ref(),
+            &StringArray::from(vec![Some("a"), None, None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn trimmed_strings_are_checked_for_null_values() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::String(" a\t".to_owned())),
+            Cell::new((1, 0), CalData::String("\u{a0}b c\u{a0}".to_owned())),
+            Cell::new((2, 0), CalData::String("  ".to_owned())),
+            Cell::new((3, 0), CalData::String(" - ".to_owned())),
+        ]);
+        let read_string_array = |read_options: &ReadOptions| {
+            array_impls::create_string_array(&range, 0, 0, 4, read_options)
+        };
+
+        assert_eq!(
+            read_string_array(&ReadOptions::default()).as_ref(),
+            &StringArray::from(vec![" a\t", "\u{a0}b c\u{a0}", "  ", " - "]) as &dyn Array
+        );
+        let read_options = ReadOptions {
+            trim_strings: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            read_string_array(&read_options).as_ref(),
+            &StringArray::from(vec!["a", "b c", "", "-"]) as &dyn Array
+        );
+        let read_options = ReadOptions {
+            na_values: vec!["-".to_owned()],
+            empty_string_as_null: true,
+            ..read_options
+        };
+        assert_eq!(
+            read_string_array(&read_options).as_ref(),
+            &StringArray::from(vec![Some("a"), Some("b c"), None, None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn columns_read_on_several_threads_keep_their_order() {
+        let row_count = 30_000;
+        let range = Range::from_sparse(
+            (0..row_count)
+                .flat_map(|row| {
+                    [
+                        Cell::new((row, 0), CalData::Float(f64::from(row))),
+                        Cell::new((row, 1), CalData::String(format!("row {row}"))),
+                        Cell::new((row, 2), CalData::Int(i64::from(row) * 2)),
+                        Cell::new((row, 3), CalData::Bool(row % 2 == 0)),
+                    ]
+                })
+                .collect(),
+        );
+        let column = |name: &str, index, dtype| {
+            ColumnInfo::new(
+                name.to_owned(),
+                index,
+                ColumnNameFrom::LookedUp,
+                dtype,
+                DTypeFrom::ProvidedByName,
+            )
+        };
+        let columns = vec![
+            column("float", 0, DType::Float),
+            column("string", 1, DType::String),
+            column("int", 2, DType::Int),
+            column("bool", 3, DType::Bool),
+        ];
+        let read = |n_threads| {
+            record_batch_from_range_and_columns(
+                &columns,
+                &range,
+                0,
+                row_count as usize,
+                &DTypeCoercion::Coerce,
+                &ReadOptions {
+                    n_threads: Some(n_threads),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let rb = read(3);
+        assert_eq!(rb, read(1));
+        assert_eq!(
+            rb.schema()
+                .fields()
+                .iter()
+                .map(|field| field.name())
+                .collect::<Vec<_>>(),
+            ["float", "string", "int", "bool"]
+        );
+        assert_eq!(
+            rb.column(1).as_ref(),
+            &StringArray::from_iter_values((0..row_count).map(|row| format!("row {row}")))
+                as &dyn Array
+        );
+    }
+
+    #[test]
+    fn record_batch_keeps_index_column_metadata() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.0)),
+            Cell::new((0, 1), CalData::Float(2.0)),
+        ]);
+        let columns: Vec<ColumnInfo> = ["a", "b"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                ColumnInfo::new(
+                    name.to_owned(),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    DType::Float,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+        let read_options = ReadOptions {
+            index_column: Some(IdxOrName::Name("b".to_owned())),
+            ..Default::default()
+        };
+
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            0,
+            1,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+
+        assert!(rb.schema_ref().field(0).metadata().is_empty());
+        assert_eq!(
+            rb.schema_ref().field(1).metadata(),
+            columns[1].field(&read_options).metadata()
+        );
+    }
+
+    #[test]
+    fn header_only_range_gives_an_empty_typed_record_batch() {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::String("a".to_owned())),
+            Cell::new((0, 1), CalData::String("b".to_owned())),
+            Cell::new((0, 2), CalData::String("c".to_owned())),
+        ]);
+        let columns: Vec<ColumnInfo> = [DType::Null, DType::Float, DType::String]
+            .into_iter()
+            .zip(["a", "b", "c"])
+            .enumerate()
+            .map(|(idx, (dtype, name))| {
+                ColumnInfo::new(
+                    name.to_owned(),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    dtype,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+        // The data starts right after the header, which is the last row
+        let rb = record_batch_from_range_and_columns(
+            &columns,
+            &range,
+            1,
+            1,
+            &DTypeCoercion::Coerce,
+            &ReadOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(rb.num_rows(), 0);
+        assert_eq!(
+            rb.schema_ref()
+                .fields()
+                .iter()
+                .map(|field| (field.name().as_str(), field.data_type().clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", ArrowDataType::Null),
+                ("b", ArrowDataType::Float64),
+                ("c", ArrowDataType::Utf8)
+            ]
+        );
+    }
 }
@@ -1,12 +1,12 @@
 use crate::types::idx_or_name::IdxOrName;
-use calamine::XlsxError;
+use calamine::{CellErrorType, OdsError, XlsError, XlsbError, XlsxError};
 use std::{error::Error, fmt::Display};
 
 #[derive(Debug)]
 pub(crate) enum FastExcelErrorKind {
     UnsupportedColumnTypeCombination(String),
     CannotRetrieveCellData(usize, usize),
-    CalamineCellError(calamine::CellErrorType),
+    CalamineCellError(CellErrorType),
     CalamineError(calamine::Error),
     SheetNotFound(IdxOrName),
     ColumnNotFound(IdxOrName),
@@ -14,9 +14,33 @@ pub(crate) enum FastExcelErrorKind {
     // the actual type has not much value for us, so we just store a string context
     ArrowError(String),
     InvalidParameters(String),
+    InvalidDtype(String),
+    UnknownColumn(IdxOrName),
+    CorruptFile(String),
+    SizeLimitExceeded(String),
+    ResourceLimit(String),
+    DuplicateColumn(String),
     Internal(String),
 }
 
+impl FastExcelErrorKind {
+    /// Converts an error returned by calamine while opening a workbook. Errors coming from the
+    /// underlying container (zip archive or CFB compound file) mean the file is damaged
+    pub(crate) fn from_open_error(err: calamine::Error) -> Self {
+        match err {
+            calamine::Error::Xlsx(XlsxError::Zip(zip_err))
+            | calamine::Error::Xlsb(XlsbError::Zip(zip_err))
+            | calamine::Error::Ods(OdsError::Zip(zip_err)) => {
+                FastExcelErrorKind::CorruptFile(format!("invalid zip archive: {zip_err}"))
+            }
+            calamine::Error::Xls(XlsError::Cfb(cfb_err)) => {
+                FastExcelErrorKind::CorruptFile(format!("invalid compound file: {cfb_err}"))
+            }
+            err => FastExcelErrorKind::CalamineError(err),
+        }
+    }
+}
+
 impl Display for FastExcelErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -27,7 +51,8 @@ impl Display for FastExcelErrorKind {
                 write!(f, "cannot retrieve cell data at ({row}, {col})")
             }
             FastExcelErrorKind::CalamineCellError(calamine_error) => {
-                write!(f, "calamine cell error: {calamine_error}")
+                let description = cell_error_description(calamine_error);
+                write!(f, "calamine cell error: {calamine_error} ({description})")
             }
             FastExcelErrorKind::CalamineError(calamine_error) => {
                 write!(f, "calamine error: {calamine_error}")
@@ -42,21 +67,108 @@ impl Display for FastExcelErrorKind {
             }
             FastExcelErrorKind::ArrowError(err) => write!(f, "arrow error: {err}"),
             FastExcelErrorKind::InvalidParameters(err) => write!(f, "invalid parameters: {err}"),
+            FastExcelErrorKind::InvalidDtype(dtype) => write!(f, "invalid dtype: {dtype}"),
+            FastExcelErrorKind::UnknownColumn(idx_or_name) => {
+                let message = idx_or_name.format_message();
+                write!(f, "dtype specified for unknown column {message}")
+            }
+            FastExcelErrorKind::CorruptFile(detail) => write!(
+                f,
+                "corrupt file: {detail}. The file may be incomplete (e.g. truncated by an interrupted download) or damaged"
+            ),
+            FastExcelErrorKind::SizeLimitExceeded(detail) => {
+                write!(f, "size limit exceeded: {detail}")
+            }
+            FastExcelErrorKind::ResourceLimit(detail) => {
+                write!(f, "resource limit exceeded: {detail}")
+            }
+            FastExcelErrorKind::DuplicateColumn(name) => {
+                write!(f, "duplicate column name \"{name}\"")
+            }
             FastExcelErrorKind::Internal(err) => write!(f, "fastexcel error: {err}"),
         }
     }
 }
 
+/// Location of the cell an error relates to. Every part is optional, as they are filled in by
+/// different layers (cell, column, sheet) while the error bubbles up
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct CellLocation {
+    /// Absolute (row, column) position of the cell in the sheet, starting at 0
+    pub(crate) position: Option<(usize, usize)>,
+    pub(crate) column_name: Option<String>,
+    pub(crate) sheet_name: Option<String>,
+}
+
+/// Converts a column index (starting at 0) to Excel column letters, e.g. 27 -> "AB"
+pub(crate) fn column_letters(mut col: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(b'A' + (col % 26) as u8);
+        if col < 26 {
+            break;
+        }
+        col = col / 26 - 1;
+    }
+    letters.reverse();
+    String::from_utf8(letters).expect("column letters are ASCII")
+}
+
+/// Spells out the kind of an error cell, e.g. `Div0` -> "division by zero"
+pub(crate) fn cell_error_description(cell_error: &CellErrorType) -> &'static str {
+    match cell_error {
+        CellErrorType::Div0 => "division by zero",
+        CellErrorType::NA => "value not available",
+        CellErrorType::Name => "invalid name",
+        CellErrorType::Null => "null intersection of ranges",
+        CellErrorType::Num => "invalid number",
+        CellErrorType::Ref => "invalid cell reference",
+        CellErrorType::Value => "invalid value",
+        CellErrorType::GettingData => "data still being retrieved",
+    }
+}
+
+impl Display for CellLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::with_capacity(3);
+        if let Some((row, col)) = self.position {
+            parts.push(format!(
+                "cell {letters}{excel_row} (row {row}, column {col})",
+                letters = column_letters(col),
+                excel_row = row + 1
+            ));
+        }
+        if let Some(column_name) = &self.column_name {
+            parts.push(format!("column \"{column_name}\""));
+        }
+        if let Some(sheet_name) = &self.sheet_name {
+            parts.push(format!("sheet \"{sheet_name}\""));
+        }
+        write!(f, "{}", parts.join(" in "))
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct FastExcelError {
     pub kind: FastExcelErrorKind,
     context: Vec<String>,
+    // Boxed to keep FastExcelResult small
+    cell_location: Option<Box<CellLocation>>,
 }
 
 pub(crate) trait ErrorContext {
     fn with_context<S: ToString, F>(self, ctx_fn: F) -> Self
     where
         F: FnOnce() -> S;
+
+    /// Marks the error as relating to the cell at the given absolute (row, column) position
+    fn with_cell_position(self, position: (usize, usize)) -> Self;
+
+    /// Fills in more details about the location of the cell the error relates to. Does nothing
+    /// if the error does not relate to a cell
+    fn with_cell_location<F>(self, update_fn: F) -> Self
+    where
+        F: FnOnce(&mut CellLocation);
 }
 
 impl FastExcelError {
@@ -64,13 +176,22 @@ impl FastExcelError {
         Self {
             kind,
             context: vec![],
+            cell_location: None,
         }
     }
+
+    #[cfg(test)]
+    pub(crate) fn cell_location(&self) -> Option<&CellLocation> {
+        self.cell_location.as_deref()
+    }
 }
 
 impl Display for FastExcelError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{kind}", kind = self.kind)?;
+        if let Some(cell_location) = &self.cell_location {
+            write!(f, " (at {cell_location})")?;
+        }
         if !self.context.is_empty() {
             writeln!(f, "\nContext:")?;
 
@@ -93,6 +214,23 @@ impl ErrorContext for FastExcelError {
         self.context.push(ctx_fn().to_string());
         self
     }
+
+    fn with_cell_position(mut self, position: (usize, usize)) -> Self {
+        self.cell_location
+            .get_or_insert_with(Default::default)
+            .position = Some(position);
+        self
+    }
+
+    fn with_cell_location<F>(mut self, update_fn: F) -> Self
+    where
+        F: FnOnce(&mut CellLocation),
+    {
+        if let Some(cell_location) = self.cell_location.as_mut() {
+            update_fn(cell_location);
+        }
+        self
+    }
 }
 
 impl From<FastExcelErrorKind> for FastExcelError {
@@ -119,6 +257,17 @@ impl<T> ErrorContext for FastExcelResult<T> {
             Err(e) => Err(e.with_context(ctx_fn)),
         }
     }
+
+    fn with_cell_position(self, position: (usize, usize)) -> Self {
+        self.map_err(|e| e.with_cell_position(position))
+    }
+
+    fn with_cell_location<F>(self, update_fn: F) -> Self
+    where
+        F: FnOnce(&mut CellLocation),
+    {
+        self.map_err(|e| e.with_cell_location(update_fn))
+    }
 }
 
 /// Contains Python versions of our custom errors
@@ -133,25 +282,39 @@ pub(crate) mod py_errors {
         PyException,
         "The base class for all fastexcel errors"
     );
+    // Base class for errors related to the content of a cell
+    create_exception!(
+        _fastexcel,
+        CellError,
+        FastExcelError,
+        "The base class for errors related to the content of a cell"
+    );
+    // Base class for errors related to the columns of a sheet or table
+    create_exception!(
+        _fastexcel,
+        SchemaError,
+        FastExcelError,
+        "The base class for errors related to the columns and their dtypes"
+    );
     // Unsupported column type
     create_exception!(
         _fastexcel,
         UnsupportedColumnTypeCombinationError,
-        FastExcelError,
+        SchemaError,
         "Column contains an unsupported type combination"
     );
     // Cannot retrieve cell data
     create_exception!(
         _fastexcel,
         CannotRetrieveCellDataError,
-        FastExcelError,
+        CellError,
         "Data for a given cell cannot be retrieved"
     );
     // Calamine cell error
     create_exception!(
         _fastexcel,
         CalamineCellError,
-        FastExcelError,
+        CellError,
         "calamine returned an error regarding the content of the cell"
     );
     // Calamine error
@@ -168,11 +331,11 @@ pub(crate) mod py_errors {
         FastExcelError,
         "Sheet was not found"
     );
-    // Sheet not found
+    // Column not found
     create_exception!(
         _fastexcel,
         ColumnNotFoundError,
-        FastExcelError,
+        SchemaError,
         "Column was not found"
     );
     // Arrow error
@@ -189,6 +352,48 @@ pub(crate) mod py_errors {
         FastExcelError,
         "Provided parameters are invalid"
     );
+    // Invalid dtype
+    create_exception!(
+        _fastexcel,
+        InvalidDtypeError,
+        InvalidParametersError,
+        "Provided dtype is not supported"
+    );
+    // Dtype specified for an unknown column
+    create_exception!(
+        _fastexcel,
+        UnknownColumnError,
+        ColumnNotFoundError,
+        "A dtype was specified for a column that does not exist"
+    );
+    // Corrupt or truncated file
+    create_exception!(
+        _fastexcel,
+        CorruptFileError,
+        CalamineError,
+        "The file is corrupt or incomplete"
+    );
+    // Sheet larger than the size limits
+    create_exception!(
+        _fastexcel,
+        SizeLimitExceededError,
+        FastExcelError,
+        "The sheet has more columns or rows than allowed"
+    );
+    // Sheet with more cells than allowed
+    create_exception!(
+        _fastexcel,
+        ResourceLimitError,
+        SizeLimitExceededError,
+        "The sheet has more cells than allowed"
+    );
+    // Several columns with the same name
+    create_exception!(
+        _fastexcel,
+        DuplicateColumnError,
+        SchemaError,
+        "Several columns have the same name"
+    );
     // Internal error
     create_exception!(
         _fastexcel,
@@ -232,10 +437,73 @@ pub(crate) mod py_errors {
                         FastExcelErrorKind::InvalidParameters(_) => {
                             InvalidParametersError::new_err(message)
                         }
-                        FastExcelErrorKind::Internal(_) => ArrowError::new_err(message),
+                        FastExcelErrorKind::InvalidDtype(_) => InvalidDtypeError::new_err(message),
+                        FastExcelErrorKind::UnknownColumn(_) => {
+                            UnknownColumnError::new_err(message)
+                        }
+                        FastExcelErrorKind::CorruptFile(_) => CorruptFileError::new_err(message),
+                        FastExcelErrorKind::SizeLimitExceeded(_) => {
+                            SizeLimitExceededError::new_err(message)
+                        }
+                        FastExcelErrorKind::ResourceLimit(_) => {
+                            ResourceLimitError::new_err(message)
+                        }
+                        FastExcelErrorKind::DuplicateColumn(_) => {
+                            DuplicateColumnError::new_err(message)
+                        }
+                        FastExcelErrorKind::Internal(_) => InternalError::new_err(message),
                     })
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, "A")]
+    #[case(25, "Z")]
+    #[case(26, "AA")]
+    #[case(27, "AB")]
+    #[case(701, "ZZ")]
+    #[case(702, "AAA")]
+    fn column_letters_from_index(#[case] col: usize, #[case] expected: &str) {
+        assert_eq!(column_letters(col), expected);
+    }
+
+    #[rstest]
+    #[case(CellErrorType::Div0, "calamine cell error: #DIV/0! (division by zero)")]
+    #[case(CellErrorType::NA, "calamine cell error: #N/A (value not available)")]
+    #[case(
+        CellErrorType::Ref,
+        "calamine cell error: #REF! (invalid cell reference)"
+    )]
+    fn cell_errors_are_spelled_out(#[case] cell_error: CellErrorType, #[case] expected: &str) {
+        assert_eq!(
+            FastExcelErrorKind::CalamineCellError(cell_error).to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn cell_location_is_only_updated_for_cell_errors() {
+        let err = FastExcelError::from(FastExcelErrorKind::Internal("oops".to_string()))
+            .with_cell_location(|location| location.sheet_name = Some("Sheet1".to_string()));
+        assert_eq!(err.cell_location(), None);
+
+        let err = err
+            .with_cell_position((4, 2))
+            .with_cell_location(|location| location.column_name = Some("col".to_string()))
+            .with_cell_location(|location| location.sheet_name = Some("Sheet1".to_string()));
+        assert_eq!(
+            err.to_string(),
+            "fastexcel error: oops (at cell C5 (row 4, column 2) in column \"col\" in sheet \"Sheet1\")"
+        );
+    }
+}
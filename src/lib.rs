@@ -3,10 +3,25 @@ mod error;
 mod types;
 mod utils;
 
-use error::{py_errors, ErrorContext};
+use arrow::{
+    pyarrow::{PyArrowType, ToPyArrow},
+    record_batch::RecordBatch,
+};
+use error::{py_errors, ErrorContext, FastExcelErrorKind, FastExcelResult};
 use pyo3::{prelude::*, types::PyString};
 use types::python::{
-    excelsheet::column_info::ColumnInfo, table::ExcelTable, ExcelReader, ExcelSheet,
+    excelsheet::{
+        batches::ExcelSheetBatches,
+        column::ArrowColumn,
+        column_info::{ColumnInfo, HeaderWarning},
+        column_stats::ColumnStats,
+        read_warnings::ReadWarning,
+    },
+    table::ExcelTable,
+    ExcelReader, ExcelSheet,
+};
+use utils::concat::{
+    concat_record_batches, merge_record_batches, with_constant_string_column, TypePromotion,
 };
 
 /// Reads an excel file and returns an object allowing to access its sheets and a bit of metadata
@@ -30,6 +45,63 @@ fn read_excel(source: &Bound<'_, PyAny>) -> PyResult<ExcelReader> {
     }
 }
 
+/// Concatenates several RecordBatches into a single one, using the union of their schemas.
+///
+/// If `source_column` is provided, a column with that name is added to every batch, containing
+/// the batch's matching element in `sources`. If `promote_types` is true, columns with
+/// conflicting types are promoted to a common type rather than resulting in an error.
+#[pyfunction]
+#[pyo3(signature = (batches, *, sources = None, source_column = None, promote_types = false))]
+fn _concat_record_batches(
+    batches: Vec<PyArrowType<RecordBatch>>,
+    sources: Option<Vec<String>>,
+    source_column: Option<&str>,
+    promote_types: bool,
+    py: Python<'_>,
+) -> PyResult<PyObject> {
+    use py_errors::IntoPyResult;
+
+    let batches: Vec<RecordBatch> = batches.into_iter().map(|rb| rb.0).collect();
+    let batches = match (source_column, sources) {
+        (None, _) => Ok(batches),
+        (Some(column_name), Some(sources)) if sources.len() == batches.len() => batches
+            .iter()
+            .zip(sources.iter())
+            .map(|(rb, source)| with_constant_string_column(rb, column_name, source))
+            .collect::<FastExcelResult<Vec<_>>>(),
+        (Some(_), _) => Err(FastExcelErrorKind::InvalidParameters(
+            "`sources` must contain exactly one element per batch when `source_column` is set"
+                .to_string(),
+        )
+        .into()),
+    }
+    .into_pyresult()?;
+
+    if batches.is_empty() {
+        return Err(py_errors::InvalidParametersError::new_err(
+            "at least one RecordBatch is required",
+        ));
+    }
+
+    concat_record_batches(&batches, promote_types)
+        .into_pyresult()
+        .and_then(|rb| rb.to_pyarrow(py))
+}
+
+/// Concatenates two RecordBatches, promoting the types of their conflicting columns. Returns the
+/// concatenated batch along with the promotions that were applied
+#[pyfunction]
+fn _merge_record_batches(
+    left: PyArrowType<RecordBatch>,
+    right: PyArrowType<RecordBatch>,
+    py: Python<'_>,
+) -> PyResult<(PyObject, Vec<TypePromotion>)> {
+    use py_errors::IntoPyResult;
+
+    let (rb, promotions) = merge_record_batches(&[left.0, right.0]).into_pyresult()?;
+    Ok((rb.to_pyarrow(py)?, promotions))
+}
+
 // Taken from pydantic-core:
 // https://github.com/pydantic/pydantic-core/blob/main/src/lib.rs#L24
 fn get_version() -> String {
@@ -46,10 +118,18 @@ fn get_version() -> String {
 fn _fastexcel(m: &Bound<'_, PyModule>) -> PyResult<()> {
     let py = m.py();
     m.add_function(wrap_pyfunction!(read_excel, m)?)?;
+    m.add_function(wrap_pyfunction!(_concat_record_batches, m)?)?;
+    m.add_function(wrap_pyfunction!(_merge_record_batches, m)?)?;
     m.add_class::<ColumnInfo>()?;
+    m.add_class::<HeaderWarning>()?;
+    m.add_class::<ReadWarning>()?;
+    m.add_class::<ColumnStats>()?;
+    m.add_class::<TypePromotion>()?;
     m.add_class::<ExcelSheet>()?;
+    m.add_class::<ExcelSheetBatches>()?;
     m.add_class::<ExcelReader>()?;
     m.add_class::<ExcelTable>()?;
+    m.add_class::<ArrowColumn>()?;
     m.add("__version__", get_version())?;
 
     // errors
@@ -58,6 +138,8 @@ fn _fastexcel(m: &Bound<'_, PyModule>) -> PyResult<()> {
             "FastExcelError",
             py.get_type_bound::<py_errors::FastExcelError>(),
         ),
+        ("CellError", py.get_type_bound::<py_errors::CellError>()),
+        ("SchemaError", py.get_type_bound::<py_errors::SchemaError>()),
         (
             "UnsupportedColumnTypeCombinationError",
             py.get_type_bound::<py_errors::UnsupportedColumnTypeCombinationError>(),
@@ -87,6 +169,34 @@ fn _fastexcel(m: &Bound<'_, PyModule>) -> PyResult<()> {
             "InvalidParametersError",
             py.get_type_bound::<py_errors::InvalidParametersError>(),
         ),
+        (
+            "InvalidDtypeError",
+            py.get_type_bound::<py_errors::InvalidDtypeError>(),
+        ),
+        (
+            "UnknownColumnError",
+            py.get_type_bound::<py_errors::UnknownColumnError>(),
+        ),
+        (
+            "CorruptFileError",
+            py.get_type_bound::<py_errors::CorruptFileError>(),
+        ),
+        (
+            "SizeLimitExceededError",
+            py.get_type_bound::<py_errors::SizeLimitExceededError>(),
+        ),
+        (
+            "ResourceLimitError",
+            py.get_type_bound::<py_errors::ResourceLimitError>(),
+        ),
+        (
+            "DuplicateColumnError",
+            py.get_type_bound::<py_errors::DuplicateColumnError>(),
+        ),
+        (
+            "InternalError",
+            py.get_type_bound::<py_errors::InternalError>(),
+        ),
     ]
     .into_iter()
     .try_for_each(|(exc_name, exc_type)| m.add(exc_name, exc_type))
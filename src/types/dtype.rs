@@ -7,14 +7,20 @@ use std::{
 
 use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
 use calamine::{CellErrorType, CellType, DataType, Range};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use pyo3::{
     prelude::PyAnyMethods, types::PyString, Bound, FromPyObject, PyAny, PyObject, PyResult, Python,
     ToPyObject,
 };
 
-use crate::error::{py_errors::IntoPyResult, FastExcelError, FastExcelErrorKind, FastExcelResult};
+use crate::{
+    error::{
+        py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
+    },
+    utils::schema::SampleRows,
+};
 
-use super::idx_or_name::IdxOrName;
+use super::{idx_or_name::IdxOrName, read_options::ReadOptions};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub(crate) enum DType {
@@ -23,9 +29,14 @@ pub(crate) enum DType {
     Float,
     String,
     Bool,
+    /// Boolean stored as 0/1 numbers
+    BoolFromInt,
     DateTime,
+    /// Timestamps with a UTC offset, converted to UTC
+    DateTimeTz,
     Date,
     Duration,
+    ExcelSerial,
 }
 
 impl FromStr for DType {
@@ -38,10 +49,14 @@ impl FromStr for DType {
             "float" => Ok(Self::Float),
             "string" => Ok(Self::String),
             "boolean" => Ok(Self::Bool),
+            // "bool" is accepted as a shorter name of the 0/1 booleans dtype
+            "bool_from_int" | "bool" => Ok(Self::BoolFromInt),
             "datetime" => Ok(Self::DateTime),
+            "datetime_tz" => Ok(Self::DateTimeTz),
             "date" => Ok(Self::Date),
             "duration" => Ok(Self::Duration),
-            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+            "excel_serial" => Ok(Self::ExcelSerial),
+            _ => Err(FastExcelErrorKind::InvalidDtype(format!(
                 "unsupported dtype: \"{raw_dtype}\""
             ))
             .into()),
@@ -57,9 +72,12 @@ impl Display for DType {
             DType::Float => "float",
             DType::String => "string",
             DType::Bool => "boolean",
+            DType::BoolFromInt => "bool_from_int",
             DType::DateTime => "datetime",
+            DType::DateTimeTz => "datetime_tz",
             DType::Date => "date",
             DType::Duration => "duration",
+            DType::ExcelSerial => "excel_serial",
         })
     }
 }
@@ -86,18 +104,55 @@ impl FromPyObject<'_> for DType {
 
 pub(crate) type DTypeMap = HashMap<IdxOrName, DType>;
 
-impl From<&DType> for ArrowDataType {
-    fn from(dtype: &DType) -> Self {
-        match dtype {
+impl DType {
+    /// Returns the arrow type of the arrays built for this dtype
+    pub(crate) fn arrow_type(&self, read_options: &ReadOptions) -> ArrowDataType {
+        match self {
             DType::Null => ArrowDataType::Null,
             DType::Int => ArrowDataType::Int64,
             DType::Float => ArrowDataType::Float64,
             DType::String => ArrowDataType::Utf8,
-            DType::Bool => ArrowDataType::Boolean,
-            DType::DateTime => ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
+            DType::Bool | DType::BoolFromInt if read_options.bool_as_int8 => ArrowDataType::Int8,
+            DType::Bool | DType::BoolFromInt => ArrowDataType::Boolean,
+            DType::DateTime | DType::DateTimeTz if read_options.has_custom_epoch() => {
+                ArrowDataType::Int64
+            }
+            DType::DateTime => ArrowDataType::Timestamp(read_options.timestamp_unit.into(), None),
+            DType::DateTimeTz => ArrowDataType::Timestamp(
+                read_options.timestamp_unit.into(),
+                Some(read_options.timezone_name().into()),
+            ),
+            DType::Date if read_options.has_custom_epoch() => ArrowDataType::Int32,
             DType::Date => ArrowDataType::Date32,
             DType::Duration => ArrowDataType::Duration(TimeUnit::Millisecond),
+            DType::ExcelSerial => ArrowDataType::Float64,
+        }
+    }
+}
+
+/// Returns the name pyarrow gives to an arrow type, e.g. `double` or `timestamp[ms, tz=UTC]`
+pub(crate) fn arrow_type_name(data_type: &ArrowDataType) -> String {
+    fn unit_name(unit: &TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "s",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Microsecond => "us",
+            TimeUnit::Nanosecond => "ns",
+        }
+    }
+    match data_type {
+        ArrowDataType::Null => "null".to_owned(),
+        ArrowDataType::Boolean => "bool".to_owned(),
+        ArrowDataType::Float64 => "double".to_owned(),
+        ArrowDataType::Utf8 => "string".to_owned(),
+        ArrowDataType::Date32 => "date32[day]".to_owned(),
+        ArrowDataType::Timestamp(unit, None) => format!("timestamp[{}]", unit_name(unit)),
+        ArrowDataType::Timestamp(unit, Some(tz)) => {
+            format!("timestamp[{}, tz={tz}]", unit_name(unit))
         }
+        ArrowDataType::Duration(unit) => format!("duration[{}]", unit_name(unit)),
+        // Integer types are named the same way by arrow and pyarrow, e.g. int64
+        other => other.to_string().to_lowercase(),
     }
 }
 
@@ -136,28 +191,55 @@ impl FromPyObject<'_> for DTypeCoercion {
     }
 }
 
+/// The timezone of "datetime_tz" columns
+pub(crate) const UTC_TIMEZONE: &str = "UTC";
+
+/// Parses an ISO 8601 timestamp with a UTC offset (e.g. "2023-05-01T12:00:00+02:00"), and
+/// converts it to UTC. Returns `None` for any other string, including timestamps without an
+/// offset
+pub(crate) fn parse_offset_datetime(value: &str) -> Option<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|datetime| datetime.naive_utc())
+}
+
+/// Parses the ISO 8601 date or datetime of a `DateTimeIso` cell, e.g. "2023-05-01" or
+/// "2023-05-01T12:00:00". Dates are read at midnight. A trailing "Z" is ignored, since datetime
+/// columns are naive
+pub(crate) fn parse_iso_datetime(value: &str) -> Option<NaiveDateTime> {
+    let value = value.strip_suffix('Z').unwrap_or(value);
+    NaiveDateTime::from_str(value).ok().or_else(|| {
+        NaiveDate::from_str(value)
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN))
+    })
+}
+
 /// All the possible string values that should be considered as NULL
 const NULL_STRING_VALUES: [&str; 19] = [
     "", "#N/A", "#N/A N/A", "#NA", "-1.#IND", "-1.#QNAN", "-NaN", "-nan", "1.#IND", "1.#QNAN",
     "<NA>", "N/A", "NA", "NULL", "NaN", "None", "n/a", "nan", "null",
 ];
 
-fn get_cell_dtype<DT: CellType + Debug + DataType>(
+pub(crate) fn get_cell_dtype<DT: CellType + Debug + DataType>(
     data: &Range<DT>,
     row: usize,
     col: usize,
 ) -> FastExcelResult<DType> {
     let cell = data
         .get((row, col))
-        .ok_or_else(|| FastExcelErrorKind::CannotRetrieveCellData(row, col))?;
+        .ok_or(FastExcelErrorKind::CannotRetrieveCellData(row, col))?;
 
     if cell.is_int() {
         Ok(DType::Int)
     } else if cell.is_float() {
         Ok(DType::Float)
     } else if cell.is_string() {
-        if NULL_STRING_VALUES.contains(&cell.get_string().unwrap()) {
+        let value = cell.get_string().unwrap();
+        if NULL_STRING_VALUES.contains(&value) {
             Ok(DType::Null)
+        } else if parse_offset_datetime(value).is_some() {
+            Ok(DType::DateTimeTz)
         } else {
             Ok(DType::String)
         }
@@ -176,13 +258,12 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
         })
     }
     // These types contain an ISO8601 representation of a date/datetime or a durat
-    else if cell.is_datetime_iso() {
-        match cell.as_datetime() {
-            // If we cannot convert the cell to a datetime, we're working on a date
-            Some(_) => Ok(DType::DateTime),
+    else if let Some(value) = cell.get_datetime_iso() {
+        match NaiveDate::from_str(value) {
             // NOTE: not using the Date64 type on purpose, as pyarrow converts it to a datetime
             // rather than a date
-            None => Ok(DType::Date),
+            Ok(_) => Ok(DType::Date),
+            Err(_) => Ok(DType::DateTime),
         }
     }
     // Simple durations
@@ -194,10 +275,7 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
         Ok(DType::Null)
     } else if cell.is_error() {
         match cell.get_error() {
-            // considering cells with #N/A! or #REF! as null
-            Some(
-                CellErrorType::NA | CellErrorType::Value | CellErrorType::Null | CellErrorType::Ref,
-            ) => Ok(DType::Null),
+            Some(err) if is_null_error(err) => Ok(DType::Null),
             Some(err) => Err(FastExcelErrorKind::CalamineCellError(err.to_owned()).into()),
             None => Err(FastExcelErrorKind::Internal(format!(
                 "cell is an error but get_error returned None: {cell:?}"
@@ -209,9 +287,20 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
     }
 }
 
+/// Whether an error cell is considered as null, such as #N/A! or #REF!. Other error cells, such
+/// as #DIV/0!, are only read as null when type coercion is not strict
+pub(crate) fn is_null_error(err: &CellErrorType) -> bool {
+    matches!(
+        err,
+        CellErrorType::NA | CellErrorType::Value | CellErrorType::Null | CellErrorType::Ref
+    )
+}
+
 static FLOAT_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
 static INT_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
 static STRING_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
+static DATETIME_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
+static DATETIME_TZ_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
 
 fn float_types() -> &'static HashSet<DType> {
     FLOAT_TYPES_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Float, DType::Bool]))
@@ -221,6 +310,15 @@ fn int_types() -> &'static HashSet<DType> {
     INT_TYPES_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Bool]))
 }
 
+fn datetime_types() -> &'static HashSet<DType> {
+    DATETIME_TYPES_CELL.get_or_init(|| HashSet::from([DType::Date, DType::DateTime]))
+}
+
+fn datetime_tz_types() -> &'static HashSet<DType> {
+    DATETIME_TZ_TYPES_CELL
+        .get_or_init(|| HashSet::from([DType::Date, DType::DateTime, DType::DateTimeTz]))
+}
+
 fn string_types() -> &'static HashSet<DType> {
     STRING_TYPES_CELL.get_or_init(|| {
         HashSet::from([
@@ -229,6 +327,7 @@ fn string_types() -> &'static HashSet<DType> {
             DType::Float,
             DType::String,
             DType::DateTime,
+            DType::DateTimeTz,
             DType::Date,
         ])
     })
@@ -236,13 +335,24 @@ fn string_types() -> &'static HashSet<DType> {
 
 pub(crate) fn get_dtype_for_column<DT: CellType + Debug + DataType>(
     data: &Range<DT>,
-    start_row: usize,
-    end_row: usize,
+    sample_rows: SampleRows,
     col: usize,
     dtype_coercion: &DTypeCoercion,
 ) -> FastExcelResult<DType> {
-    let mut column_types = (start_row..end_row)
-        .map(|row| get_cell_dtype(data, row, col))
+    let (range_start_row, range_start_col) = data.start().unwrap_or((0, 0));
+    let mut column_types = sample_rows
+        .rows()
+        .map(|row| match get_cell_dtype(data, row, col) {
+            // Error cells such as #DIV/0! are read as null, unless dtype coercion is strict
+            Err(FastExcelError {
+                kind: FastExcelErrorKind::CalamineCellError(_),
+                ..
+            }) if matches!(dtype_coercion, &DTypeCoercion::Coerce) => Ok(DType::Null),
+            cell_dtype => cell_dtype.with_cell_position((
+                range_start_row as usize + row,
+                range_start_col as usize + col,
+            )),
+        })
         .collect::<FastExcelResult<HashSet<_>>>()?;
 
     // All columns are nullable anyway so we're not taking Null into account here
@@ -262,12 +372,28 @@ pub(crate) fn get_dtype_for_column<DT: CellType + Debug + DataType>(
     } else if column_types.len() == 1 {
         // If a single non-null type was found, return it
         Ok(column_types.into_iter().next().unwrap())
+    } else if column_types.contains(&DType::Bool)
+        && column_types.is_subset(float_types())
+        && numbers_are_flags(data, sample_rows, col)
+    {
+        // If the column mixes booleans with numbers that are all 0 or 1, it's a boolean column
+        Ok(DType::BoolFromInt)
     } else if column_types.is_subset(int_types()) {
         // If every cell in the column can be converted to an int, return int64
         Ok(DType::Int)
     } else if column_types.is_subset(float_types()) {
         // If every cell in the column can be converted to a float, return Float64
         Ok(DType::Float)
+    } else if column_types.contains(&DType::DateTimeTz)
+        && column_types.is_subset(datetime_tz_types())
+    {
+        // If the column mixes timestamps with a UTC offset and naive dates or datetimes, the
+        // whole column is timezone-aware, and the naive ones are read in `assume_timezone`
+        Ok(DType::DateTimeTz)
+    } else if column_types.is_subset(datetime_types()) {
+        // If the column mixes dates and datetimes, e.g. ISO dates and serial datetimes, dates
+        // are read at midnight
+        Ok(DType::DateTime)
     } else if column_types.is_subset(string_types()) {
         // If every cell in the column can be converted to a string, return Utf8
         Ok(DType::String)
@@ -280,9 +406,22 @@ pub(crate) fn get_dtype_for_column<DT: CellType + Debug + DataType>(
     }
 }
 
+/// Whether every numeric cell of the sampled rows is 0 or 1
+fn numbers_are_flags<DT: CellType + DataType>(
+    data: &Range<DT>,
+    sample_rows: SampleRows,
+    col: usize,
+) -> bool {
+    sample_rows
+        .rows()
+        .filter_map(|row| data.get((row, col)))
+        .filter(|cell| cell.is_int() || cell.is_float())
+        .all(|cell| matches!(cell.as_f64(), Some(value) if value == 0.0 || value == 1.0))
+}
+
 #[cfg(test)]
 mod tests {
-    use calamine::{Cell, Data as CalData};
+    use calamine::{Cell, Data as CalData, ExcelDateTime, ExcelDateTimeType};
     use rstest::{fixture, rstest};
 
     use super::*;
@@ -303,6 +442,9 @@ mod tests {
             Cell::new((9, 0), CalData::Float(12.21)),
             Cell::new((10, 0), CalData::Bool(true)),
             Cell::new((11, 0), CalData::Int(1337)),
+            Cell::new((12, 0), CalData::Float(0.0)),
+            Cell::new((13, 0), CalData::Int(1)),
+            Cell::new((14, 0), CalData::Bool(false)),
         ])
     }
 
@@ -333,6 +475,10 @@ mod tests {
     #[case(7, 11, DType::Float)]
     // int + bool
     #[case(10, 12, DType::Int)]
+    // bool + 0/1 ints and floats
+    #[case(12, 15, DType::BoolFromInt)]
+    // 0/1 ints and floats without bool
+    #[case(12, 14, DType::Float)]
     fn get_arrow_column_type_multi_dtype_ok_coerce(
         range: Range<CalData>,
         #[case] start_row: usize,
@@ -340,7 +486,13 @@ mod tests {
         #[case] expected: DType,
     ) {
         assert_eq!(
-            get_dtype_for_column(&range, start_row, end_row, 0, &DTypeCoercion::Coerce).unwrap(),
+            get_dtype_for_column(
+                &range,
+                SampleRows::new(start_row, end_row),
+                0,
+                &DTypeCoercion::Coerce
+            )
+            .unwrap(),
             expected
         );
     }
@@ -363,7 +515,13 @@ mod tests {
         #[case] expected: DType,
     ) {
         assert_eq!(
-            get_dtype_for_column(&range, start_row, end_row, 0, &DTypeCoercion::Strict).unwrap(),
+            get_dtype_for_column(
+                &range,
+                SampleRows::new(start_row, end_row),
+                0,
+                &DTypeCoercion::Strict
+            )
+            .unwrap(),
             expected
         );
     }
@@ -388,10 +546,177 @@ mod tests {
         #[case] start_row: usize,
         #[case] end_row: usize,
     ) {
-        let result = get_dtype_for_column(&range, start_row, end_row, 0, &DTypeCoercion::Strict);
+        let result = get_dtype_for_column(
+            &range,
+            SampleRows::new(start_row, end_row),
+            0,
+            &DTypeCoercion::Strict,
+        );
         assert!(matches!(
             result.unwrap_err().kind,
             FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
         ));
     }
+
+    #[rstest]
+    // serial datetime + ISO datetime
+    #[case(CalData::DateTimeIso("2023-01-02T08:30:00".to_owned()), DType::DateTime)]
+    // serial datetime + ISO date
+    #[case(CalData::DateTimeIso("2023-01-03".to_owned()), DType::DateTime)]
+    fn get_arrow_column_type_mixed_datetime_representations(
+        #[case] iso_cell: CalData,
+        #[case] expected: DType,
+    ) {
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::DateTime(ExcelDateTime::new(
+                    44927.5,
+                    ExcelDateTimeType::DateTime,
+                    false,
+                )),
+            ),
+            Cell::new((1, 0), iso_cell),
+        ]);
+
+        assert_eq!(
+            get_dtype_for_column(&range, SampleRows::new(0, 2), 0, &DTypeCoercion::Coerce).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("2023-01-02T08:30:00", Some((2, 8, 30)))]
+    #[case("2023-01-02T08:30:00Z", Some((2, 8, 30)))]
+    #[case("2023-01-03", Some((3, 0, 0)))]
+    #[case("08:30:00", None)]
+    fn iso_datetimes(#[case] value: &str, #[case] expected: Option<(u32, u32, u32)>) {
+        let expected = expected.map(|(day, hour, min)| {
+            NaiveDate::from_ymd_opt(2023, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, min, 0)
+                .unwrap()
+        });
+
+        assert_eq!(parse_iso_datetime(value), expected);
+    }
+
+    #[test]
+    fn get_arrow_column_type_cell_error_has_cell_location() {
+        // The range starts at B2
+        let range = Range::from_sparse(vec![
+            Cell::new((1, 1), CalData::Float(1.0)),
+            Cell::new((2, 1), CalData::Error(CellErrorType::Div0)),
+        ]);
+
+        // Error cells are only read as null when dtypes are coerced
+        assert_eq!(
+            get_dtype_for_column(&range, SampleRows::new(0, 2), 0, &DTypeCoercion::Coerce).unwrap(),
+            DType::Float
+        );
+        let err = get_dtype_for_column(&range, SampleRows::new(0, 2), 0, &DTypeCoercion::Strict)
+            .unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::CalamineCellError(_)));
+        assert_eq!(err.cell_location().unwrap().position, Some((2, 1)));
+        assert!(err.to_string().starts_with(
+            "calamine cell error: #DIV/0! (division by zero) (at cell B3 (row 2, column 1))"
+        ));
+    }
+
+    #[rstest]
+    #[case("2023-05-01T12:00:00+02:00", DType::DateTimeTz)]
+    #[case("2023-05-01T12:00:00.5Z", DType::DateTimeTz)]
+    // Timestamps without an offset are not converted
+    #[case("2023-05-01T12:00:00", DType::String)]
+    #[case("2023-05-01T12:00:00+25:00", DType::String)]
+    fn get_arrow_column_type_offset_datetime_strings(#[case] value: &str, #[case] expected: DType) {
+        let range = Range::from_sparse(vec![Cell::new((0, 0), CalData::String(value.to_owned()))]);
+
+        assert_eq!(
+            get_dtype_for_column(&range, SampleRows::new(0, 1), 0, &DTypeCoercion::Strict).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn get_arrow_column_type_naive_and_offset_datetimes() {
+        let range = Range::from_sparse(vec![
+            Cell::new(
+                (0, 0),
+                CalData::DateTime(ExcelDateTime::new(
+                    45128.5,
+                    ExcelDateTimeType::DateTime,
+                    false,
+                )),
+            ),
+            Cell::new(
+                (1, 0),
+                CalData::String("2023-05-01T12:00:00+02:00".to_owned()),
+            ),
+        ]);
+
+        assert_eq!(
+            get_dtype_for_column(&range, SampleRows::new(0, 2), 0, &DTypeCoercion::Coerce).unwrap(),
+            DType::DateTimeTz
+        );
+    }
+
+    #[rstest]
+    // calamine converts numeric cells to datetimes or durations based on their number format
+    #[case(
+        CalData::DateTime(ExcelDateTime::new(45128.5, ExcelDateTimeType::DateTime, false)),
+        DType::DateTime
+    )]
+    #[case(
+        CalData::DateTime(ExcelDateTime::new(0.5, ExcelDateTimeType::TimeDelta, false)),
+        DType::Duration
+    )]
+    // Cells with a number format other than a date are plain numbers
+    #[case(CalData::Float(45128.5), DType::Float)]
+    fn get_arrow_column_type_uses_the_number_format(
+        #[case] cell: CalData,
+        #[case] expected: DType,
+    ) {
+        let range = Range::from_sparse(vec![Cell::new((0, 0), cell)]);
+
+        assert_eq!(
+            get_dtype_for_column(&range, SampleRows::new(0, 1), 0, &DTypeCoercion::Strict).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(DType::Int, "int64")]
+    #[case(DType::Float, "double")]
+    #[case(DType::String, "string")]
+    #[case(DType::Bool, "bool")]
+    #[case(DType::Date, "date32[day]")]
+    #[case(DType::DateTime, "timestamp[ms]")]
+    #[case(DType::DateTimeTz, "timestamp[ms, tz=UTC]")]
+    #[case(DType::Duration, "duration[ms]")]
+    #[case(DType::Null, "null")]
+    fn arrow_type_names_match_pyarrow(#[case] dtype: DType, #[case] expected: &str) {
+        assert_eq!(
+            arrow_type_name(&dtype.arrow_type(&ReadOptions::default())),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("int32")]
+    #[case("bool_from_float")]
+    fn unsupported_dtype_is_an_invalid_dtype_error(#[case] raw_dtype: &str) {
+        let err = raw_dtype.parse::<DType>().unwrap_err();
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidDtype(_)));
+    }
+
+    #[rstest]
+    #[case("bool_from_int")]
+    #[case("bool")]
+    fn bool_from_int_dtype_round_trips(#[case] raw_dtype: &str) {
+        let dtype = raw_dtype.parse::<DType>().unwrap();
+        assert_eq!(dtype, DType::BoolFromInt);
+        assert_eq!(dtype.to_string(), "bool_from_int");
+    }
 }
@@ -1,3 +1,4 @@
 pub(crate) mod dtype;
 pub(crate) mod idx_or_name;
 pub(crate) mod python;
+pub(crate) mod read_options;
@@ -1,15 +1,21 @@
 use std::{
+    collections::{HashMap, HashSet},
     fs::File,
-    io::{BufReader, Cursor},
+    io::{BufReader, Cursor, Read, Seek},
+    sync::Arc,
 };
 
 use arrow::{pyarrow::ToPyArrow, record_batch::RecordBatch};
-use pyo3::{prelude::PyObject, pyclass, pymethods, Bound, IntoPy, PyAny, PyResult, Python};
+use pyo3::{
+    exceptions::PyUserWarning, prelude::PyObject, pyclass, pymethods, Bound, IntoPy, PyAny, PyErr,
+    PyResult, Python,
+};
 
 use calamine::{
-    open_workbook_auto, open_workbook_auto_from_rs, Data, DataRef, Range, Reader,
-    Sheet as CalamineSheet, Sheets, Table,
+    open_workbook_auto, open_workbook_auto_from_rs, CellType, Data, DataRef, DataType, Dimensions,
+    Range, Reader, Sheet as CalamineSheet, SheetVisible, Sheets, Table, Xlsx,
 };
+use chrono::NaiveDate;
 
 use crate::{
     data::{record_batch_from_data_and_columns, ExcelSheetData},
@@ -17,24 +23,44 @@ use crate::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
     types::{
-        dtype::{DTypeCoercion, DTypeMap},
+        dtype::{DType, DTypeCoercion, DTypeMap},
         idx_or_name::IdxOrName,
-        python::excelsheet::table::{extract_table_names, extract_table_range},
+        python::excelsheet::table::{
+            extract_table_infos, extract_table_names, extract_table_range, table_column_names,
+            TableInfo,
+        },
+        read_options::{
+            DuplicateColumns, FillValue, ReadOptions, SampleStrategy, TimestampUnit, Timezone,
+            UnknownRenames, DEFAULT_COLUMN_PREFIX,
+        },
+    },
+    utils::{
+        autofilter::autofilter_hidden_rows,
+        schema::{get_schema_sample_rows, SchemaSampleRows},
+        workbook_xml::{
+            shared_strings, table_header_row_count, uses_1904_date_system, WorkbookArchive,
+        },
+        zip::check_zip_archive,
     },
-    utils::schema::get_schema_sample_rows,
 };
 
 use pyo3::types::PyString;
 
 use super::excelsheet::{
-    column_info::{build_available_columns, build_available_columns_info},
-    ExcelSheet, Header, Pagination, SelectedColumns,
+    cell_range::CellRange,
+    column_info::{alias_available_columns, build_available_columns, build_available_columns_info},
+    defined_names::{extract_defined_name_range, extract_defined_names},
+    detect_header_row, find_header_row,
+    formulas::CellsMode,
+    merged_cells::fill_merged_regions,
+    select_data_rows, transpose_range, visible_data_rows, ExcelSheet, Header, Pagination,
+    SelectedColumns, SizeLimitOverflow, SizeLimits,
 };
 use super::table::ExcelTable;
 
 enum ExcelSheets {
     File(Sheets<BufReader<File>>),
-    Bytes(Sheets<Cursor<Vec<u8>>>),
+    Bytes(Sheets<Cursor<Arc<[u8]>>>),
 }
 
 impl ExcelSheets {
@@ -47,6 +73,15 @@ impl ExcelSheets {
         .with_context(|| format!("Error while loading sheet {name}"))
     }
 
+    fn worksheet_formula(&mut self, name: &str) -> FastExcelResult<Range<String>> {
+        match self {
+            Self::File(sheets) => sheets.worksheet_formula(name),
+            Self::Bytes(sheets) => sheets.worksheet_formula(name),
+        }
+        .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+        .with_context(|| format!("Error while loading the formulas of sheet {name}"))
+    }
+
     #[allow(dead_code)]
     fn sheet_metadata(&self) -> &[CalamineSheet] {
         match self {
@@ -66,14 +101,62 @@ impl ExcelSheets {
         }
     }
 
-    fn supports_by_ref(&self) -> bool {
+    fn table_infos(&mut self, sheet_name: Option<&str>) -> FastExcelResult<Vec<TableInfo>> {
+        match self {
+            Self::File(sheets) => extract_table_infos(sheets, sheet_name)?,
+            Self::Bytes(sheets) => extract_table_infos(sheets, sheet_name)?,
+        }
+    }
+
+    fn defined_names(&self) -> Vec<(String, String)> {
+        match self {
+            Self::File(sheets) => extract_defined_names(sheets),
+            Self::Bytes(sheets) => extract_defined_names(sheets),
+        }
+    }
+
+    fn defined_name_range(&self, name: &str) -> FastExcelResult<(String, CellRange)> {
+        match self {
+            Self::File(sheets) => extract_defined_name_range(name, sheets),
+            Self::Bytes(sheets) => extract_defined_name_range(name, sheets),
+        }
+    }
+
+    /// The merged regions of a sheet. `None` if the format does not expose them
+    fn merged_regions(&mut self, sheet_name: &str) -> FastExcelResult<Option<Vec<Dimensions>>> {
+        fn xlsx_merged_regions<RS: Read + Seek>(
+            xlsx: &mut Xlsx<RS>,
+            sheet_name: &str,
+        ) -> FastExcelResult<Vec<Dimensions>> {
+            // Internally checks if merged regions already loaded
+            xlsx.load_merged_regions()?;
+            Ok(xlsx
+                .merged_regions_by_sheet(sheet_name)
+                .into_iter()
+                .map(|(_, _, dimensions)| *dimensions)
+                .collect())
+        }
+
+        match self {
+            Self::File(Sheets::Xlsx(xlsx)) => xlsx_merged_regions(xlsx, sheet_name).map(Some),
+            Self::Bytes(Sheets::Xlsx(xlsx)) => xlsx_merged_regions(xlsx, sheet_name).map(Some),
+            _ => Ok(None),
+        }
+        .with_context(|| format!("could not read the merged cells of sheet \"{sheet_name}\""))
+    }
+
+    fn is_xlsx(&self) -> bool {
         matches!(
             self,
             Self::File(Sheets::Xlsx(_)) | Self::Bytes(Sheets::Xlsx(_))
         )
     }
 
-    fn worksheet_range_ref(&mut self, name: &str) -> FastExcelResult<Range<DataRef>> {
+    fn supports_by_ref(&self) -> bool {
+        self.is_xlsx()
+    }
+
+    fn worksheet_range_ref(&mut self, name: &str) -> FastExcelResult<Range<DataRef<'_>>> {
         match self {
             ExcelSheets::File(Sheets::Xlsx(sheets)) => Ok(sheets.worksheet_range_ref(name)?),
             ExcelSheets::Bytes(Sheets::Xlsx(sheets)) => Ok(sheets.worksheet_range_ref(name)?),
@@ -93,11 +176,79 @@ impl ExcelSheets {
     }
 }
 
+/// A range ready to be read, with its header, its window of data rows, and the data rows selected
+/// from the sheet if any
+type PreparedRange<CT> = (Range<CT>, Header, Pagination, Option<Vec<usize>>);
+
+/// The options of `load_sheet` locating the header and the data rows of a sheet and selecting
+/// the cells that are read. Built from the arguments of `load_sheet`, and shared by the eager and
+/// the lazy paths of `build_sheet`
+struct RangeOptions {
+    header_row: Option<usize>,
+    column_names: Option<Vec<String>>,
+    auto_header: bool,
+    validate_header: bool,
+    expected_headers: Option<Vec<String>>,
+    header_search_limit: usize,
+    header_depth: usize,
+    header_separator: String,
+    keep_header_in_data: bool,
+    data_start_row: Option<usize>,
+    data_rows: Option<Vec<usize>>,
+    skip_rows: usize,
+    n_rows: Option<usize>,
+    stop_at_empty_row: bool,
+    respect_autofilter: bool,
+    collect_warnings: bool,
+    transpose: bool,
+    cell_range: Option<CellRange>,
+    fill_merged_cells: bool,
+    size_limits: SizeLimits,
+    cells: CellsMode,
+}
+
+impl RangeOptions {
+    /// Checks that the options can be used together
+    fn validate(&self) -> FastExcelResult<()> {
+        let invalid_parameters =
+            |message: &str| Err(FastExcelErrorKind::InvalidParameters(message.to_owned()).into());
+        if self.auto_header && self.column_names.is_some() {
+            return invalid_parameters("`auto_header` cannot be used with `column_names`");
+        }
+        if self.expected_headers.is_some() && (self.auto_header || self.column_names.is_some()) {
+            return invalid_parameters(
+                "`expected_headers` cannot be used with `auto_header` or `column_names`",
+            );
+        }
+        if self.transpose && self.respect_autofilter {
+            return invalid_parameters("`transpose` cannot be used with `respect_autofilter`");
+        }
+        if self.data_start_row.is_some() && (self.keep_header_in_data || self.data_rows.is_some()) {
+            return invalid_parameters(
+                "`data_start_row` cannot be used with `keep_header_in_data` or `data_rows`",
+            );
+        }
+        if self.cells == CellsMode::ValueAndFormula
+            && (self.transpose || self.respect_autofilter || self.data_rows.is_some())
+        {
+            return invalid_parameters(
+                "`cells=\"value_and_formula\"` cannot be used with `transpose`, `respect_autofilter` or `data_rows`",
+            );
+        }
+        Ok(())
+    }
+}
+
 #[pyclass(name = "_ExcelReader")]
 pub(crate) struct ExcelReader {
     sheets: ExcelSheets,
     sheet_metadata: Vec<CalamineSheet>,
     source: String,
+    /// The workbook's content when it was read from bytes, shared with calamine. Used to read
+    /// what calamine does not expose, such as the rows hidden by an autofilter
+    bytes: Option<Arc<[u8]>>,
+    /// Whether the workbook uses the 1904 date system, read when the workbook is opened
+    date1904: bool,
 }
 
 impl ExcelReader {
@@ -107,32 +258,133 @@ impl ExcelReader {
         use_columns.try_into().with_context(|| format!("expected selected columns to be list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None, got {use_columns:?}"))
     }
 
+    /// Looks up the metadata of a sheet by index or name
+    fn find_sheet_meta(&self, idx_or_name: IdxOrName) -> FastExcelResult<&CalamineSheet> {
+        match idx_or_name {
+            IdxOrName::Name(name) => {
+                if let Some(sheet) = self.sheet_metadata.iter().find(|s| s.name == name) {
+                    Ok(sheet)
+                } else {
+                    Err(FastExcelErrorKind::SheetNotFound(IdxOrName::Name(name.clone())).into())
+                        .with_context(|| {
+                            let available_sheets = self
+                                .sheet_metadata
+                                .iter()
+                                .map(|s| format!("\"{}\"", s.name))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            format!(
+                                "Sheet \"{name}\" not found in file. Available sheets: {available_sheets}."
+                            )
+                        })
+                }
+            }
+            IdxOrName::Idx(idx) => self
+                .sheet_metadata
+                .get(idx)
+                .ok_or_else(|| FastExcelErrorKind::SheetNotFound(IdxOrName::Idx(idx)).into())
+                .with_context(|| {
+                    format!(
+                        "Sheet index {idx} is out of range. File has {} sheets.",
+                        self.sheet_metadata.len()
+                    )
+                }),
+        }
+    }
+
+    /// Opens the archive of the workbook, to read what calamine does not expose
+    fn workbook_archive(&self) -> FastExcelResult<WorkbookArchive> {
+        match &self.bytes {
+            Some(bytes) => Ok(WorkbookArchive::Bytes(Cursor::new(Arc::clone(bytes)))),
+            None => File::open(&self.source)
+                .map(|file| WorkbookArchive::File(BufReader::new(file)))
+                .map_err(|err| FastExcelErrorKind::Internal(err.to_string()).into())
+                .with_context(|| format!("could not open workbook at {}", self.source)),
+        }
+    }
+
+    /// Reads the rows of an xlsx sheet hidden by its autofilter
+    fn autofilter_hidden_rows(&self, sheet_name: &str) -> FastExcelResult<HashSet<u32>> {
+        if !self.sheets.is_xlsx() {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "`respect_autofilter` is only supported for xlsx files".to_string(),
+            )
+            .into());
+        }
+        autofilter_hidden_rows(self.workbook_archive()?, sheet_name)
+    }
+
+    /// The data of an xlsx table including the header rows calamine excludes from it, if any
+    fn table_data_with_header_rows(
+        &mut self,
+        table: &Table<Data>,
+    ) -> FastExcelResult<Option<Range<Data>>> {
+        let header_row_count = table_header_row_count(self.workbook_archive()?, table.name())
+            .with_context(|| format!("could not read the header rows of table {}", table.name()))?;
+        let (Some(start), Some(end)) = (table.data().start(), table.data().end()) else {
+            return Ok(None);
+        };
+        if header_row_count == 0 {
+            return Ok(None);
+        }
+        let sheet_range = self.sheets.worksheet_range(table.sheet_name())?;
+        Ok(Some(sheet_range.range(
+            (start.0.saturating_sub(header_row_count), start.1),
+            end,
+        )))
+    }
+
+    /// Reads whether the workbook stores its dates as days since 1904-01-01. Only xlsx workbooks
+    /// are looked up, other formats are considered to use the 1900 date system. Called when the
+    /// workbook is opened, so that loading sheets does not depend on reopening it
+    fn read_1904_date_system(&self) -> FastExcelResult<bool> {
+        if !self.sheets.is_xlsx() {
+            return Ok(false);
+        }
+        uses_1904_date_system(self.workbook_archive()?)
+            .with_context(|| "could not read the date system of the workbook")
+    }
+
     // NOTE: Not implementing TryFrom here, because we're aren't building the file from the passed
     // string, but rather from the file pointed by it. Semantically, try_from_path is clearer
     pub(crate) fn try_from_path(path: &str) -> FastExcelResult<Self> {
+        // If the file cannot be opened, let calamine report it
+        if let Ok(mut file) = File::open(path) {
+            check_zip_archive(&mut file)
+                .with_context(|| format!("Could not open workbook at {path}"))?;
+        }
         let sheets = open_workbook_auto(path)
-            .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+            .map_err(|err| FastExcelErrorKind::from_open_error(err).into())
             .with_context(|| format!("Could not open workbook at {path}"))?;
         let sheet_metadata = sheets.sheets_metadata().to_owned();
-        Ok(Self {
+        let mut reader = Self {
             sheets: ExcelSheets::File(sheets),
             sheet_metadata,
             source: path.to_owned(),
-        })
+            bytes: None,
+            date1904: false,
+        };
+        reader.date1904 = reader
+            .read_1904_date_system()
+            .with_context(|| format!("Could not open workbook at {path}"))?;
+        Ok(reader)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn load_sheet_eager(
         data: &ExcelSheetData,
         pagination: Pagination,
         header: Header,
-        sample_rows: Option<usize>,
+        sample_rows: Option<SchemaSampleRows>,
         selected_columns: &SelectedColumns,
         dtypes: Option<&DTypeMap>,
         dtype_coercion: &DTypeCoercion,
+        read_options: &ReadOptions,
     ) -> FastExcelResult<RecordBatch> {
         let offset = header.offset() + pagination.offset();
         let limit = {
-            let upper_bound = data.height();
+            // The header and skipped rows may go beyond the end of the data
+            let upper_bound = std::cmp::max(data.height(), offset);
             if let Some(n_rows) = pagination.n_rows() {
                 // minimum value between (offset+n_rows) and the data's height
                 std::cmp::min(offset + n_rows, upper_bound)
@@ -141,46 +393,233 @@ impl ExcelReader {
             }
         };
 
-        let sample_rows_limit = get_schema_sample_rows(sample_rows, offset, limit);
-        let available_columns_info = build_available_columns_info(data, selected_columns, &header)?;
+        let sample_rows =
+            get_schema_sample_rows(sample_rows, read_options.sample_strategy, offset, limit);
+        let available_columns_info = build_available_columns_info(
+            data,
+            selected_columns,
+            &header,
+            read_options.column_prefix(),
+        )?;
 
         let available_columns = build_available_columns(
             available_columns_info,
             data,
-            offset,
-            sample_rows_limit,
+            sample_rows,
             dtypes,
             dtype_coercion,
+            read_options,
         )?;
 
-        let final_columns = selected_columns.select_columns(&available_columns)?;
+        let final_columns =
+            read_options.reorder_columns(selected_columns.select_columns(&available_columns)?)?;
+        read_options.validate_fill_values(&final_columns)?;
+
+        record_batch_from_data_and_columns(
+            &final_columns,
+            data,
+            offset,
+            limit,
+            dtype_coercion,
+            read_options,
+        )
+    }
+
+    /// Detects the header row of a sheet. Falls back to the first row, and emits a warning, if no
+    /// header row could be detected
+    fn auto_header<CT: CellType + DataType>(
+        range: &Range<CT>,
+        sheet_name: &str,
+        py: Python<'_>,
+    ) -> PyResult<Header> {
+        let header_row = match detect_header_row(range) {
+            Some(row) => row,
+            None => {
+                PyErr::warn_bound(
+                    py,
+                    &py.get_type_bound::<PyUserWarning>(),
+                    &format!(
+                        "could not detect the header row of sheet \"{sheet_name}\", using the first row"
+                    ),
+                    1,
+                )?;
+                0
+            }
+        };
+        Ok(Header::At(header_row))
+    }
+
+    /// Looks the header row up when it is detected with `auto_header` or found with
+    /// `expected_headers`. Returns `header` otherwise
+    fn lookup_header<CT: CellType + DataType>(
+        range: &Range<CT>,
+        header: Header,
+        auto_header: bool,
+        expected_headers: Option<&[String]>,
+        header_search_limit: usize,
+        sheet_name: &str,
+        py: Python<'_>,
+    ) -> PyResult<Header> {
+        if let Some(expected_headers) = expected_headers {
+            find_header_row(range, expected_headers, header_search_limit)
+                .map(Header::At)
+                .with_context(|| format!("could not find the header row of sheet \"{sheet_name}\""))
+                .into_pyresult()
+        } else if auto_header {
+            Self::auto_header(range, sheet_name, py)
+        } else {
+            Ok(header)
+        }
+    }
+
+    /// Shapes the range of a sheet according to `options`, looks its header up and computes the
+    /// window of data rows to read. Also returns the data rows that were selected from the range,
+    /// if any
+    fn prepare_range<CT: CellType + DataType>(
+        mut range: Range<CT>,
+        sheet_name: &str,
+        merged_regions: Option<&[Dimensions]>,
+        hidden_rows: Option<&HashSet<u32>>,
+        options: &RangeOptions,
+        py: Python<'_>,
+    ) -> PyResult<PreparedRange<CT>> {
+        if let Some(merged_regions) = merged_regions {
+            fill_merged_regions(&mut range, merged_regions);
+        }
+        if let Some(cell_range) = &options.cell_range {
+            range = cell_range.apply(&range);
+        }
+        if options.transpose {
+            range = transpose_range(&range);
+        }
+        let size_limits = options.size_limits;
+        let range = size_limits
+            .cap_columns(range)
+            .and_then(|range| size_limits.check_cells(&range).map(|_| range))
+            .with_context(|| format!("could not read sheet \"{sheet_name}\""))
+            .into_pyresult()?;
+
+        let header = Self::lookup_header(
+            &range,
+            Header::new(options.header_row, options.column_names.clone()),
+            options.auto_header,
+            options.expected_headers.as_deref(),
+            options.header_search_limit,
+            sheet_name,
+            py,
+        )?
+        .with_depth(options.header_depth, &options.header_separator)
+        .into_pyresult()?;
+        let header = if options.keep_header_in_data {
+            header.keep_in_data().into_pyresult()?
+        } else {
+            header
+        };
+
+        let data_rows = match hidden_rows {
+            Some(hidden_rows) => Some(visible_data_rows(
+                &range,
+                header.offset(),
+                options.data_rows.as_deref(),
+                hidden_rows,
+            )),
+            None => options.data_rows.clone(),
+        };
+        let range = match &data_rows {
+            Some(data_rows) => {
+                select_data_rows(&range, header.offset(), data_rows).into_pyresult()?
+            }
+            None => range,
+        };
 
-        record_batch_from_data_and_columns(&final_columns, data, offset, limit)
+        let skip_rows = match options.data_start_row {
+            Some(data_start_row) => {
+                options.skip_rows + header.gap_before(data_start_row).into_pyresult()?
+            }
+            None => options.skip_rows,
+        };
+        let mut pagination = Pagination::new(skip_rows, options.n_rows, &range);
+        if options.stop_at_empty_row {
+            pagination = pagination.stop_at_empty_row(&range, header.offset());
+        }
+        let pagination = size_limits
+            .cap_rows(pagination, &range, header.offset())
+            .with_context(|| format!("could not read sheet \"{sheet_name}\""))
+            .into_pyresult()?;
+        Ok((range, header, pagination, data_rows))
     }
 
     #[allow(clippy::too_many_arguments)]
     fn build_sheet(
         &mut self,
         sheet_meta: CalamineSheet,
-        header_row: Option<usize>,
-        column_names: Option<Vec<String>>,
-        skip_rows: usize,
-        n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
+        range_options: RangeOptions,
+        schema_sample_rows: Option<SchemaSampleRows>,
         dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
         dtypes: Option<DTypeMap>,
+        read_options: ReadOptions,
         eager: bool,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
-        let header = Header::new(header_row, column_names);
+        range_options.validate().into_pyresult()?;
         let selected_columns = Self::build_selected_columns(use_columns).into_pyresult()?;
-        if eager && self.sheets.supports_by_ref() {
+        // Read before the sheet's range, which borrows the sheets
+        let hidden_rows = if range_options.respect_autofilter {
+            Some(
+                self.autofilter_hidden_rows(&sheet_meta.name)
+                    .into_pyresult()?,
+            )
+        } else {
+            None
+        };
+        let merged_regions = if range_options.fill_merged_cells {
+            let merged_regions = self
+                .sheets
+                .merged_regions(&sheet_meta.name)
+                .into_pyresult()?;
+            if merged_regions.is_none() {
+                PyErr::warn_bound(
+                    py,
+                    &py.get_type_bound::<PyUserWarning>(),
+                    &format!(
+                        "merged cells can only be filled for xlsx files, the merged cells of sheet \"{}\" are left blank",
+                        sheet_meta.name
+                    ),
+                    1,
+                )?;
+            }
+            merged_regions
+        } else {
+            None
+        };
+        let formulas = match range_options.cells {
+            CellsMode::Values => None,
+            CellsMode::ValueAndFormula => Some(
+                self.sheets
+                    .worksheet_formula(&sheet_meta.name)
+                    .into_pyresult()?,
+            ),
+        };
+        // Validating the header and reading formulas require an ExcelSheet, so the eager path is
+        // not used in those cases
+        if eager
+            && self.sheets.supports_by_ref()
+            && !range_options.validate_header
+            && formulas.is_none()
+        {
             let range = self
                 .sheets
                 .worksheet_range_ref(&sheet_meta.name)
                 .into_pyresult()?;
-            let pagination = Pagination::new(skip_rows, n_rows, &range).into_pyresult()?;
+            let (range, header, pagination, _) = Self::prepare_range(
+                range,
+                &sheet_meta.name,
+                merged_regions.as_deref(),
+                hidden_rows.as_ref(),
+                &range_options,
+                py,
+            )?;
             Self::load_sheet_eager(
                 &range.into(),
                 pagination,
@@ -189,7 +628,9 @@ impl ExcelReader {
                 &selected_columns,
                 dtypes.as_ref(),
                 &dtype_coercion,
+                &read_options,
             )
+            .with_cell_location(|location| location.sheet_name = Some(sheet_meta.name.clone()))
             .into_pyresult()
             .and_then(|rb| rb.to_pyarrow(py))
         } else {
@@ -197,8 +638,15 @@ impl ExcelReader {
                 .sheets
                 .worksheet_range(&sheet_meta.name)
                 .into_pyresult()?;
-            let pagination = Pagination::new(skip_rows, n_rows, &range).into_pyresult()?;
-            let sheet = ExcelSheet::try_new(
+            let (range, header, pagination, data_rows) = Self::prepare_range(
+                range,
+                &sheet_meta.name,
+                merged_regions.as_deref(),
+                hidden_rows.as_ref(),
+                &range_options,
+                py,
+            )?;
+            let mut sheet = ExcelSheet::try_new(
                 sheet_meta,
                 range.into(),
                 header,
@@ -207,9 +655,28 @@ impl ExcelReader {
                 dtype_coercion,
                 selected_columns,
                 dtypes,
+                read_options,
             )
             .into_pyresult()?;
 
+            if range_options.validate_header {
+                for header_warning in sheet.validate_header() {
+                    PyErr::warn_bound(
+                        py,
+                        &py.get_type_bound::<PyUserWarning>(),
+                        &header_warning.message(),
+                        1,
+                    )?;
+                }
+            }
+            if range_options.collect_warnings {
+                sheet.collect_warnings();
+            }
+            if let Some(formulas) = formulas {
+                sheet.read_formulas(formulas);
+            }
+            sheet.record_source_positions(data_rows, range_options.transpose);
+
             if eager {
                 sheet.to_arrow(py)
             } else {
@@ -222,38 +689,52 @@ impl ExcelReader {
     fn build_table(
         &mut self,
         name: String,
+        table_has_header: bool,
         header_row: Option<usize>,
         column_names: Option<Vec<String>>,
         skip_rows: usize,
         n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
+        schema_sample_rows: Option<SchemaSampleRows>,
         dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
         dtypes: Option<DTypeMap>,
+        read_options: ReadOptions,
         eager: bool,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
         let selected_columns = Self::build_selected_columns(use_columns).into_pyresult()?;
 
         let table = self.sheets.get_table(&name).into_pyresult()?;
+        let data_with_header_rows = if table_has_header {
+            None
+        } else {
+            self.table_data_with_header_rows(&table).into_pyresult()?
+        };
         let header = {
             match (column_names, header_row) {
-                (None, None) => Header::With(table.columns().into()),
+                (None, None) if table_has_header => Header::With(table_column_names(&table)),
+                (None, None) => Header::None,
                 (None, Some(row)) => Header::At(row),
                 (Some(column_names), _) => Header::With(column_names),
             }
         };
 
-        let pagination = Pagination::new(skip_rows, n_rows, table.data()).into_pyresult()?;
+        let pagination = Pagination::new(
+            skip_rows,
+            n_rows,
+            data_with_header_rows.as_ref().unwrap_or(table.data()),
+        );
 
         let excel_table = ExcelTable::try_new(
             table,
+            data_with_header_rows,
             header,
             pagination,
             schema_sample_rows,
             dtype_coercion,
             selected_columns,
             dtypes,
+            read_options,
         )
         .into_pyresult()?;
 
@@ -269,16 +750,24 @@ impl TryFrom<&[u8]> for ExcelReader {
     type Error = FastExcelError;
 
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        let cursor = Cursor::new(bytes.to_vec());
+        let bytes: Arc<[u8]> = bytes.into();
+        let mut cursor = Cursor::new(Arc::clone(&bytes));
+        check_zip_archive(&mut cursor).with_context(|| "Could not open workbook from bytes")?;
         let sheets = open_workbook_auto_from_rs(cursor)
-            .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+            .map_err(|err| FastExcelErrorKind::from_open_error(err).into())
             .with_context(|| "Could not open workbook from bytes")?;
         let sheet_metadata = sheets.sheets_metadata().to_owned();
-        Ok(Self {
+        let mut reader = Self {
             sheets: ExcelSheets::Bytes(sheets),
             sheet_metadata,
             source: "bytes".to_owned(),
-        })
+            bytes: Some(bytes),
+            date1904: false,
+        };
+        reader.date1904 = reader
+            .read_1904_date_system()
+            .with_context(|| "Could not open workbook from bytes")?;
+        Ok(reader)
     }
 }
 
@@ -289,9 +778,32 @@ impl ExcelReader {
     }
 
     pub fn table_names(&mut self, sheet_name: Option<&str>) -> PyResult<Vec<String>> {
+        // calamine finds no table in sheets that do not exist
+        if let Some(sheet_name) = sheet_name {
+            self.find_sheet_meta(IdxOrName::Name(sheet_name.to_owned()))
+                .into_pyresult()?;
+        }
         self.sheets.table_names(sheet_name).into_pyresult()
     }
 
+    #[pyo3(signature = (sheet_name = None))]
+    pub fn tables(&mut self, sheet_name: Option<&str>) -> PyResult<Vec<TableInfo>> {
+        if let Some(sheet_name) = sheet_name {
+            self.find_sheet_meta(IdxOrName::Name(sheet_name.to_owned()))
+                .into_pyresult()?;
+        }
+        self.sheets.table_infos(sheet_name).into_pyresult()
+    }
+
+    pub fn defined_names(&self) -> Vec<(String, String)> {
+        self.sheets.defined_names()
+    }
+
+    pub fn defined_name_range(&self, name: &str) -> PyResult<(String, String)> {
+        let (sheet_name, cell_range) = self.sheets.defined_name_range(name).into_pyresult()?;
+        Ok((sheet_name, cell_range.to_string()))
+    }
+
     #[pyo3(signature = (
         idx_or_name,
         *,
@@ -299,11 +811,59 @@ impl ExcelReader {
         column_names = None,
         skip_rows = 0,
         n_rows = None,
-        schema_sample_rows = 1_000,
+        schema_sample_rows = Some(SchemaSampleRows::Count(1_000)),
+        sample_strategy = SampleStrategy::Head,
         dtype_coercion = DTypeCoercion::Coerce,
         use_columns = None,
         dtypes = None,
+        ignore_unknown_dtypes = false,
         eager = false,
+        timestamp_unit = TimestampUnit::Millisecond,
+        index_column = None,
+        bool_as_int8 = false,
+        bool_fill = None,
+        epoch = None,
+        assume_timezone = None,
+        timezone = None,
+        na_values = None,
+        empty_string_as_null = false,
+        trim_strings = false,
+        n_threads = None,
+        all_columns_dtype = None,
+        infer_dates = false,
+        true_values = None,
+        false_values = None,
+        rename = None,
+        unknown_renames = UnknownRenames::Ignore,
+        duplicate_columns = DuplicateColumns::Underscore,
+        currency_symbols = None,
+        capture_currency = false,
+        tighten_nullability = false,
+        decimal_separator = None,
+        thousands_separator = None,
+        fill_values = None,
+        forward_fill = None,
+        column_prefix = DEFAULT_COLUMN_PREFIX,
+        auto_header = false,
+        validate_header = false,
+        stop_at_empty_row = false,
+        header_depth = 1,
+        header_separator = " / ",
+        keep_header_in_data = false,
+        data_start_row = None,
+        data_rows = None,
+        expected_headers = None,
+        header_search_limit = 100,
+        respect_autofilter = false,
+        collect_warnings = false,
+        transpose = false,
+        cell_range = None,
+        fill_merged_cells = false,
+        max_columns = None,
+        max_rows = None,
+        max_cells = None,
+        on_size_limit = SizeLimitOverflow::Error,
+        cells = CellsMode::Values,
     ))]
     #[allow(clippy::too_many_arguments)]
     pub fn load_sheet(
@@ -313,50 +873,136 @@ impl ExcelReader {
         column_names: Option<Vec<String>>,
         skip_rows: usize,
         n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
+        schema_sample_rows: Option<SchemaSampleRows>,
+        sample_strategy: SampleStrategy,
         dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
         dtypes: Option<DTypeMap>,
+        ignore_unknown_dtypes: bool,
         eager: bool,
+        timestamp_unit: TimestampUnit,
+        index_column: Option<IdxOrName>,
+        bool_as_int8: bool,
+        bool_fill: Option<bool>,
+        epoch: Option<NaiveDate>,
+        assume_timezone: Option<Timezone>,
+        timezone: Option<Timezone>,
+        na_values: Option<Vec<String>>,
+        empty_string_as_null: bool,
+        trim_strings: bool,
+        n_threads: Option<usize>,
+        all_columns_dtype: Option<DType>,
+        infer_dates: bool,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        rename: Option<HashMap<String, String>>,
+        unknown_renames: UnknownRenames,
+        duplicate_columns: DuplicateColumns,
+        currency_symbols: Option<Vec<String>>,
+        capture_currency: bool,
+        tighten_nullability: bool,
+        decimal_separator: Option<char>,
+        thousands_separator: Option<char>,
+        fill_values: Option<HashMap<String, FillValue>>,
+        forward_fill: Option<Vec<IdxOrName>>,
+        column_prefix: &str,
+        auto_header: bool,
+        validate_header: bool,
+        stop_at_empty_row: bool,
+        header_depth: usize,
+        header_separator: &str,
+        keep_header_in_data: bool,
+        data_start_row: Option<usize>,
+        data_rows: Option<Vec<usize>>,
+        expected_headers: Option<Vec<String>>,
+        header_search_limit: usize,
+        respect_autofilter: bool,
+        collect_warnings: bool,
+        transpose: bool,
+        cell_range: Option<CellRange>,
+        fill_merged_cells: bool,
+        max_columns: Option<usize>,
+        max_rows: Option<usize>,
+        max_cells: Option<usize>,
+        on_size_limit: SizeLimitOverflow,
+        cells: CellsMode,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
+        let read_options = ReadOptions {
+            timestamp_unit,
+            index_column,
+            bool_as_int8,
+            bool_fill,
+            epoch,
+            assume_timezone,
+            timezone,
+            na_values: na_values.unwrap_or_default(),
+            empty_string_as_null,
+            trim_strings,
+            n_threads,
+            all_columns_dtype,
+            infer_dates,
+            true_values: true_values.unwrap_or_default(),
+            false_values: false_values.unwrap_or_default(),
+            rename: rename.unwrap_or_default(),
+            unknown_renames,
+            ignore_unknown_dtypes,
+            duplicate_columns,
+            currency_symbols: currency_symbols.unwrap_or_default(),
+            capture_currency,
+            tighten_nullability,
+            decimal_separator,
+            thousands_separator,
+            fill_values: fill_values.unwrap_or_default(),
+            forward_fill: forward_fill.unwrap_or_default(),
+            sample_strategy,
+            date1904: self.date1904,
+            column_prefix: Some(column_prefix.to_owned()),
+        };
+        read_options.validate_separators().into_pyresult()?;
+        read_options.validate_n_threads().into_pyresult()?;
+        let range_options = RangeOptions {
+            header_row,
+            column_names,
+            auto_header,
+            validate_header,
+            expected_headers,
+            header_search_limit,
+            header_depth,
+            header_separator: header_separator.to_owned(),
+            keep_header_in_data,
+            data_start_row,
+            data_rows,
+            skip_rows,
+            n_rows,
+            stop_at_empty_row,
+            respect_autofilter,
+            collect_warnings,
+            transpose,
+            cell_range,
+            fill_merged_cells,
+            size_limits: SizeLimits {
+                max_columns,
+                max_rows,
+                max_cells,
+                on_overflow: on_size_limit,
+            },
+            cells,
+        };
         let sheet = idx_or_name
             .try_into()
-            .and_then(|idx_or_name| match idx_or_name {
-                IdxOrName::Name(name) => {
-                    if let Some(sheet) = self.sheet_metadata.iter().find(|s| s.name == name) {
-                        Ok(sheet)
-                    } else {
-                        Err(FastExcelErrorKind::SheetNotFound(IdxOrName::Name(name.clone())).into()).with_context(||  {
-                            let available_sheets = self.sheet_metadata.iter().map(|s| format!("\"{}\"", s.name)).collect::<Vec<_>>().join(", ");
-                            format!(
-                                "Sheet \"{name}\" not found in file. Available sheets: {available_sheets}."
-                            )
-                        })
-                    }
-                }
-                IdxOrName::Idx(idx) => self
-                    .sheet_metadata                    .get(idx)
-                    .ok_or_else(|| FastExcelErrorKind::SheetNotFound(IdxOrName::Idx(idx)).into())
-                    .with_context(|| {                         format!(
-                            "Sheet index {idx} is out of range. File has {} sheets.",
-                            self.sheet_metadata.len()
-                        )
-                    })
-                    ,
-            })
-            .into_pyresult()?.to_owned();
+            .and_then(|idx_or_name| self.find_sheet_meta(idx_or_name))
+            .into_pyresult()?
+            .to_owned();
 
         self.build_sheet(
             sheet,
-            header_row,
-            column_names,
-            skip_rows,
-            n_rows,
+            range_options,
             schema_sample_rows,
             dtype_coercion,
             use_columns,
             dtypes,
+            read_options,
             eager,
             py,
         )
@@ -369,11 +1015,38 @@ impl ExcelReader {
         column_names = None,
         skip_rows = 0,
         n_rows = None,
-        schema_sample_rows = 1_000,
+        schema_sample_rows = Some(SchemaSampleRows::Count(1_000)),
+        sample_strategy = SampleStrategy::Head,
         dtype_coercion = DTypeCoercion::Coerce,
         use_columns = None,
         dtypes = None,
         eager = false,
+        timestamp_unit = TimestampUnit::Millisecond,
+        index_column = None,
+        bool_as_int8 = false,
+        bool_fill = None,
+        epoch = None,
+        assume_timezone = None,
+        timezone = None,
+        na_values = None,
+        empty_string_as_null = false,
+        trim_strings = false,
+        n_threads = None,
+        all_columns_dtype = None,
+        infer_dates = false,
+        true_values = None,
+        false_values = None,
+        rename = None,
+        unknown_renames = UnknownRenames::Ignore,
+        duplicate_columns = DuplicateColumns::Underscore,
+        currency_symbols = None,
+        capture_currency = false,
+        tighten_nullability = false,
+        decimal_separator = None,
+        thousands_separator = None,
+        fill_values = None,
+        forward_fill = None,
+        table_has_header = true,
     ))]
     #[allow(clippy::too_many_arguments)]
     pub fn load_table(
@@ -383,15 +1056,76 @@ impl ExcelReader {
         column_names: Option<Vec<String>>,
         skip_rows: usize,
         n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
+        schema_sample_rows: Option<SchemaSampleRows>,
+        sample_strategy: SampleStrategy,
         dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
         dtypes: Option<DTypeMap>,
         eager: bool,
+        timestamp_unit: TimestampUnit,
+        index_column: Option<IdxOrName>,
+        bool_as_int8: bool,
+        bool_fill: Option<bool>,
+        epoch: Option<NaiveDate>,
+        assume_timezone: Option<Timezone>,
+        timezone: Option<Timezone>,
+        na_values: Option<Vec<String>>,
+        empty_string_as_null: bool,
+        trim_strings: bool,
+        n_threads: Option<usize>,
+        all_columns_dtype: Option<DType>,
+        infer_dates: bool,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        rename: Option<HashMap<String, String>>,
+        unknown_renames: UnknownRenames,
+        duplicate_columns: DuplicateColumns,
+        currency_symbols: Option<Vec<String>>,
+        capture_currency: bool,
+        tighten_nullability: bool,
+        decimal_separator: Option<char>,
+        thousands_separator: Option<char>,
+        fill_values: Option<HashMap<String, FillValue>>,
+        forward_fill: Option<Vec<IdxOrName>>,
+        table_has_header: bool,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
+        let read_options = ReadOptions {
+            timestamp_unit,
+            index_column,
+            bool_as_int8,
+            bool_fill,
+            epoch,
+            assume_timezone,
+            timezone,
+            na_values: na_values.unwrap_or_default(),
+            empty_string_as_null,
+            trim_strings,
+            n_threads,
+            all_columns_dtype,
+            infer_dates,
+            true_values: true_values.unwrap_or_default(),
+            false_values: false_values.unwrap_or_default(),
+            rename: rename.unwrap_or_default(),
+            unknown_renames,
+            ignore_unknown_dtypes: false,
+            duplicate_columns,
+            currency_symbols: currency_symbols.unwrap_or_default(),
+            capture_currency,
+            tighten_nullability,
+            decimal_separator,
+            thousands_separator,
+            fill_values: fill_values.unwrap_or_default(),
+            forward_fill: forward_fill.unwrap_or_default(),
+            sample_strategy,
+            date1904: self.date1904,
+            column_prefix: None,
+        };
+        read_options.validate_separators().into_pyresult()?;
+        read_options.validate_n_threads().into_pyresult()?;
         self.build_table(
             name.to_string(),
+            table_has_header,
             header_row,
             column_names,
             skip_rows,
@@ -400,11 +1134,71 @@ impl ExcelReader {
             dtype_coercion,
             use_columns,
             dtypes,
+            read_options,
             eager,
             py,
         )
     }
 
+    #[pyo3(signature = (
+        idx_or_name,
+        *,
+        header_row = 0,
+        header_depth = 1,
+        header_separator = " / ",
+        column_prefix = DEFAULT_COLUMN_PREFIX,
+        duplicate_columns = DuplicateColumns::Underscore,
+    ))]
+    pub fn column_names(
+        &mut self,
+        idx_or_name: &Bound<'_, PyAny>,
+        header_row: Option<usize>,
+        header_depth: usize,
+        header_separator: &str,
+        column_prefix: &str,
+        duplicate_columns: DuplicateColumns,
+    ) -> PyResult<Vec<String>> {
+        let sheet_name = idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| self.find_sheet_meta(idx_or_name))
+            .into_pyresult()?
+            .name
+            .to_owned();
+        let range = self.sheets.worksheet_range(&sheet_name).into_pyresult()?;
+        let header = Header::new(header_row, None)
+            .with_depth(header_depth, header_separator)
+            .into_pyresult()?;
+        // Only the header cells are read, no dtype is inferred from the data
+        let available_columns_info =
+            build_available_columns_info(&range, &SelectedColumns::All, &header, column_prefix)
+                .into_pyresult()?;
+        Ok(
+            alias_available_columns(available_columns_info, duplicate_columns)
+                .into_pyresult()?
+                .iter()
+                .map(|column_info_builder| column_info_builder.name().to_owned())
+                .collect(),
+        )
+    }
+
+    pub fn uses_1904_dates(&self) -> bool {
+        self.date1904
+    }
+
+    pub fn shared_strings(&self) -> PyResult<Vec<String>> {
+        if !self.sheets.is_xlsx() {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "`shared_strings` is only supported for xlsx files".to_string(),
+            )
+            .into())
+            .into_pyresult();
+        }
+        self.workbook_archive()
+            .and_then(shared_strings)
+            .with_context(|| "could not read the shared strings of the workbook")
+            .into_pyresult()
+    }
+
     #[getter]
     pub fn sheet_names(&self) -> Vec<&str> {
         self.sheet_metadata
@@ -412,4 +1206,13 @@ impl ExcelReader {
             .map(|s| s.name.as_str())
             .collect()
     }
+
+    #[getter]
+    pub fn visible_sheet_names(&self) -> Vec<&str> {
+        self.sheet_metadata
+            .iter()
+            .filter(|s| s.visible == SheetVisible::Visible)
+            .map(|s| s.name.as_str())
+            .collect()
+    }
 }
@@ -0,0 +1,148 @@
+use std::{cmp, sync::Arc};
+
+use arrow::{
+    datatypes::{Schema, SchemaRef},
+    pyarrow::ToPyArrow,
+    record_batch::RecordBatch,
+};
+use pyo3::{pyclass, pymethods, Py, PyObject, PyRef, PyResult, Python};
+
+use crate::{
+    data::{forward_fill_from_previous_batch, record_batch_from_data_and_columns},
+    error::{py_errors::IntoPyResult, ErrorContext, FastExcelErrorKind, FastExcelResult},
+};
+
+use super::ExcelSheet;
+
+/// Iterator over the rows of a sheet, yielding record batches of at most `batch_size` rows. Only
+/// one batch is converted at a time, and all of them have the same schema
+#[pyclass(name = "_ExcelSheetBatches")]
+pub(crate) struct ExcelSheetBatches {
+    sheet: Py<ExcelSheet>,
+    schema: SchemaRef,
+    batch_size: usize,
+    row: usize,
+    limit: usize,
+    /// The last row of the previous batch, which forward-filled columns continue
+    previous_row: Option<RecordBatch>,
+}
+
+impl ExcelSheetBatches {
+    pub(crate) fn try_new(
+        sheet: Py<ExcelSheet>,
+        schema: Schema,
+        batch_size: usize,
+        offset: usize,
+        limit: usize,
+    ) -> FastExcelResult<Self> {
+        if batch_size == 0 {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "`batch_size` must be greater than 0".to_string(),
+            )
+            .into());
+        }
+        Ok(Self {
+            sheet,
+            schema: Arc::new(schema),
+            batch_size,
+            row: offset,
+            limit,
+            previous_row: None,
+        })
+    }
+}
+
+/// Sets the schema shared by all batches, as the nullability and metadata of the fields of a
+/// batch depend on its rows
+fn with_batches_schema(rb: RecordBatch, schema: SchemaRef) -> FastExcelResult<RecordBatch> {
+    RecordBatch::try_new(schema, rb.columns().to_vec())
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+}
+
+#[pymethods]
+impl ExcelSheetBatches {
+    /// The schema of every batch
+    #[getter]
+    pub fn schema(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.schema.to_pyarrow(py)
+    }
+
+    pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        if self.row >= self.limit {
+            return Ok(None);
+        }
+        let end = cmp::min(self.row + self.batch_size, self.limit);
+        let sheet = self.sheet.borrow(py);
+        let rb = record_batch_from_data_and_columns(
+            &sheet.selected_columns,
+            sheet.data(),
+            self.row,
+            end,
+            &sheet.dtype_coercion,
+            &sheet.read_options,
+        )
+        .and_then(|rb| match &self.previous_row {
+            Some(previous_row) => forward_fill_from_previous_batch(
+                rb,
+                previous_row,
+                &sheet.selected_columns,
+                &sheet.read_options,
+            ),
+            None => Ok(rb),
+        })
+        .inspect(|rb| self.previous_row = Some(rb.slice(rb.num_rows() - 1, 1)))
+        .and_then(|rb| sheet.with_formula_columns(rb, self.row, end))
+        .and_then(|rb| with_batches_schema(rb, Arc::clone(&self.schema)))
+        .with_context(|| {
+            format!(
+                "could not read rows {start} to {end} of sheet \"{sheet_name}\"",
+                start = self.row,
+                sheet_name = sheet.name()
+            )
+        })
+        .into_pyresult()?;
+        self.row = end;
+        rb.to_pyarrow(py).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::{
+        array::{Float64Array, StringArray},
+        datatypes::{DataType, Field},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn batches_share_the_nullable_schema() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("amount", DataType::Float64, true),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let rb = RecordBatch::try_from_iter_with_nullable([
+            (
+                "amount",
+                Arc::new(Float64Array::from(vec![1.0, 2.0])) as _,
+                false,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["a", "b"])) as _,
+                false,
+            ),
+        ])
+        .unwrap();
+
+        let rb = with_batches_schema(rb, Arc::clone(&schema)).unwrap();
+
+        assert_eq!(rb.schema(), schema);
+        assert_eq!(rb.num_rows(), 2);
+    }
+}
@@ -0,0 +1,179 @@
+use std::{cmp, fmt::Display, str::FromStr};
+
+use calamine::{CellType, Range};
+use pyo3::{prelude::PyAnyMethods, types::PyString, Bound, FromPyObject, PyAny, PyResult};
+
+use crate::error::{
+    column_letters, py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind,
+    FastExcelResult,
+};
+
+use super::SelectedColumns;
+
+/// A rectangle of cells read instead of the whole sheet, e.g. `"B2:F100"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CellRange {
+    /// The absolute position of the top-left cell
+    start: (u32, u32),
+    /// The absolute position of the bottom-right cell
+    end: (u32, u32),
+}
+
+impl CellRange {
+    /// Restricts `range` to the cells of the cell range. The resulting range starts at the
+    /// top-left cell of the cell range, even if it is empty, and ends at the last row and column
+    /// of `range`
+    pub(crate) fn apply<CT: CellType>(&self, range: &Range<CT>) -> Range<CT> {
+        let Some((end_row, end_col)) = range.end() else {
+            return Range::empty();
+        };
+        let end = (cmp::min(self.end.0, end_row), cmp::min(self.end.1, end_col));
+        if end.0 < self.start.0 || end.1 < self.start.1 {
+            Range::empty()
+        } else {
+            range.range(self.start, end)
+        }
+    }
+}
+
+impl Display for CellRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ((start_row, start_col), (end_row, end_col)) = (self.start, self.end);
+        write!(
+            f,
+            "{}{}:{}{}",
+            column_letters(start_col as usize),
+            start_row + 1,
+            column_letters(end_col as usize),
+            end_row + 1
+        )
+    }
+}
+
+/// Parses an A1-style cell reference, e.g. `"B2"`, into an absolute (row, column) position
+fn parse_cell_reference(reference: &str) -> FastExcelResult<(u32, u32)> {
+    let invalid_reference = || {
+        FastExcelError::from(FastExcelErrorKind::InvalidParameters(format!(
+            "invalid cell reference: \"{reference}\""
+        )))
+    };
+    let digits_start = reference
+        .find(|chr: char| chr.is_ascii_digit())
+        .ok_or_else(invalid_reference)?;
+    let (letters, digits) = reference.split_at(digits_start);
+    let col = SelectedColumns::col_idx_for_col_as_letter(&letters.to_ascii_uppercase())
+        .with_context(|| format!("invalid cell reference: \"{reference}\""))?;
+    let row = digits
+        .parse::<u32>()
+        .ok()
+        .filter(|row| *row > 0)
+        .ok_or_else(invalid_reference)?;
+    let col = u32::try_from(col).map_err(|_| invalid_reference())?;
+    Ok((row - 1, col))
+}
+
+impl FromStr for CellRange {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        let Some((start, end)) = raw_value.split_once(':') else {
+            return Err(FastExcelErrorKind::InvalidParameters(format!(
+                "invalid cell_range: \"{raw_value}\", expected a range such as \"B2:F100\""
+            ))
+            .into());
+        };
+        let start = parse_cell_reference(start.trim())
+            .with_context(|| format!("invalid cell_range: \"{raw_value}\""))?;
+        let end = parse_cell_reference(end.trim())
+            .with_context(|| format!("invalid cell_range: \"{raw_value}\""))?;
+        if end.0 < start.0 || end.1 < start.1 {
+            return Err(FastExcelErrorKind::InvalidParameters(format!(
+                "end of cell_range is before its start: \"{raw_value}\""
+            ))
+            .into());
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl FromPyObject<'_> for CellRange {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{Cell, Data as CalData};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("B2:F100", (1, 1), (99, 5))]
+    #[case("a1:a1", (0, 0), (0, 0))]
+    #[case("AA10:AB12", (9, 26), (11, 27))]
+    fn valid_cell_ranges(
+        #[case] raw_value: &str,
+        #[case] start: (u32, u32),
+        #[case] end: (u32, u32),
+    ) {
+        let cell_range = raw_value.parse::<CellRange>().unwrap();
+
+        assert_eq!(cell_range, CellRange { start, end });
+        assert_eq!(cell_range.to_string(), raw_value.to_ascii_uppercase());
+    }
+
+    #[rstest]
+    #[case("B2", "expected a range such as")]
+    #[case("B2:A1", "end of cell_range is before its start")]
+    #[case("B2:F0", "invalid cell reference: \"F0\"")]
+    #[case("2:F10", "invalid cell reference: \"2\"")]
+    #[case("B:F10", "invalid cell reference: \"B\"")]
+    fn invalid_cell_ranges(#[case] raw_value: &str, #[case] expected_message: &str) {
+        let err = raw_value.parse::<CellRange>().unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+        assert!(
+            err.to_string().contains(expected_message),
+            "unexpected message: {err}"
+        );
+    }
+
+    #[test]
+    fn cell_range_is_relative_to_its_top_left_cell() {
+        // The sheet has a title in A1, and data in B3:C4
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::String("title".to_owned())),
+            Cell::new((2, 1), CalData::String("a".to_owned())),
+            Cell::new((2, 2), CalData::String("b".to_owned())),
+            Cell::new((3, 1), CalData::Float(1.0)),
+            Cell::new((3, 2), CalData::Float(2.0)),
+        ]);
+
+        let sub_range = "B3:Z100".parse::<CellRange>().unwrap().apply(&range);
+
+        assert_eq!(sub_range.start(), Some((2, 1)));
+        assert_eq!(sub_range.end(), Some((3, 2)));
+        assert_eq!(
+            sub_range.get((0, 0)),
+            Some(&CalData::String("a".to_owned()))
+        );
+        assert_eq!(sub_range.get((1, 1)), Some(&CalData::Float(2.0)));
+
+        assert!("E10:F12"
+            .parse::<CellRange>()
+            .unwrap()
+            .apply(&range)
+            .is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use std::ffi::CString;
+
+use arrow::{
+    array::{Array, ArrayRef},
+    datatypes::Field,
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema},
+    pyarrow::ToPyArrow,
+    record_batch::RecordBatch,
+};
+use pyo3::{pyclass, pymethods, types::PyCapsule, Bound, PyAny, PyObject, PyResult, Python};
+
+use crate::{
+    error::{py_errors::IntoPyResult, ErrorContext, FastExcelErrorKind, FastExcelResult},
+    types::idx_or_name::IdxOrName,
+};
+
+use super::column_info::ColumnInfo;
+
+/// Returns the selected column matching `idx_or_name`. Indices are the ones of the columns in the
+/// sheet, as in `use_columns`
+pub(crate) fn find_selected_column<'a>(
+    selected_columns: &'a [ColumnInfo],
+    idx_or_name: &IdxOrName,
+) -> FastExcelResult<&'a ColumnInfo> {
+    selected_columns
+        .iter()
+        .find(|column_info| *column_info == idx_or_name)
+        .ok_or_else(|| FastExcelErrorKind::ColumnNotFound(idx_or_name.clone()).into())
+        .with_context(|| "the column must be one of the selected columns")
+}
+
+/// A single column of a sheet or table, which implements the Arrow PyCapsule interface so that it
+/// can be imported without copies by any library supporting it
+#[pyclass(name = "ArrowColumn")]
+pub(crate) struct ArrowColumn {
+    field: Field,
+    array: ArrayRef,
+}
+
+impl ArrowColumn {
+    /// Builds a column from a `RecordBatch` containing a single column
+    pub(crate) fn try_from_record_batch(rb: RecordBatch) -> FastExcelResult<Self> {
+        match (rb.schema_ref().fields().first(), rb.columns().first()) {
+            (Some(field), Some(array)) if rb.num_columns() == 1 => Ok(Self {
+                field: field.as_ref().clone(),
+                array: array.clone(),
+            }),
+            _ => Err(FastExcelErrorKind::Internal(format!(
+                "expected a RecordBatch with a single column, got {} columns",
+                rb.num_columns()
+            ))
+            .into()),
+        }
+    }
+
+    fn ffi_schema_and_array(&self) -> FastExcelResult<(FFI_ArrowSchema, FFI_ArrowArray)> {
+        let schema = FFI_ArrowSchema::try_from(&self.field)
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            .with_context(|| format!("could not export the schema of column {}", self.name()))?;
+        Ok((schema, FFI_ArrowArray::new(&self.array.to_data())))
+    }
+}
+
+#[pymethods]
+impl ArrowColumn {
+    #[getter]
+    pub fn name(&self) -> &str {
+        self.field.name()
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Converts the column to a pyarrow `Array`
+    pub fn to_arrow(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.array.to_data().to_pyarrow(py)
+    }
+
+    /// Exports the column as an Arrow C array. The requested schema is ignored, as the column's
+    /// type is already determined when it is built
+    #[pyo3(signature = (requested_schema = None))]
+    pub fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        let (schema, array) = self.ffi_schema_and_array().into_pyresult()?;
+        let schema_capsule =
+            PyCapsule::new_bound(py, schema, Some(CString::new("arrow_schema").unwrap()))?;
+        let array_capsule =
+            PyCapsule::new_bound(py, array, Some(CString::new("arrow_array").unwrap()))?;
+        Ok((schema_capsule, array_capsule))
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ArrowColumn<{}: {}, length={}>",
+            self.name(),
+            self.field.data_type(),
+            self.array.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::{
+        array::{Float64Array, StringArray},
+        ffi::from_ffi,
+    };
+    use pretty_assertions::assert_eq;
+    use pyo3::types::PyCapsuleMethods;
+
+    use super::*;
+
+    #[test]
+    fn column_is_exported_with_the_pycapsule_interface() {
+        let array: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.0), None]));
+        let rb = RecordBatch::try_from_iter([("value", array.clone())]).unwrap();
+        let column = ArrowColumn::try_from_record_batch(rb).unwrap();
+
+        Python::with_gil(|py| {
+            let (schema_capsule, array_capsule) = column.__arrow_c_array__(py, None).unwrap();
+            assert_eq!(
+                schema_capsule.name().unwrap().unwrap().to_str(),
+                Ok("arrow_schema")
+            );
+            assert_eq!(
+                array_capsule.name().unwrap().unwrap().to_str(),
+                Ok("arrow_array")
+            );
+
+            // Consumers move the array out of its capsule, and only borrow the schema
+            let schema = unsafe { &*(schema_capsule.pointer() as *const FFI_ArrowSchema) };
+            let ffi_array =
+                unsafe { FFI_ArrowArray::from_raw(array_capsule.pointer() as *mut FFI_ArrowArray) };
+            assert_eq!(schema.name(), "value");
+            let data = unsafe { from_ffi(ffi_array, schema) }.unwrap();
+            assert_eq!(data, array.to_data());
+        });
+    }
+
+    #[test]
+    fn record_batch_must_have_a_single_column() {
+        let rb = RecordBatch::try_from_iter([
+            ("a", Arc::new(StringArray::from(vec!["x"])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["y"])) as ArrayRef),
+        ])
+        .unwrap();
+
+        assert!(ArrowColumn::try_from_record_batch(rb).is_err());
+    }
+}
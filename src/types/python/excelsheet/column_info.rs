@@ -1,22 +1,36 @@
-use std::{fmt::Display, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
 
 use arrow::datatypes::Field;
 use calamine::DataType;
 use pyo3::{pyclass, pymethods, PyResult};
 
 use crate::{
-    data::ExcelSheetData,
+    data::{has_only_dates, ExcelSheetData},
     error::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
     types::{
         dtype::{get_dtype_for_column, DType, DTypeCoercion, DTypeMap},
         idx_or_name::IdxOrName,
+        read_options::{DuplicateColumns, ReadOptions, UnknownRenames},
     },
+    utils::schema::SampleRows,
 };
 
 use super::{Header, SelectedColumns};
 
+/// Key of the arrow field metadata marking the index column
+pub(crate) const INDEX_COLUMN_METADATA_KEY: &str = "fastexcel.index_column";
+/// Key of the arrow field metadata listing the currency symbols found in a float column
+pub(crate) const CURRENCY_METADATA_KEY: &str = "fastexcel.currency";
+/// Key of the arrow field metadata holding the epoch of the integer offsets of a date or datetime
+/// column, when it is not the Unix epoch
+pub(crate) const EPOCH_METADATA_KEY: &str = "fastexcel.epoch";
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ColumnNameFrom {
     Provided,
@@ -54,6 +68,7 @@ impl Display for ColumnNameFrom {
 pub(crate) enum DTypeFrom {
     ProvidedByIndex,
     ProvidedByName,
+    ProvidedForAll,
     Guessed,
 }
 
@@ -62,6 +77,7 @@ impl Display for DTypeFrom {
         f.write_str(match self {
             DTypeFrom::ProvidedByIndex => "provided_by_index",
             DTypeFrom::ProvidedByName => "provided_by_name",
+            DTypeFrom::ProvidedForAll => "provided_for_all",
             DTypeFrom::Guessed => "guessed",
         })
     }
@@ -74,6 +90,7 @@ impl FromStr for DTypeFrom {
         match s {
             "provided_by_index" => Ok(Self::ProvidedByIndex),
             "provided_by_name" => Ok(Self::ProvidedByName),
+            "provided_for_all" => Ok(Self::ProvidedForAll),
             "guessed" => Ok(Self::Guessed),
             _ => Err(
                 FastExcelErrorKind::InvalidParameters(format!("invalid DTypesFrom: {s}")).into(),
@@ -127,11 +144,82 @@ impl ColumnInfo {
     pub(crate) fn dtype(&self) -> &DType {
         &self.dtype
     }
+
+    pub(crate) fn dtype_from(&self) -> &DTypeFrom {
+        &self.dtype_from
+    }
+
+    /// Whether the column is a "datetime" column whose dtype was guessed
+    fn is_guessed_datetime(&self) -> bool {
+        matches!(
+            (&self.dtype, &self.dtype_from),
+            (DType::DateTime, DTypeFrom::Guessed)
+        )
+    }
+
+    /// Reads the column as a "date" column
+    fn into_date(self) -> Self {
+        Self {
+            dtype: DType::Date,
+            ..self
+        }
+    }
+
+    /// Reads the column as a "datetime_tz" column if it is a "datetime" column
+    fn into_timezone_aware(self) -> Self {
+        match self.dtype {
+            DType::DateTime => Self {
+                dtype: DType::DateTimeTz,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// Returns the arrow field matching this column
+    pub(crate) fn field(&self, read_options: &ReadOptions) -> Field {
+        let mut metadata = HashMap::new();
+        if read_options.is_index_column(self) {
+            metadata.insert(INDEX_COLUMN_METADATA_KEY.to_owned(), "true".to_owned());
+        }
+        if read_options.has_custom_epoch()
+            && matches!(
+                self.dtype(),
+                DType::Date | DType::DateTime | DType::DateTimeTz
+            )
+        {
+            metadata.insert(
+                EPOCH_METADATA_KEY.to_owned(),
+                read_options.epoch().to_string(),
+            );
+        }
+        Field::new(self.name(), self.dtype().arrow_type(read_options), true).with_metadata(metadata)
+    }
 }
 
-impl From<&ColumnInfo> for Field {
-    fn from(col_info: &ColumnInfo) -> Self {
-        Field::new(col_info.name(), col_info.dtype().into(), true)
+/// The number of columns listed by `columns_preview`
+const PREVIEWED_COLUMNS: usize = 5;
+
+/// A short description of the first columns, with their dtype, e.g. `[id:int, name:string, ...]`
+pub(crate) fn columns_preview(columns: &[ColumnInfo]) -> String {
+    let mut preview: Vec<_> = columns
+        .iter()
+        .take(PREVIEWED_COLUMNS)
+        .map(|column_info| format!("{}:{}", column_info.name(), column_info.dtype()))
+        .collect();
+    if columns.len() > PREVIEWED_COLUMNS {
+        preview.push("...".to_owned());
+    }
+    format!("[{}]", preview.join(", "))
+}
+
+// Allows us to easily compare ourselves to a column index or name
+impl PartialEq<IdxOrName> for ColumnInfo {
+    fn eq(&self, other: &IdxOrName) -> bool {
+        match other {
+            IdxOrName::Idx(index) => index == &self.index,
+            IdxOrName::Name(name) => name == &self.name,
+        }
     }
 }
 
@@ -180,9 +268,10 @@ impl ColumnInfo {
 
     /// `fastexcel.DTypeFrom`. How the dtype of the column was determined.
     ///
-    /// One of three possible values:
+    /// One of four possible values:
     /// - `"provided_by_index"`: The dtype was specified via the column index
     /// - `"provided_by_name"`: The dtype was specified via the column name
+    /// - `"provided_for_all"`: The dtype was specified for all columns via `all_columns_dtype`
     /// - `"guessed"`: The dtype was determined from the content of the column
     #[getter(dtype_from)]
     fn get_dtype_from(&self) -> String {
@@ -198,6 +287,110 @@ impl ColumnInfo {
     }
 }
 
+/// A suspicious mismatch between the type of a header cell and the type of its column, which
+/// often means that the header row was misidentified
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(name = "HeaderWarning")]
+pub(crate) struct HeaderWarning {
+    /// `str`. The name of the column
+    #[pyo3(get)]
+    column_name: String,
+    /// `int`. The index of the column
+    #[pyo3(get)]
+    pub(crate) column_index: usize,
+    header_dtype: DType,
+    column_dtype: DType,
+}
+
+impl HeaderWarning {
+    /// Returns a warning if the dtype of the header cell does not look like a header for a column
+    /// of the given dtype, namely if:
+    /// * The header cell is not a string but the column contains strings
+    /// * The header cell is not a string and has the same dtype as the column, meaning the header
+    ///   row is probably a data row
+    fn check(column_info: &ColumnInfo, header_dtype: DType) -> Option<Self> {
+        let column_dtype = *column_info.dtype();
+        let suspicious = match header_dtype {
+            DType::Null | DType::String => false,
+            _ => column_dtype == DType::String || column_dtype == header_dtype,
+        };
+        suspicious.then(|| Self {
+            column_name: column_info.name.clone(),
+            column_index: column_info.index,
+            header_dtype,
+            column_dtype,
+        })
+    }
+
+    pub(crate) fn message(&self) -> String {
+        format!(
+            "header of column \"{name}\" (index {index}) is of type {header_dtype} while the column is of type {column_dtype}, the header row may be misidentified",
+            name = self.column_name,
+            index = self.column_index,
+            header_dtype = self.header_dtype,
+            column_dtype = self.column_dtype,
+        )
+    }
+}
+
+#[pymethods]
+impl HeaderWarning {
+    /// `fastexcel.DType`. The dtype of the header cell
+    #[getter(header_dtype)]
+    fn get_header_dtype(&self) -> String {
+        self.header_dtype.to_string()
+    }
+
+    /// `fastexcel.DType`. The dtype of the column
+    #[getter(column_dtype)]
+    fn get_column_dtype(&self) -> String {
+        self.column_dtype.to_string()
+    }
+
+    /// `str`. A human-readable description of the warning
+    #[getter(message)]
+    fn get_message(&self) -> String {
+        self.message()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "HeaderWarning(column_name=\"{column_name}\", column_index={column_index}, header_dtype=\"{header_dtype}\", column_dtype=\"{column_dtype}\")",
+            column_name = self.column_name,
+            column_index = self.column_index,
+            header_dtype = self.header_dtype,
+            column_dtype = self.column_dtype,
+        )
+    }
+}
+
+/// Compares the dtype of the header cell of every column with the column's dtype. Returns an
+/// empty list if the column names are not read from the data.
+pub(crate) fn validate_header<D: CalamineDataProvider>(
+    data: &D,
+    header: &Header,
+    columns: &[ColumnInfo],
+) -> Vec<HeaderWarning> {
+    let Some(header_row) = header.row() else {
+        return Vec::new();
+    };
+    columns
+        .iter()
+        .filter_map(|column_info| {
+            // A header cell for which no dtype can be determined (e.g. an error cell) is not
+            // considered suspicious
+            let header_dtype = data
+                .dtype_for_column(
+                    SampleRows::new(header_row, header_row + 1),
+                    column_info.index,
+                    &DTypeCoercion::Coerce,
+                )
+                .ok()?;
+            HeaderWarning::check(column_info, header_dtype)
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 pub(crate) struct ColumnInfoBuilder {
     name: String,
@@ -229,16 +422,16 @@ impl ColumnInfoBuilder {
         self
     }
 
-    pub(super) fn name(&self) -> &str {
+    pub(crate) fn name(&self) -> &str {
         &self.name
     }
 
     fn dtype_info<D: CalamineDataProvider>(
         &self,
         data: &D,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: SampleRows,
         specified_dtypes: Option<&DTypeMap>,
+        all_columns_dtype: Option<DType>,
         dtype_coercion: &DTypeCoercion,
     ) -> FastExcelResult<(DType, DTypeFrom)> {
         specified_dtypes
@@ -253,10 +446,12 @@ impl ColumnInfoBuilder {
                         .map(|dtype| (*dtype, DTypeFrom::ProvidedByName))
                 }
             })
+            // Dtypes specified per column take precedence over the one specified for all columns
+            .or_else(|| all_columns_dtype.map(|dtype| (dtype, DTypeFrom::ProvidedForAll)))
             .map(FastExcelResult::Ok)
             // If we could not look up a dtype, guess it from the data
             .unwrap_or_else(|| {
-                data.dtype_for_column(start_row, end_row, self.index, dtype_coercion)
+                data.dtype_for_column(sample_rows, self.index, dtype_coercion)
                     .map(|dtype| (dtype, DTypeFrom::Guessed))
             })
     }
@@ -264,14 +459,21 @@ impl ColumnInfoBuilder {
     pub(super) fn finish<D: CalamineDataProvider>(
         self,
         data: &D,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: SampleRows,
         specified_dtypes: Option<&DTypeMap>,
+        all_columns_dtype: Option<DType>,
         dtype_coercion: &DTypeCoercion,
     ) -> FastExcelResult<ColumnInfo> {
         let (dtype, dtype_from) = self
-            .dtype_info(data, start_row, end_row, specified_dtypes, dtype_coercion)
-            .with_context(|| format!("could not determine dtype for column {}", self.name))?;
+            .dtype_info(
+                data,
+                sample_rows,
+                specified_dtypes,
+                all_columns_dtype,
+                dtype_coercion,
+            )
+            .with_context(|| format!("could not determine dtype for column {}", self.name))
+            .with_cell_location(|location| location.column_name = Some(self.name.clone()))?;
         Ok(ColumnInfo::new(
             self.name,
             self.index,
@@ -287,11 +489,16 @@ pub(crate) trait CalamineDataProvider {
     fn get_as_string(&self, pos: (usize, usize)) -> Option<String>;
     fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: SampleRows,
         col: usize,
         dtype_coercion: &DTypeCoercion,
     ) -> FastExcelResult<DType>;
+    fn has_only_dates(
+        &self,
+        sample_rows: SampleRows,
+        col: usize,
+        read_options: &ReadOptions,
+    ) -> bool;
 }
 
 impl CalamineDataProvider for ExcelSheetData<'_> {
@@ -305,12 +512,20 @@ impl CalamineDataProvider for ExcelSheetData<'_> {
 
     fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: SampleRows,
         col: usize,
         dtype_coercion: &DTypeCoercion,
     ) -> FastExcelResult<DType> {
-        self.dtype_for_column(start_row, end_row, col, dtype_coercion)
+        self.dtype_for_column(sample_rows, col, dtype_coercion)
+    }
+
+    fn has_only_dates(
+        &self,
+        sample_rows: SampleRows,
+        col: usize,
+        read_options: &ReadOptions,
+    ) -> bool {
+        self.has_only_dates(sample_rows, col, read_options)
     }
 }
 
@@ -325,32 +540,42 @@ impl CalamineDataProvider for calamine::Range<calamine::Data> {
 
     fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: SampleRows,
         col: usize,
         dtype_coercion: &DTypeCoercion,
     ) -> FastExcelResult<DType> {
-        get_dtype_for_column(self, start_row, end_row, col, dtype_coercion)
+        get_dtype_for_column(self, sample_rows, col, dtype_coercion)
+    }
+
+    fn has_only_dates(
+        &self,
+        sample_rows: SampleRows,
+        col: usize,
+        read_options: &ReadOptions,
+    ) -> bool {
+        has_only_dates(self, sample_rows, col, read_options)
     }
 }
 
+/// Builds the columns of the header. Columns without a name are named `{column_prefix}{index}`
 pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
     data: &D,
     selected_columns: &SelectedColumns,
     header: &Header,
+    column_prefix: &str,
 ) -> FastExcelResult<Vec<ColumnInfoBuilder>> {
     let width = data.width();
     match header {
         Header::None => Ok((0..width)
             .map(|col_idx| {
                 ColumnInfoBuilder::new(
-                    format!("__UNNAMED__{col_idx}"),
+                    format!("{column_prefix}{col_idx}"),
                     col_idx,
                     ColumnNameFrom::Generated,
                 )
             })
             .collect()),
-        Header::At(row_idx) => Ok((0..width)
+        Header::At(row_idx) | Header::InData(row_idx) => Ok((0..width)
             .map(|col_idx| {
                 data.get_as_string((*row_idx, col_idx))
                     .map(|col_name| {
@@ -358,13 +583,55 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
                     })
                     .unwrap_or_else(|| {
                         ColumnInfoBuilder::new(
-                            format!("__UNNAMED__{col_idx}"),
+                            format!("{column_prefix}{col_idx}"),
                             col_idx,
                             ColumnNameFrom::Generated,
                         )
                     })
             })
             .collect()),
+        Header::MultiRow {
+            row,
+            depth,
+            separator,
+        } => {
+            // Labels of the upper header rows are forward-filled from the left, so that a merged
+            // group header applies to all the columns below it
+            let mut group_labels: Vec<Option<String>> = vec![None; depth - 1];
+            Ok((0..width)
+                .map(|col_idx| {
+                    let mut labels = Vec::with_capacity(*depth);
+                    for level in 0..*depth {
+                        let label = data
+                            .get_as_string((row + level, col_idx))
+                            .filter(|label| !label.is_empty());
+                        if level + 1 == *depth {
+                            labels.extend(label);
+                            continue;
+                        }
+                        if label.is_some() {
+                            group_labels[level] = label;
+                            // A new group resets the groups nested in the previous one
+                            group_labels[level + 1..].fill(None);
+                        }
+                        labels.extend(group_labels[level].clone());
+                    }
+                    if labels.is_empty() {
+                        ColumnInfoBuilder::new(
+                            format!("{column_prefix}{col_idx}"),
+                            col_idx,
+                            ColumnNameFrom::Generated,
+                        )
+                    } else {
+                        ColumnInfoBuilder::new(
+                            labels.join(separator),
+                            col_idx,
+                            ColumnNameFrom::LookedUp,
+                        )
+                    }
+                })
+                .collect())
+        }
         Header::With(names) => {
             if let SelectedColumns::Selection(column_selection) = selected_columns {
                 if column_selection.len() != names.len() {
@@ -403,7 +670,7 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
                                 ColumnNameFrom::Provided,
                             ),
                             None => ColumnInfoBuilder::new(
-                                format!("__UNNAMED__{col_idx}"),
+                                format!("{column_prefix}{col_idx}"),
                                 col_idx,
                                 ColumnNameFrom::Generated,
                             ),
@@ -420,7 +687,7 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
                     })
                     .chain((nameless_start_idx..width).map(|col_idx| {
                         ColumnInfoBuilder::new(
-                            format!("__UNNAMED__{col_idx}"),
+                            format!("{column_prefix}{col_idx}"),
                             col_idx,
                             ColumnNameFrom::Generated,
                         )
@@ -431,47 +698,200 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
     }
 }
 
-fn alias_for_name(name: &str, existing_names: &[String]) -> String {
-    #[inline]
-    fn rec(name: &str, existing_names: &[String], depth: usize) -> String {
-        let alias = if depth == 0 {
-            name.to_owned()
-        } else {
-            format!("{name}_{depth}")
-        };
-        match existing_names
+/// The column names in use, used to give every column a unique name
+#[derive(Debug)]
+pub(crate) struct ColumnAliases {
+    used_names: HashSet<String>,
+    /// The next suffix to try for a name, so that huge header rows made of the same name do not
+    /// retry every suffix already in use
+    next_suffixes: HashMap<String, usize>,
+    /// The separator between a name and its suffix
+    separator: char,
+}
+
+impl Default for ColumnAliases {
+    fn default() -> Self {
+        Self::with_separator('_')
+    }
+}
+
+impl ColumnAliases {
+    pub(crate) fn with_separator(separator: char) -> Self {
+        Self {
+            used_names: HashSet::new(),
+            next_suffixes: HashMap::new(),
+            separator,
+        }
+    }
+
+    /// Aliases names that must not collide with `used_names`
+    pub(crate) fn with_used_names(used_names: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            used_names: used_names.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns `name` if it is not in use yet, and `{name}{separator}{n}` with the smallest
+    /// available `n` otherwise
+    pub(crate) fn alias_for_name(&mut self, name: &str) -> String {
+        let mut suffix = self.next_suffixes.get(name).copied().unwrap_or(0);
+        loop {
+            let alias = if suffix == 0 {
+                name.to_owned()
+            } else {
+                format!("{name}{}{suffix}", self.separator)
+            };
+            suffix += 1;
+            if self.used_names.insert(alias.clone()) {
+                self.next_suffixes.insert(name.to_owned(), suffix);
+                return alias;
+            }
+        }
+    }
+}
+
+/// Sets the right alias for every column, so that column names are unique. Duplicate names are
+/// an error if `duplicate_columns` is `DuplicateColumns::Error`
+pub(crate) fn alias_available_columns(
+    available_columns_info: Vec<ColumnInfoBuilder>,
+    duplicate_columns: DuplicateColumns,
+) -> FastExcelResult<Vec<ColumnInfoBuilder>> {
+    let Some(separator) = duplicate_columns.separator() else {
+        let mut used_names = HashSet::with_capacity(available_columns_info.len());
+        if let Some(duplicate) = available_columns_info
             .iter()
-            .any(|existing_name| existing_name == &alias)
+            .find(|column_info_builder| !used_names.insert(column_info_builder.name()))
         {
-            true => rec(name, existing_names, depth + 1),
-            false => alias,
+            return Err(FastExcelErrorKind::DuplicateColumn(duplicate.name().to_owned()).into());
+        }
+        return Ok(available_columns_info);
+    };
+    let mut aliases = ColumnAliases::with_separator(separator);
+
+    Ok(available_columns_info
+        .into_iter()
+        .map(|column_info_builder| {
+            let alias = aliases.alias_for_name(column_info_builder.name());
+            if alias != column_info_builder.name() {
+                column_info_builder.with_name(alias)
+            } else {
+                column_info_builder
+            }
+        })
+        .collect())
+}
+
+/// Renames columns according to `read_options.rename`. Columns to rename that are not available
+/// are ignored, or an error depending on `read_options.unknown_renames`
+pub(crate) fn rename_available_columns(
+    available_columns_info: Vec<ColumnInfoBuilder>,
+    read_options: &ReadOptions,
+) -> FastExcelResult<Vec<ColumnInfoBuilder>> {
+    if read_options.rename.is_empty() {
+        return Ok(available_columns_info);
+    }
+    if read_options.unknown_renames == UnknownRenames::Error {
+        if let Some(unknown_name) = read_options.rename.keys().find(|name| {
+            !available_columns_info
+                .iter()
+                .any(|column_info_builder| column_info_builder.name() == name.as_str())
+        }) {
+            return Err(FastExcelErrorKind::ColumnNotFound(IdxOrName::Name(
+                unknown_name.to_owned(),
+            ))
+            .into())
+            .with_context(|| {
+                let available_columns = available_columns_info
+                    .iter()
+                    .map(|column_info_builder| format!("\"{}\"", column_info_builder.name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("cannot rename unknown column, available columns are: {available_columns}")
+            });
         }
     }
 
-    rec(name, existing_names, 0)
+    Ok(available_columns_info
+        .into_iter()
+        .map(
+            |column_info_builder| match read_options.rename.get(column_info_builder.name()) {
+                Some(new_name) => column_info_builder.with_name(new_name.to_owned()),
+                None => column_info_builder,
+            },
+        )
+        .collect())
 }
 
 pub(crate) fn build_available_columns<D: CalamineDataProvider>(
     available_columns_info: Vec<ColumnInfoBuilder>,
     data: &D,
-    start_row: usize,
-    end_row: usize,
+    sample_rows: SampleRows,
     specified_dtypes: Option<&DTypeMap>,
     dtype_coercion: &DTypeCoercion,
+    read_options: &ReadOptions,
 ) -> FastExcelResult<Vec<ColumnInfo>> {
-    let mut aliased_available_columns = Vec::with_capacity(available_columns_info.len());
+    alias_available_columns(
+        rename_available_columns(available_columns_info, read_options)?,
+        read_options.duplicate_columns,
+    )?
+    .into_iter()
+    .map(|column_info_builder| {
+        // Setting the dtype info
+        column_info_builder
+            .finish(
+                data,
+                sample_rows,
+                specified_dtypes,
+                read_options.all_columns_dtype,
+                dtype_coercion,
+            )
+            .map(|column_info| {
+                if read_options.infer_dates
+                    && column_info.is_guessed_datetime()
+                    && data.has_only_dates(sample_rows, column_info.index(), read_options)
+                {
+                    column_info.into_date()
+                } else {
+                    column_info
+                }
+            })
+            .map(|column_info| match read_options.timezone {
+                Some(_) => column_info.into_timezone_aware(),
+                None => column_info,
+            })
+    })
+    .collect::<FastExcelResult<Vec<_>>>()
+    .and_then(|available_columns| {
+        if !read_options.ignore_unknown_dtypes {
+            check_specified_dtypes(&available_columns, specified_dtypes)?;
+        }
+        Ok(available_columns)
+    })
+}
 
-    available_columns_info
-        .into_iter()
-        .map(|mut column_info_builder| {
-            // Setting the right alias for every column
-            let alias = alias_for_name(column_info_builder.name(), &aliased_available_columns);
-            if alias != column_info_builder.name() {
-                column_info_builder = column_info_builder.with_name(alias.clone());
-            }
-            aliased_available_columns.push(alias);
-            // Setting the dtype info
-            column_info_builder.finish(data, start_row, end_row, specified_dtypes, dtype_coercion)
-        })
-        .collect()
+/// Ensures that every column for which a dtype was specified exists
+fn check_specified_dtypes(
+    available_columns: &[ColumnInfo],
+    specified_dtypes: Option<&DTypeMap>,
+) -> FastExcelResult<()> {
+    let Some(specified_dtypes) = specified_dtypes else {
+        return Ok(());
+    };
+    match specified_dtypes.keys().find(|idx_or_name| {
+        !available_columns
+            .iter()
+            .any(|column_info| column_info == *idx_or_name)
+    }) {
+        None => Ok(()),
+        Some(idx_or_name) => Err(FastExcelErrorKind::UnknownColumn(idx_or_name.clone()).into())
+            .with_context(|| {
+                let available_columns = available_columns
+                    .iter()
+                    .map(|column_info| format!("\"{}\"", column_info.name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("available columns are: {available_columns}")
+            }),
+    }
 }
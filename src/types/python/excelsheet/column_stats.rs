@@ -0,0 +1,328 @@
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use calamine::{CellType, DataType, Range};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use pyo3::{pyclass, pymethods, PyObject, Python, ToPyObject};
+
+use crate::{
+    data::{
+        cell_values::{
+            cell_to_bool, cell_to_bool_from_int, cell_to_date, cell_to_datetime,
+            cell_to_excel_serial, cell_to_float, cell_to_int, cell_to_string, cell_to_utc_datetime,
+            days_since_epoch, timestamp_since_epoch,
+        },
+        ExcelSheetData,
+    },
+    types::{dtype::DType, read_options::ReadOptions},
+};
+
+use super::column_info::ColumnInfo;
+
+/// A value of a numeric or temporal column. Values of a column all have the same variant
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum StatValue {
+    Int(i64),
+    Float(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    DateTimeTz(DateTime<Utc>),
+    Duration(Duration),
+}
+
+impl ToPyObject for StatValue {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            StatValue::Int(value) => value.to_object(py),
+            StatValue::Float(value) => value.to_object(py),
+            StatValue::Date(value) => value.to_object(py),
+            StatValue::DateTime(value) => value.to_object(py),
+            StatValue::DateTimeTz(value) => value.to_object(py),
+            StatValue::Duration(value) => value.to_object(py),
+        }
+    }
+}
+
+/// What a non-null cell contributes to the statistics of its column
+enum CellStat {
+    /// The value of a numeric or temporal cell
+    Ordered(StatValue),
+    /// The hash of a string cell
+    Hashed(u64),
+    /// A cell of a column without statistics besides counts, e.g. a boolean one
+    Counted,
+}
+
+/// Statistics of a selected column, computed by scanning its cells without converting them to
+/// arrow
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(name = "ColumnStats")]
+pub(crate) struct ColumnStats {
+    /// `str`. The name of the column
+    #[pyo3(get)]
+    column_name: String,
+    /// `int`. The index of the column
+    #[pyo3(get)]
+    column_index: usize,
+    dtype: DType,
+    /// `int`. The number of cells that are read as a value
+    #[pyo3(get)]
+    count: usize,
+    /// `int`. The number of cells that are read as null
+    #[pyo3(get)]
+    null_count: usize,
+    min: Option<StatValue>,
+    max: Option<StatValue>,
+    /// `int | None`. The number of distinct values of a string column
+    #[pyo3(get)]
+    distinct_estimate: Option<usize>,
+}
+
+impl ColumnStats {
+    fn new(column_info: &ColumnInfo) -> Self {
+        Self {
+            column_name: column_info.name().to_owned(),
+            column_index: column_info.index(),
+            dtype: *column_info.dtype(),
+            count: 0,
+            null_count: 0,
+            min: None,
+            max: None,
+            distinct_estimate: None,
+        }
+    }
+
+    fn update(&mut self, value: StatValue) {
+        if self.min.is_none_or(|min| value < min) {
+            self.min = Some(value);
+        }
+        if self.max.is_none_or(|max| value > max) {
+            self.max = Some(value);
+        }
+    }
+}
+
+#[pymethods]
+impl ColumnStats {
+    /// `fastexcel.DType`. The dtype of the column
+    #[getter(dtype)]
+    fn get_dtype(&self) -> String {
+        self.dtype.to_string()
+    }
+
+    /// The smallest value of a numeric or temporal column
+    #[getter(min)]
+    fn get_min(&self, py: Python<'_>) -> PyObject {
+        self.min.to_object(py)
+    }
+
+    /// The largest value of a numeric or temporal column
+    #[getter(max)]
+    fn get_max(&self, py: Python<'_>) -> PyObject {
+        self.max.to_object(py)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ColumnStats(column_name=\"{column_name}\", column_index={column_index}, dtype=\"{dtype}\", count={count}, null_count={null_count})",
+            column_name = self.column_name,
+            column_index = self.column_index,
+            dtype = self.dtype,
+            count = self.count,
+            null_count = self.null_count,
+        )
+    }
+}
+
+/// Computes the statistics of `columns` over the rows from `offset` to `limit`, in a single scan
+/// of their cells
+pub(crate) fn column_stats(
+    data: &ExcelSheetData,
+    columns: &[ColumnInfo],
+    offset: usize,
+    limit: usize,
+    read_options: &ReadOptions,
+) -> Vec<ColumnStats> {
+    columns
+        .iter()
+        .map(|column_info| match data {
+            ExcelSheetData::Owned(range) => {
+                scan_column(range, column_info, offset, limit, read_options)
+            }
+            ExcelSheetData::Ref(range) => {
+                scan_column(range, column_info, offset, limit, read_options)
+            }
+        })
+        .collect()
+}
+
+/// Whether any cell of the column from `offset` to `limit` is read as null
+pub(crate) fn has_null_cells<DT: CellType + DataType>(
+    data: &Range<DT>,
+    column_info: &ColumnInfo,
+    offset: usize,
+    limit: usize,
+    read_options: &ReadOptions,
+) -> bool {
+    (offset..limit).any(|row| {
+        data.get((row, column_info.index()))
+            .filter(|cell| !cell.is_empty())
+            .and_then(|cell| cell_stat(cell, column_info.dtype(), read_options))
+            .is_none()
+    })
+}
+
+fn scan_column<DT: CellType + Debug + DataType>(
+    data: &Range<DT>,
+    column_info: &ColumnInfo,
+    offset: usize,
+    limit: usize,
+    read_options: &ReadOptions,
+) -> ColumnStats {
+    let mut stats = ColumnStats::new(column_info);
+    // Hashing the strings is enough to tell them apart, without keeping them around
+    let mut hashes = HashSet::new();
+    for row in offset..limit {
+        let cell_stat = data
+            .get((row, column_info.index()))
+            .filter(|cell| !cell.is_empty())
+            .and_then(|cell| cell_stat(cell, column_info.dtype(), read_options));
+        match cell_stat {
+            None => stats.null_count += 1,
+            Some(cell_stat) => {
+                stats.count += 1;
+                match cell_stat {
+                    CellStat::Ordered(value) => stats.update(value),
+                    CellStat::Hashed(hash) => {
+                        hashes.insert(hash);
+                    }
+                    CellStat::Counted => {}
+                }
+            }
+        }
+    }
+    if column_info.dtype() == &DType::String {
+        stats.distinct_estimate = Some(hashes.len());
+    }
+    stats
+}
+
+/// Converts a cell as it would be read for `dtype`. Returns `None` if it would be read as null
+fn cell_stat<DT: CellType + DataType>(
+    cell: &DT,
+    dtype: &DType,
+    read_options: &ReadOptions,
+) -> Option<CellStat> {
+    match dtype {
+        DType::Null => None,
+        DType::Int => cell_to_int(cell, read_options)
+            .map(StatValue::Int)
+            .map(CellStat::Ordered),
+        DType::Float => cell_to_float(cell, read_options)
+            .map(StatValue::Float)
+            .map(CellStat::Ordered),
+        DType::ExcelSerial => cell_to_excel_serial(cell)
+            .map(StatValue::Float)
+            .map(CellStat::Ordered),
+        // Dates and datetimes too far from the epoch are read as null, as in their arrays
+        DType::Date => cell_to_date(cell, read_options)
+            .filter(|date| days_since_epoch(*date, read_options).is_some())
+            .map(StatValue::Date)
+            .map(CellStat::Ordered),
+        DType::DateTime => cell_to_datetime(cell, read_options)
+            .filter(|dt| timestamp_since_epoch(*dt, read_options).is_some())
+            .map(StatValue::DateTime)
+            .map(CellStat::Ordered),
+        DType::DateTimeTz => cell_to_utc_datetime(cell, read_options)
+            .filter(|dt| timestamp_since_epoch(*dt, read_options).is_some())
+            .map(|dt| StatValue::DateTimeTz(dt.and_utc()))
+            .map(CellStat::Ordered),
+        DType::Duration => cell
+            .as_duration()
+            .map(StatValue::Duration)
+            .map(CellStat::Ordered),
+        DType::String => cell_to_string(cell, read_options).map(|value| {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            CellStat::Hashed(hasher.finish())
+        }),
+        DType::Bool => cell_to_bool(cell, read_options).map(|_| CellStat::Counted),
+        DType::BoolFromInt => cell_to_bool_from_int(cell).map(|_| CellStat::Counted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{Cell, Data as CalData};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::python::excelsheet::column_info::{ColumnNameFrom, DTypeFrom};
+
+    fn column(index: usize, dtype: DType) -> ColumnInfo {
+        ColumnInfo::new(
+            format!("col{index}"),
+            index,
+            ColumnNameFrom::LookedUp,
+            dtype,
+            DTypeFrom::ProvidedByIndex,
+        )
+    }
+
+    #[test]
+    fn stats_of_numeric_and_string_columns() {
+        let data = ExcelSheetData::from(Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(3.5)),
+            Cell::new((0, 1), CalData::String("a".to_owned())),
+            Cell::new((1, 0), CalData::String("nope".to_owned())),
+            Cell::new((1, 1), CalData::String("b".to_owned())),
+            Cell::new((2, 0), CalData::Int(-2)),
+            Cell::new((2, 1), CalData::String("a".to_owned())),
+            Cell::new((3, 0), CalData::Float(10.0)),
+        ]));
+        let columns = [column(0, DType::Float), column(1, DType::String)];
+
+        let stats = column_stats(&data, &columns, 0, 4, &ReadOptions::default());
+
+        assert_eq!(
+            (stats[0].count, stats[0].null_count),
+            (3, 1),
+            "the string cell is read as null"
+        );
+        assert_eq!(
+            (stats[0].min, stats[0].max),
+            (Some(StatValue::Float(-2.0)), Some(StatValue::Float(10.0)))
+        );
+        assert_eq!(stats[0].distinct_estimate, None);
+
+        assert_eq!((stats[1].count, stats[1].null_count), (3, 1));
+        assert_eq!((stats[1].min, stats[1].max), (None, None));
+        assert_eq!(stats[1].distinct_estimate, Some(2));
+    }
+
+    #[test]
+    fn stats_skip_rows_outside_of_the_limits() {
+        let data = ExcelSheetData::from(Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Int(100)),
+            Cell::new((1, 0), CalData::Int(1)),
+            Cell::new((2, 0), CalData::Int(2)),
+        ]));
+
+        let stats = column_stats(
+            &data,
+            &[column(0, DType::Int)],
+            1,
+            3,
+            &ReadOptions::default(),
+        );
+
+        assert_eq!((stats[0].count, stats[0].null_count), (2, 0));
+        assert_eq!(
+            (stats[0].min, stats[0].max),
+            (Some(StatValue::Int(1)), Some(StatValue::Int(2)))
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use calamine::{Reader, Sheets};
+use std::io::{Read, Seek};
+
+use crate::error::{ErrorContext, FastExcelErrorKind, FastExcelResult};
+
+use super::cell_range::CellRange;
+
+/// The name and the reference (e.g. "Sheet1!$A$1:$D$50") of every defined name of a workbook
+pub(crate) fn extract_defined_names<RS: Read + Seek>(sheets: &Sheets<RS>) -> Vec<(String, String)> {
+    sheets.defined_names().to_vec()
+}
+
+/// Resolves a defined name to the sheet and the cells it refers to
+pub(crate) fn extract_defined_name_range<RS: Read + Seek>(
+    name: &str,
+    sheets: &Sheets<RS>,
+) -> FastExcelResult<(String, CellRange)> {
+    let Some((_, reference)) = sheets
+        .defined_names()
+        .iter()
+        .find(|(defined_name, _)| defined_name == name)
+    else {
+        let available_names = sheets
+            .defined_names()
+            .iter()
+            .map(|(defined_name, _)| format!("\"{defined_name}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(FastExcelErrorKind::InvalidParameters(format!(
+            "defined name \"{name}\" not found, available defined names are: {available_names}"
+        ))
+        .into());
+    };
+    parse_reference(reference)
+        .with_context(|| format!("cannot load defined name \"{name}\" = \"{reference}\""))
+}
+
+/// Parses the reference of a defined name, either Excel-style ("Sheet1!$A$1:$D$50") or
+/// OpenDocument-style ("$Sheet1.$A$1:.$D$50")
+fn parse_reference(reference: &str) -> FastExcelResult<(String, CellRange)> {
+    let reference = reference.trim().trim_start_matches('=');
+    let invalid_reference = |detail: &str| {
+        Err(
+            FastExcelErrorKind::InvalidParameters(format!("reference \"{reference}\" {detail}"))
+                .into(),
+        )
+    };
+    if reference.contains("#REF!") {
+        return invalid_reference("refers to deleted cells");
+    }
+    if reference.contains(',') || reference.contains(';') {
+        return invalid_reference("refers to several ranges of cells");
+    }
+    let Some((sheet_name, cells)) = split_sheet_name(reference) else {
+        return invalid_reference("does not refer to cells of a sheet");
+    };
+    if sheet_name.starts_with('[') {
+        return invalid_reference("refers to another workbook");
+    }
+    // Sheet names cannot contain colons, which denote 3D references such as "Sheet1:Sheet3!A1"
+    if sheet_name.contains(':') {
+        return invalid_reference("refers to several sheets");
+    }
+    let cells: Vec<&str> = cells
+        .split(':')
+        // OpenDocument references repeat the sheet name before the end cell
+        .map(|cell| cell.rsplit_once('.').map_or(cell, |(_, cell)| cell))
+        .collect();
+    let cell_range = match cells.as_slice() {
+        [cell] => format!("{cell}:{cell}"),
+        [start, end] => format!("{start}:{end}"),
+        _ => return invalid_reference("is not a range of cells"),
+    };
+    Ok((sheet_name, cell_range.replace('$', "").parse()?))
+}
+
+/// Splits a reference into its unquoted sheet name and its cells
+fn split_sheet_name(reference: &str) -> Option<(String, &str)> {
+    let (sheet_name, cells) = match reference.rsplit_once('!') {
+        Some(parts) => parts,
+        None => {
+            let reference = reference.strip_prefix('$')?;
+            // The sheet name of OpenDocument references ends at the first dot outside quotes
+            let sheet_name_end = if let Some(quoted) = reference.strip_prefix('\'') {
+                quoted.find("'.").map(|idx| idx + 2)?
+            } else {
+                reference.find('.')?
+            };
+            (
+                &reference[..sheet_name_end],
+                &reference[sheet_name_end + 1..],
+            )
+        }
+    };
+    let sheet_name = match sheet_name
+        .strip_prefix('\'')
+        .and_then(|name| name.strip_suffix('\''))
+    {
+        Some(quoted_name) => quoted_name.replace("''", "'"),
+        None => sheet_name.to_owned(),
+    };
+    Some((sheet_name, cells))
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::open_workbook_auto;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Sheet1!$A$1:$D$50", "Sheet1", "A1:D50")]
+    #[case("=Sheet1!B3", "Sheet1", "B3:B3")]
+    #[case("'My ''data'''!$B$2:$C$4", "My 'data'", "B2:C4")]
+    #[case("$Sheet1.$A$1:.$D$50", "Sheet1", "A1:D50")]
+    #[case("$'My data'.$B$2:$'My data'.$C$4", "My data", "B2:C4")]
+    fn valid_references(
+        #[case] reference: &str,
+        #[case] sheet_name: &str,
+        #[case] cell_range: &str,
+    ) {
+        assert_eq!(
+            parse_reference(reference).unwrap(),
+            (sheet_name.to_owned(), cell_range.parse().unwrap())
+        );
+    }
+
+    #[rstest]
+    #[case("Sheet1:Sheet3!$A$1:$B$2", "refers to several sheets")]
+    #[case("[1]Sheet1!$A$1", "refers to another workbook")]
+    #[case("'[data.xlsx]Sheet1'!$A$1", "refers to another workbook")]
+    #[case("Sheet1!$A$1,Sheet1!$C$1", "refers to several ranges of cells")]
+    #[case("#REF!$A$1", "refers to deleted cells")]
+    #[case("0.05", "does not refer to cells of a sheet")]
+    #[case("Sheet1!$A:$A", "invalid cell reference: \"A\"")]
+    fn invalid_references(#[case] reference: &str, #[case] expected_message: &str) {
+        let err = parse_reference(reference).unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+        assert!(
+            err.to_string().contains(expected_message),
+            "unexpected message: {err}"
+        );
+    }
+
+    #[test]
+    fn defined_names_of_a_workbook() {
+        let sheets =
+            open_workbook_auto("python/tests/fixtures/fixture-defined-names.xlsx").unwrap();
+
+        assert_eq!(
+            extract_defined_names(&sheets)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            vec!["External", "Rate", "SalesData", "ThreeD"]
+        );
+        assert_eq!(
+            extract_defined_name_range("SalesData", &sheets).unwrap(),
+            ("Sheet1".to_owned(), "B3:C6".parse().unwrap())
+        );
+
+        let err = extract_defined_name_range("ThreeD", &sheets).unwrap_err();
+        assert!(err.to_string().contains("refers to several sheets"));
+        let err = extract_defined_name_range("Missing", &sheets).unwrap_err();
+        assert!(err.to_string().contains(
+            "available defined names are: \"External\", \"Rate\", \"SalesData\", \"ThreeD\""
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+use std::{str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{ArrayRef, StringArray},
+    datatypes::{DataType as ArrowDataType, Field, FieldRef, Schema},
+    record_batch::RecordBatch,
+};
+use calamine::Range;
+use pyo3::{prelude::PyAnyMethods, types::PyString, Bound, FromPyObject, PyAny, PyResult};
+
+use crate::error::{py_errors::IntoPyResult, FastExcelError, FastExcelErrorKind, FastExcelResult};
+
+use super::column_info::{ColumnAliases, ColumnInfo};
+
+/// Suffix of the names of the columns containing the formulas of another column
+const FORMULA_COLUMN_SUFFIX: &str = "__formula";
+
+/// What is read from the cells of a sheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum CellsMode {
+    /// The computed value of the cells
+    #[default]
+    Values,
+    /// The computed value of the cells, and their formula in a separate column
+    ValueAndFormula,
+}
+
+impl FromStr for CellsMode {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        match raw_value {
+            "values" => Ok(Self::Values),
+            "value_and_formula" => Ok(Self::ValueAndFormula),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported cells: \"{raw_value}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for CellsMode {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// The formulas of a sheet, read next to the values of its selected columns
+pub(crate) struct SheetFormulas {
+    /// The formulas of the sheet, by absolute position. Cells without a formula are empty strings
+    formulas: Range<String>,
+    /// The absolute position of the first cell of the sheet's data
+    data_start: (u32, u32),
+    /// The name of the formula column of every selected column
+    names: Vec<String>,
+}
+
+impl SheetFormulas {
+    pub(crate) fn new(
+        formulas: Range<String>,
+        data_start: (u32, u32),
+        selected_columns: &[ColumnInfo],
+        available_columns: &[ColumnInfo],
+    ) -> Self {
+        Self {
+            formulas,
+            data_start,
+            names: formula_column_names(selected_columns, available_columns),
+        }
+    }
+
+    /// Adds the field of the formula column of every field after it
+    pub(crate) fn interleave_fields(&self, schema: &Schema) -> Schema {
+        let fields: Vec<FieldRef> = schema
+            .fields()
+            .iter()
+            .zip(&self.names)
+            .flat_map(|(field, name)| [Arc::clone(field), formula_field(name)])
+            .collect();
+        Schema::new_with_metadata(fields, schema.metadata().clone())
+    }
+
+    /// Adds the formula column of every column of `rb` after it. `rb` must contain
+    /// `selected_columns`, read from `offset` to `limit`
+    pub(crate) fn interleave_columns(
+        &self,
+        rb: RecordBatch,
+        selected_columns: &[ColumnInfo],
+        offset: usize,
+        limit: usize,
+    ) -> FastExcelResult<RecordBatch> {
+        let columns: Vec<ArrayRef> = rb
+            .columns()
+            .iter()
+            .zip(selected_columns)
+            .flat_map(|(array, column_info)| {
+                [
+                    Arc::clone(array),
+                    self.formula_array(column_info.index(), offset, limit),
+                ]
+            })
+            .collect();
+        RecordBatch::try_new(Arc::new(self.interleave_fields(rb.schema_ref())), columns)
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+    }
+
+    /// The name of the formula column of every selected column, in the same order
+    pub(crate) fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The formula of a cell of the data, `None` if it has none
+    pub(crate) fn formula(&self, row: usize, col: usize) -> Option<&str> {
+        let (start_row, start_col) = self.data_start;
+        self.formulas
+            .get_value((start_row + row as u32, start_col + col as u32))
+            .filter(|formula| !formula.is_empty())
+            .map(String::as_str)
+    }
+
+    fn formula_array(&self, col: usize, offset: usize, limit: usize) -> ArrayRef {
+        Arc::new(StringArray::from_iter(
+            (offset..limit).map(|row| self.formula(row, col)),
+        ))
+    }
+}
+
+fn formula_field(name: &str) -> FieldRef {
+    Arc::new(Field::new(name, ArrowDataType::Utf8, true))
+}
+
+/// Names the formula column of every selected column `<name>__formula`, aliased like duplicate
+/// column names if a column already has this name
+fn formula_column_names(
+    selected_columns: &[ColumnInfo],
+    available_columns: &[ColumnInfo],
+) -> Vec<String> {
+    let mut aliases = ColumnAliases::with_used_names(
+        available_columns
+            .iter()
+            .chain(selected_columns)
+            .map(|column_info| column_info.name().to_owned()),
+    );
+    selected_columns
+        .iter()
+        .map(|column_info| {
+            aliases.alias_for_name(&format!("{}{FORMULA_COLUMN_SUFFIX}", column_info.name()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, Float64Array};
+    use calamine::{open_workbook_auto, Cell, Reader};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::{
+        dtype::DType,
+        python::excelsheet::column_info::{ColumnNameFrom, DTypeFrom},
+    };
+
+    fn column(name: &str, index: usize) -> ColumnInfo {
+        ColumnInfo::new(
+            name.to_owned(),
+            index,
+            ColumnNameFrom::LookedUp,
+            DType::Float,
+            DTypeFrom::Guessed,
+        )
+    }
+
+    #[test]
+    fn formula_names_do_not_collide_with_columns() {
+        let available_columns = [column("a", 0), column("a__formula", 1), column("b", 2)];
+        let selected_columns = [column("a", 0), column("b", 2)];
+
+        assert_eq!(
+            formula_column_names(&selected_columns, &available_columns),
+            ["a__formula_1", "b__formula"]
+        );
+    }
+
+    #[test]
+    fn formulas_of_a_workbook() {
+        let mut workbook =
+            open_workbook_auto("python/tests/fixtures/fixture-formulas.xlsx").unwrap();
+        let formulas = workbook.worksheet_formula("Sheet1").unwrap();
+        let selected_columns = [column("price", 0), column("total", 1)];
+        let sheet_formulas = SheetFormulas::new(formulas, (0, 0), &selected_columns, &[]);
+
+        assert_eq!(
+            sheet_formulas.formula_array(1, 1, 4).as_ref(),
+            &StringArray::from(vec![Some("A2*2"), None, Some("A4*2")]) as &dyn Array
+        );
+        assert_eq!(
+            sheet_formulas.formula_array(0, 1, 4).as_ref(),
+            &StringArray::from(vec![None, None, Some("SUM(A2:A3)")]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn formulas_are_read_next_to_their_column() {
+        // The data starts at B2, below its header
+        let formulas = Range::from_sparse(vec![
+            Cell::new((2, 1), "A3*2".to_owned()),
+            Cell::new((4, 1), "SUM(A1:A4)".to_owned()),
+        ]);
+        let selected_columns = [column("value", 0)];
+        let sheet_formulas = SheetFormulas::new(formulas, (1, 1), &selected_columns, &[]);
+        let rb = RecordBatch::try_from_iter([(
+            "value",
+            Arc::new(Float64Array::from(vec![2.0, 3.0, 10.0])) as ArrayRef,
+        )])
+        .unwrap();
+
+        let rb = sheet_formulas
+            .interleave_columns(rb, &selected_columns, 1, 4)
+            .unwrap();
+
+        assert_eq!(rb.schema().field(1).name(), "value__formula");
+        assert_eq!(
+            rb.column(1).as_ref(),
+            &StringArray::from(vec![Some("A3*2"), None, Some("SUM(A1:A4)")]) as &dyn Array
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use std::cmp;
+
+use calamine::{CellType, Dimensions, Range};
+
+/// Copies the value of the top-left cell of every merged region to the other cells of the region,
+/// as they are blank in the sheet. Regions are clipped to the bounds of `range`
+pub(crate) fn fill_merged_regions<CT: CellType>(range: &mut Range<CT>, regions: &[Dimensions]) {
+    let (Some((start_row, start_col)), Some((end_row, end_col))) = (range.start(), range.end())
+    else {
+        return;
+    };
+    for region in regions {
+        let Some(value) = range
+            .get_value(region.start)
+            .filter(|value| *value != &CT::default())
+            .cloned()
+        else {
+            continue;
+        };
+        let rows = cmp::max(region.start.0, start_row)..=cmp::min(region.end.0, end_row);
+        let cols = cmp::max(region.start.1, start_col)..=cmp::min(region.end.1, end_col);
+        for row in rows {
+            for col in cols.clone() {
+                if (row, col) != region.start {
+                    range.set_value((row, col), value.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{open_workbook_auto, Cell, Data as CalData, Reader, Sheets};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn merged_regions_are_filled_with_their_top_left_value() {
+        // The range starts at A2. "a" is merged over A2:A4, "b" over B2:C2 and the empty A5 over
+        // A5:B5. The region of "c" ends beyond the range
+        let mut range = Range::from_sparse(vec![
+            Cell::new((1, 0), CalData::String("a".to_owned())),
+            Cell::new((1, 1), CalData::String("b".to_owned())),
+            Cell::new((3, 2), CalData::Float(1.0)),
+            Cell::new((4, 2), CalData::String("c".to_owned())),
+        ]);
+        let regions = [
+            Dimensions::new((1, 0), (3, 0)),
+            Dimensions::new((1, 1), (1, 2)),
+            Dimensions::new((4, 0), (4, 1)),
+            Dimensions::new((4, 2), (9, 2)),
+        ];
+
+        fill_merged_regions(&mut range, &regions);
+
+        assert_eq!((range.start(), range.end()), (Some((1, 0)), Some((4, 2))));
+        let string = |value: &str| CalData::String(value.to_owned());
+        assert_eq!(
+            range.rows().map(<[CalData]>::to_vec).collect::<Vec<_>>(),
+            vec![
+                vec![string("a"), string("b"), string("b")],
+                vec![string("a"), CalData::Empty, CalData::Empty],
+                vec![string("a"), CalData::Empty, CalData::Float(1.0)],
+                vec![CalData::Empty, CalData::Empty, string("c")],
+            ]
+        );
+    }
+
+    #[test]
+    fn merged_regions_of_a_workbook() {
+        let Sheets::Xlsx(mut workbook) =
+            open_workbook_auto("python/tests/fixtures/fixture-merged-cells.xlsx").unwrap()
+        else {
+            panic!("expected an xlsx workbook");
+        };
+        workbook.load_merged_regions().unwrap();
+        let regions: Vec<Dimensions> = workbook
+            .merged_regions_by_sheet("Sheet1")
+            .into_iter()
+            .map(|(_, _, dimensions)| *dimensions)
+            .collect();
+        let mut range = workbook.worksheet_range("Sheet1").unwrap();
+
+        fill_merged_regions(&mut range, &regions);
+
+        assert_eq!(
+            range
+                .rows()
+                .map(|row| row[0].to_string())
+                .collect::<Vec<_>>(),
+            vec!["category", "fruit", "fruit", "vegetable", "vegetable"]
+        );
+    }
+}
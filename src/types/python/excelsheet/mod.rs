@@ -1,32 +1,81 @@
+pub(crate) mod batches;
+pub(crate) mod cell_range;
+pub(crate) mod column;
 pub(crate) mod column_info;
+pub(crate) mod column_stats;
+pub(crate) mod defined_names;
+pub(crate) mod formulas;
+pub(crate) mod merged_cells;
+pub(crate) mod positions;
+pub(crate) mod read_warnings;
+pub(crate) mod rows;
 pub(crate) mod table;
 
-use calamine::{CellType, Range, Sheet as CalamineSheet, SheetVisible as CalamineSheetVisible};
+use calamine::{
+    CellType, DataType, Range, Sheet as CalamineSheet, SheetVisible as CalamineSheetVisible,
+};
 use std::{cmp, collections::HashSet, fmt::Debug, str::FromStr};
 
-use arrow::{pyarrow::ToPyArrow, record_batch::RecordBatch};
+use arrow::{
+    datatypes::Schema,
+    pyarrow::{PyArrowType, ToPyArrow},
+    record_batch::RecordBatch,
+};
 
 use pyo3::{
     prelude::{pyclass, pymethods, PyAnyMethods, Python},
     types::{PyList, PyString},
-    Bound, PyAny, PyObject, PyResult, ToPyObject,
+    Bound, FromPyObject, PyAny, PyObject, PyRef, PyResult, ToPyObject,
 };
 
 use crate::{
-    data::{record_batch_from_data_and_columns, ExcelSheetData},
+    data::{
+        record_batch_from_data_and_columns, selected_columns_to_schema, with_nullability_of,
+        ExcelSheetData,
+    },
     error::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
-    types::{dtype::DTypeMap, idx_or_name::IdxOrName},
+    types::{
+        dtype::{arrow_type_name, DTypeMap},
+        idx_or_name::IdxOrName,
+        read_options::ReadOptions,
+    },
+};
+use crate::{
+    types::dtype::DTypeCoercion,
+    utils::{
+        concat::{conform_record_batch, ExtraColumns},
+        schema::{get_schema_sample_rows, SampleRows, SchemaSampleRows},
+    },
 };
-use crate::{types::dtype::DTypeCoercion, utils::schema::get_schema_sample_rows};
 
-use self::column_info::{build_available_columns, build_available_columns_info, ColumnInfo};
+use self::{
+    batches::ExcelSheetBatches,
+    column::{find_selected_column, ArrowColumn},
+    column_info::{
+        build_available_columns, build_available_columns_info, columns_preview, validate_header,
+        ColumnInfo, HeaderWarning,
+    },
+    column_stats::{column_stats, ColumnStats},
+    formulas::SheetFormulas,
+    positions::SheetPositions,
+    read_warnings::{conversion_warnings, dtype_warnings, ReadWarning},
+    rows::ExcelSheetRows,
+};
 
 #[derive(Debug)]
 pub(crate) enum Header {
     None,
     At(usize),
+    /// Column names read at the given row, which is also the first row of the data
+    InData(usize),
+    /// Column names spread over `depth` rows starting at `row`, joined with `separator`
+    MultiRow {
+        row: usize,
+        depth: usize,
+        separator: String,
+    },
     With(Vec<String>),
 }
 
@@ -44,10 +93,154 @@ impl Header {
     pub(crate) fn offset(&self) -> usize {
         match self {
             Header::At(index) => index + 1,
+            Header::InData(index) => *index,
+            Header::MultiRow { row, depth, .. } => row + depth,
             Header::None => 0,
             Header::With(_) => 0,
         }
     }
+
+    /// The index of the header row, if the column names are read from the sheet
+    pub(crate) fn row(&self) -> Option<usize> {
+        match self {
+            Header::At(index) | Header::InData(index) | Header::MultiRow { row: index, .. } => {
+                Some(*index)
+            }
+            Header::None | Header::With(_) => None,
+        }
+    }
+
+    /// Spreads the header over `depth` rows. A depth of 1 leaves the header unchanged
+    pub(crate) fn with_depth(self, depth: usize, separator: &str) -> FastExcelResult<Self> {
+        match (self, depth) {
+            (_, 0) => Err(FastExcelErrorKind::InvalidParameters(
+                "header_depth must be at least 1".to_string(),
+            )
+            .into()),
+            (header, 1) => Ok(header),
+            (Header::At(row), depth) => Ok(Header::MultiRow {
+                row,
+                depth,
+                separator: separator.to_owned(),
+            }),
+            (_, _) => Err(FastExcelErrorKind::InvalidParameters(
+                "header_depth can only be used with a header_row".to_string(),
+            )
+            .into()),
+        }
+    }
+
+    /// The number of rows between the end of the header and `data_start_row`, which must come
+    /// after the header rows
+    pub(crate) fn gap_before(&self, data_start_row: usize) -> FastExcelResult<usize> {
+        let Some(header_row) = self.row() else {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "`data_start_row` can only be used with a header_row".to_string(),
+            )
+            .into());
+        };
+        data_start_row
+            .checked_sub(self.offset())
+            .filter(|_| data_start_row > header_row)
+            .ok_or_else(|| {
+                FastExcelErrorKind::InvalidParameters(format!(
+                    "`data_start_row` ({data_start_row}) must be greater than the header rows, starting at {header_row}"
+                ))
+                .into()
+            })
+    }
+
+    /// Keeps the header row in the data, in addition to reading the column names from it
+    pub(crate) fn keep_in_data(self) -> FastExcelResult<Self> {
+        match self {
+            Header::At(row) => Ok(Header::InData(row)),
+            Header::MultiRow { .. } => Err(FastExcelErrorKind::InvalidParameters(
+                "keep_header_in_data cannot be used with header_depth".to_string(),
+            )
+            .into()),
+            Header::None | Header::InData(_) | Header::With(_) => {
+                Err(FastExcelErrorKind::InvalidParameters(
+                    "keep_header_in_data can only be used with a header_row".to_string(),
+                )
+                .into())
+            }
+        }
+    }
+}
+
+/// Number of rows scanned when looking for the header row
+const AUTO_HEADER_SCAN_ROWS: usize = 20;
+
+/// Looks for the header row in the first rows of a range. The header row is the first row that
+/// is mostly made of non-empty strings, and is followed by a row containing at least one cell that
+/// is neither empty nor a string. Returns `None` if no row matches.
+pub(crate) fn detect_header_row<CT: CellType + DataType>(range: &Range<CT>) -> Option<usize> {
+    let width = range.width();
+    let scanned_rows = cmp::min(AUTO_HEADER_SCAN_ROWS, range.height());
+    let row_cells = |row: usize| (0..width).filter_map(move |col| range.get((row, col)));
+
+    let is_header_like = |row: usize| {
+        let string_count = row_cells(row)
+            .filter(|cell| cell.get_string().is_some_and(|s| !s.trim().is_empty()))
+            .count();
+        string_count * 2 > width
+    };
+    let has_typed_values =
+        |row: usize| row_cells(row).any(|cell| !cell.is_empty() && cell.get_string().is_none());
+
+    (0..scanned_rows.saturating_sub(1))
+        .find(|&row| is_header_like(row) && has_typed_values(row + 1))
+}
+
+/// Returns the height and width of the used range of a range: the smallest rectangle containing
+/// all of its non-empty cells. Cells that are present but empty, e.g. formatted ones, pad ranges
+/// without being part of their used range
+pub(crate) fn detect_used_range<CT: CellType + DataType>(range: &Range<CT>) -> (usize, usize) {
+    let bounds = range
+        .used_cells()
+        .filter(|(_, _, cell)| !cell.is_empty())
+        .fold(None, |bounds, (row, col, _)| match bounds {
+            None => Some((row, row, col, col)),
+            Some((min_row, max_row, min_col, max_col)) => Some((
+                cmp::min(min_row, row),
+                cmp::max(max_row, row),
+                cmp::min(min_col, col),
+                cmp::max(max_col, col),
+            )),
+        });
+    bounds.map_or((0, 0), |(min_row, max_row, min_col, max_col)| {
+        (max_row - min_row + 1, max_col - min_col + 1)
+    })
+}
+
+/// Looks for the header row in the first `search_limit` rows of a range. The header row is the
+/// first row containing all of `expected_headers`, compared after trimming whitespace
+pub(crate) fn find_header_row<CT: CellType + DataType>(
+    range: &Range<CT>,
+    expected_headers: &[String],
+    search_limit: usize,
+) -> FastExcelResult<usize> {
+    let width = range.width();
+    let scanned_rows = cmp::min(search_limit, range.height());
+    let contains_expected_headers = |row: usize| {
+        let row_strings: HashSet<&str> = (0..width)
+            .filter_map(|col| range.get((row, col)))
+            .filter_map(|cell| cell.get_string())
+            .map(str::trim)
+            .collect();
+        expected_headers
+            .iter()
+            .all(|expected_header| row_strings.contains(expected_header.trim()))
+    };
+
+    (0..scanned_rows)
+        .find(|&row| contains_expected_headers(row))
+        .ok_or_else(|| {
+            FastExcelErrorKind::InvalidParameters(format!(
+                "no row in the first {search_limit} rows contains all of the expected headers: {expected_headers:?}"
+            ))
+            .into()
+        })
 }
 
 pub(crate) struct Pagination {
@@ -56,22 +249,36 @@ pub(crate) struct Pagination {
 }
 
 impl Pagination {
+    /// Skipping more rows than the range has results in an empty window rather than an error
     pub(crate) fn new<CT: CellType>(
         skip_rows: usize,
         n_rows: Option<usize>,
         range: &Range<CT>,
-    ) -> FastExcelResult<Self> {
-        let max_height = range.height();
-        if max_height < skip_rows {
-            Err(FastExcelErrorKind::InvalidParameters(format!(
-                "Too many rows skipped. Max height is {max_height}"
-            ))
-            .into())
-        } else {
-            Ok(Self { skip_rows, n_rows })
+    ) -> Self {
+        Self {
+            skip_rows: cmp::min(skip_rows, range.height()),
+            n_rows,
         }
     }
 
+    /// Limits the number of rows so that reading stops at the first row after `header_offset`
+    /// and the skipped rows in which all cells are empty
+    pub(crate) fn stop_at_empty_row<CT: CellType + DataType>(
+        self,
+        range: &Range<CT>,
+        header_offset: usize,
+    ) -> Self {
+        let empty_row_position = range
+            .rows()
+            .skip(header_offset + self.skip_rows)
+            .position(|row| row.iter().all(DataType::is_empty));
+        let n_rows = match (self.n_rows, empty_row_position) {
+            (Some(n_rows), Some(position)) => Some(cmp::min(n_rows, position)),
+            (n_rows, position) => n_rows.or(position),
+        };
+        Self { n_rows, ..self }
+    }
+
     pub(crate) fn offset(&self) -> usize {
         self.skip_rows
     }
@@ -81,6 +288,213 @@ impl Pagination {
     }
 }
 
+/// What to do with a sheet that has more columns than `max_columns` or more rows than `max_rows`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum SizeLimitOverflow {
+    #[default]
+    Error,
+    Truncate,
+}
+
+impl FromStr for SizeLimitOverflow {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        match raw_value {
+            "error" => Ok(Self::Error),
+            "truncate" => Ok(Self::Truncate),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported on_size_limit: \"{raw_value}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for SizeLimitOverflow {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// Caps on the number of columns and data rows of a sheet, bounding the resources used to read
+/// untrusted files
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SizeLimits {
+    pub(crate) max_columns: Option<usize>,
+    pub(crate) max_rows: Option<usize>,
+    /// The maximum number of cells of the used range of the sheet. Exceeding it is always an error
+    pub(crate) max_cells: Option<usize>,
+    pub(crate) on_overflow: SizeLimitOverflow,
+}
+
+impl SizeLimits {
+    /// Checks the width of a sheet's range. Keeps its first `max_columns` columns if the sheet
+    /// should be truncated
+    pub(crate) fn cap_columns<CT: CellType>(&self, range: Range<CT>) -> FastExcelResult<Range<CT>> {
+        let Some(max_columns) = self.max_columns else {
+            return Ok(range);
+        };
+        let width = range.width();
+        if width <= max_columns {
+            return Ok(range);
+        }
+        match self.on_overflow {
+            SizeLimitOverflow::Error => Err(FastExcelErrorKind::SizeLimitExceeded(format!(
+                "the sheet has {width} columns, more than max_columns={max_columns}"
+            ))
+            .into()),
+            SizeLimitOverflow::Truncate => Ok(match (range.start(), range.end()) {
+                (Some((start_row, start_col)), Some((end_row, _))) if max_columns > 0 => range
+                    .range(
+                        (start_row, start_col),
+                        (end_row, start_col + max_columns as u32 - 1),
+                    ),
+                _ => Range::empty(),
+            }),
+        }
+    }
+
+    /// Checks the number of cells of the used range of a sheet, before any of them is converted
+    pub(crate) fn check_cells<CT: CellType + DataType>(
+        &self,
+        range: &Range<CT>,
+    ) -> FastExcelResult<()> {
+        let Some(max_cells) = self.max_cells else {
+            return Ok(());
+        };
+        let (height, width) = detect_used_range(range);
+        let cells = height.saturating_mul(width);
+        if cells > max_cells {
+            Err(FastExcelErrorKind::ResourceLimit(format!(
+                "the used range of the sheet has {cells} cells ({height} rows x {width} columns), more than max_cells={max_cells}"
+            ))
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks the number of data rows read with `pagination`. Reads the first `max_rows` of
+    /// them if the sheet should be truncated
+    pub(crate) fn cap_rows<CT: CellType>(
+        &self,
+        pagination: Pagination,
+        range: &Range<CT>,
+        header_offset: usize,
+    ) -> FastExcelResult<Pagination> {
+        let Some(max_rows) = self.max_rows else {
+            return Ok(pagination);
+        };
+        let available_rows = range
+            .height()
+            .saturating_sub(header_offset + pagination.skip_rows);
+        let rows = pagination
+            .n_rows
+            .map_or(available_rows, |n_rows| cmp::min(n_rows, available_rows));
+        if rows <= max_rows {
+            return Ok(pagination);
+        }
+        match self.on_overflow {
+            SizeLimitOverflow::Error => Err(FastExcelErrorKind::SizeLimitExceeded(format!(
+                "the sheet has {rows} rows, more than max_rows={max_rows}"
+            ))
+            .into()),
+            SizeLimitOverflow::Truncate => Ok(Pagination {
+                n_rows: Some(max_rows),
+                ..pagination
+            }),
+        }
+    }
+}
+
+/// Builds a range made of the header rows followed by the given data rows, in the given order.
+/// Row indices are relative to the start of the range, like the header row, and must not point
+/// to a header row.
+pub(crate) fn select_data_rows<CT: CellType>(
+    range: &Range<CT>,
+    header_offset: usize,
+    data_rows: &[usize],
+) -> FastExcelResult<Range<CT>> {
+    let height = range.height();
+    if let Some(row) = data_rows
+        .iter()
+        .find(|&&row| row < header_offset || row >= height)
+    {
+        return Err(FastExcelErrorKind::InvalidParameters(format!(
+            "data_rows contains row {row}, which is not a data row: data rows range from \
+             {header_offset} to {height} (excluded)"
+        ))
+        .into());
+    }
+    let (Some((start_row, start_col)), Some((_, end_col))) = (range.start(), range.end()) else {
+        return Ok(Range::empty());
+    };
+    let selected_height = header_offset + data_rows.len();
+    if selected_height == 0 {
+        return Ok(Range::empty());
+    }
+
+    let mut selected = Range::new(
+        (start_row, start_col),
+        (start_row + selected_height as u32 - 1, end_col),
+    );
+    let source_rows = (0..header_offset).chain(data_rows.iter().copied());
+    for (target_row, source_row) in source_rows.enumerate() {
+        for col in 0..range.width() {
+            if let Some(cell) = range.get((source_row, col)) {
+                selected.set_value(
+                    (start_row + target_row as u32, start_col + col as u32),
+                    cell.clone(),
+                );
+            }
+        }
+    }
+    Ok(selected)
+}
+
+/// Swaps the rows and the columns of a range, so that the variables laid out in rows are read as
+/// columns. Cell positions are swapped as well, so that cells keep a unique position.
+pub(crate) fn transpose_range<CT: CellType>(range: &Range<CT>) -> Range<CT> {
+    let (Some((start_row, start_col)), Some((end_row, end_col))) = (range.start(), range.end())
+    else {
+        return Range::empty();
+    };
+    let mut transposed = Range::new((start_col, start_row), (end_col, end_row));
+    for (row, col, cell) in range.used_cells() {
+        transposed.set_value(
+            (start_col + col as u32, start_row + row as u32),
+            cell.clone(),
+        );
+    }
+    transposed
+}
+
+/// Returns the data rows of a range that are not part of `hidden_rows`, relative to the start of
+/// the range like for `select_data_rows`. `hidden_rows` are absolute sheet rows. Only the given
+/// `data_rows` are considered if any, and every row following the header otherwise.
+pub(crate) fn visible_data_rows<CT: CellType>(
+    range: &Range<CT>,
+    header_offset: usize,
+    data_rows: Option<&[usize]>,
+    hidden_rows: &HashSet<u32>,
+) -> Vec<usize> {
+    let start_row = range.start().map_or(0, |(row, _)| row as usize);
+    let is_visible = |row: &usize| !hidden_rows.contains(&((start_row + row) as u32));
+    match data_rows {
+        Some(data_rows) => data_rows.iter().copied().filter(is_visible).collect(),
+        None => (header_offset..range.height()).filter(is_visible).collect(),
+    }
+}
+
 impl TryFrom<&Bound<'_, PyList>> for SelectedColumns {
     type Error = FastExcelError;
 
@@ -186,7 +600,7 @@ impl SelectedColumns {
         'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
     ];
 
-    fn col_idx_for_col_as_letter(col: &str) -> FastExcelResult<usize> {
+    pub(super) fn col_idx_for_col_as_letter(col: &str) -> FastExcelResult<usize> {
         use FastExcelErrorKind::InvalidParameters;
 
         if col.is_empty() {
@@ -350,11 +764,21 @@ pub(crate) struct ExcelSheet {
     height: Option<usize>,
     total_height: Option<usize>,
     width: Option<usize>,
-    schema_sample_rows: Option<usize>,
+    schema_sample_rows: Option<SchemaSampleRows>,
     dtype_coercion: DTypeCoercion,
     selected_columns: Vec<ColumnInfo>,
     available_columns: Vec<ColumnInfo>,
     dtypes: Option<DTypeMap>,
+    read_options: ReadOptions,
+    header_warnings: Vec<HeaderWarning>,
+    collect_warnings: bool,
+    /// Warnings about the dtypes guessed from mixed types, collected once the columns are known
+    dtype_warnings: Vec<ReadWarning>,
+    /// Warnings about the cells read as null, collected on every read of the selected columns
+    conversion_warnings: Vec<ReadWarning>,
+    /// The formulas read next to the selected columns, if any
+    formulas: Option<SheetFormulas>,
+    positions: SheetPositions,
 }
 
 impl ExcelSheet {
@@ -368,13 +792,19 @@ impl ExcelSheet {
         data: ExcelSheetData<'static>,
         header: Header,
         pagination: Pagination,
-        schema_sample_rows: Option<usize>,
+        schema_sample_rows: Option<SchemaSampleRows>,
         dtype_coercion: DTypeCoercion,
         selected_columns: SelectedColumns,
         dtypes: Option<DTypeMap>,
+        read_options: ReadOptions,
     ) -> FastExcelResult<Self> {
-        let available_columns_info =
-            build_available_columns_info(&data, &selected_columns, &header)?;
+        let available_columns_info = build_available_columns_info(
+            &data,
+            &selected_columns,
+            &header,
+            read_options.column_prefix(),
+        )?;
+        let positions = SheetPositions::new(data.start(), header.offset());
         let mut sheet = ExcelSheet {
             sheet_meta,
             header,
@@ -383,28 +813,37 @@ impl ExcelSheet {
             schema_sample_rows,
             dtype_coercion,
             dtypes,
+            read_options,
             height: None,
             total_height: None,
             width: None,
+            header_warnings: Vec::new(),
+            collect_warnings: false,
+            dtype_warnings: Vec::new(),
+            conversion_warnings: Vec::new(),
+            formulas: None,
+            positions,
             // Empty vecs as they'll be replaced
             available_columns: Vec::with_capacity(0),
             selected_columns: Vec::with_capacity(0),
         };
 
-        let row_limit = sheet.schema_sample_rows();
-
         // Finalizing column info
         let available_columns = build_available_columns(
             available_columns_info,
             &sheet.data,
-            sheet.offset(),
-            row_limit,
+            sheet.schema_sample_rows(),
             sheet.dtypes.as_ref(),
             &sheet.dtype_coercion,
-        )?;
+            &sheet.read_options,
+        )
+        .with_cell_location(|location| location.sheet_name = Some(sheet.name().to_owned()))?;
 
         // Figure out dtype for every column
-        let selected_columns = selected_columns.select_columns(&available_columns)?;
+        let selected_columns = sheet
+            .read_options
+            .reorder_columns(selected_columns.select_columns(&available_columns)?)?;
+        sheet.read_options.validate_fill_values(&selected_columns)?;
         sheet.available_columns = available_columns;
         sheet.selected_columns = selected_columns;
 
@@ -412,7 +851,8 @@ impl ExcelSheet {
     }
 
     pub(crate) fn limit(&self) -> usize {
-        let upper_bound = self.data.height();
+        // The header and skipped rows may go beyond the end of the data
+        let upper_bound = cmp::max(self.data.height(), self.offset());
         if let Some(n_rows) = self.pagination.n_rows {
             let limit = self.offset() + n_rows;
             if limit < upper_bound {
@@ -423,8 +863,115 @@ impl ExcelSheet {
         upper_bound
     }
 
-    pub(crate) fn schema_sample_rows(&self) -> usize {
-        get_schema_sample_rows(self.schema_sample_rows, self.offset(), self.limit())
+    pub(crate) fn schema_sample_rows(&self) -> SampleRows {
+        get_schema_sample_rows(
+            self.schema_sample_rows,
+            self.read_options.sample_strategy,
+            self.offset(),
+            self.limit(),
+        )
+    }
+
+    /// The arrow schema of the sheet's selected columns. Does not require to read the sheet's data,
+    /// unless `tighten_nullability` is set
+    pub(crate) fn arrow_schema(&self) -> Schema {
+        self.with_formula_fields(self.selected_columns_schema())
+    }
+
+    /// The arrow schema of the selected columns, without their formula columns
+    fn selected_columns_schema(&self) -> Schema {
+        self.data.with_nullability(
+            selected_columns_to_schema(&self.selected_columns, &self.read_options),
+            &self.selected_columns,
+            self.offset(),
+            self.limit(),
+            &self.read_options,
+        )
+    }
+
+    /// Reads the formulas of the selected columns next to their values. `formulas` must be the
+    /// formulas of the whole sheet
+    pub(crate) fn read_formulas(&mut self, formulas: Range<String>) {
+        self.formulas = Some(SheetFormulas::new(
+            formulas,
+            self.data.start().unwrap_or((0, 0)),
+            &self.selected_columns,
+            &self.available_columns,
+        ));
+    }
+
+    /// Records how the data was derived from the worksheet: the source rows of the data rows if
+    /// they were selected, and whether the range was transposed
+    pub(crate) fn record_source_positions(
+        &mut self,
+        data_rows: Option<Vec<usize>>,
+        transposed: bool,
+    ) {
+        let mut positions = SheetPositions::new(self.data.start(), self.header.offset());
+        if let Some(data_rows) = data_rows {
+            positions = positions.with_data_rows(data_rows);
+        }
+        if transposed {
+            positions = positions.transposed();
+        }
+        self.positions = positions;
+    }
+
+    /// Adds the fields of the formula columns to a schema of the selected columns
+    pub(crate) fn with_formula_fields(&self, schema: Schema) -> Schema {
+        match &self.formulas {
+            Some(formulas) => formulas.interleave_fields(&schema),
+            None => schema,
+        }
+    }
+
+    /// Adds the formula columns to a record batch of the selected columns, read from `offset` to
+    /// `limit`
+    pub(crate) fn with_formula_columns(
+        &self,
+        rb: RecordBatch,
+        offset: usize,
+        limit: usize,
+    ) -> FastExcelResult<RecordBatch> {
+        match &self.formulas {
+            Some(formulas) => {
+                formulas.interleave_columns(rb, &self.selected_columns, offset, limit)
+            }
+            None => Ok(rb),
+        }
+    }
+
+    /// Checks the header cells of the selected columns, and stores the resulting warnings
+    pub(crate) fn validate_header(&mut self) -> &[HeaderWarning] {
+        self.header_warnings = validate_header(&self.data, &self.header, &self.selected_columns);
+        &self.header_warnings
+    }
+
+    /// Starts collecting read warnings, beginning with the selected columns whose dtype was
+    /// guessed from mixed types
+    pub(crate) fn collect_warnings(&mut self) {
+        self.collect_warnings = true;
+        self.dtype_warnings = dtype_warnings(
+            &self.data,
+            &self.selected_columns,
+            self.schema_sample_rows(),
+        );
+    }
+
+    /// Reads the selected columns. If warnings are collected, the cells read as null although
+    /// they contain a value are recorded
+    fn read_record_batch(&mut self) -> FastExcelResult<RecordBatch> {
+        let rb = RecordBatch::try_from(&*self)?;
+        if self.collect_warnings {
+            self.conversion_warnings = conversion_warnings(
+                &self.data,
+                &self.selected_columns,
+                self.offset(),
+                self.limit(),
+                &rb,
+            );
+        }
+        self.with_formula_columns(rb, self.offset(), self.limit())
     }
 }
 
@@ -435,8 +982,21 @@ impl TryFrom<&ExcelSheet> for RecordBatch {
         let offset = sheet.offset();
         let limit = sheet.limit();
 
-        record_batch_from_data_and_columns(&sheet.selected_columns, sheet.data(), offset, limit)
-            .with_context(|| format!("could not convert sheet {} to RecordBatch", sheet.name()))
+        record_batch_from_data_and_columns(
+            &sheet.selected_columns,
+            sheet.data(),
+            offset,
+            limit,
+            &sheet.dtype_coercion,
+            &sheet.read_options,
+        )
+        .and_then(|rb| {
+            if !sheet.read_options.tighten_nullability {
+                return Ok(rb);
+            }
+            with_nullability_of(rb, &sheet.selected_columns_schema())
+        })
+        .with_context(|| format!("could not convert sheet {} to RecordBatch", sheet.name()))
     }
 }
 
@@ -463,17 +1023,70 @@ impl ExcelSheet {
     #[getter]
     pub fn total_height(&mut self) -> usize {
         self.total_height.unwrap_or_else(|| {
-            let total_height = self.data.height() - self.header.offset();
+            let total_height = self.data.height().saturating_sub(self.header.offset());
             self.total_height = Some(total_height);
             total_height
         })
     }
 
+    /// Whether the sheet has no data rows below its header. Does not read any cell
+    pub fn is_empty(&mut self) -> bool {
+        self.total_height() == 0
+    }
+
     #[getter]
     pub fn offset(&self) -> usize {
         self.header.offset() + self.pagination.offset()
     }
 
+    #[getter]
+    pub fn header_row(&self) -> Option<usize> {
+        self.header.row()
+    }
+
+    /// The absolute (row, column) position in the worksheet of the first cell of the loaded data,
+    /// i.e. of the first data row in the first column of the data
+    #[getter]
+    pub fn origin(&self) -> (usize, usize) {
+        self.positions.sheet_position(self.offset(), 0)
+    }
+
+    /// The A1 reference in the worksheet of a loaded cell, e.g. "AB12". `row` is relative to the
+    /// first loaded data row, and `column` is the index or the name of a selected column
+    pub fn cell_reference(&self, row: usize, column: &Bound<'_, PyAny>) -> PyResult<String> {
+        let idx_or_name: IdxOrName = column.try_into().into_pyresult()?;
+        let height = self.limit() - self.offset();
+        if row >= height {
+            return Err(FastExcelErrorKind::InvalidParameters(format!(
+                "row {row} is out of bounds, {height} data rows were loaded"
+            ))
+            .into())
+            .into_pyresult();
+        }
+        find_selected_column(&self.selected_columns, &idx_or_name)
+            .map(|column_info| {
+                self.positions
+                    .cell_reference(self.offset() + row, column_info.index())
+            })
+            .into_pyresult()
+    }
+
+    #[getter]
+    pub fn header_warnings<'p>(&'p self, _py: Python<'p>) -> Vec<HeaderWarning> {
+        self.header_warnings.clone()
+    }
+
+    /// The warnings collected while reading the sheet, if `collect_warnings` was set. Cells read
+    /// as null are only known once the sheet has been converted with `to_arrow`
+    #[getter]
+    pub fn read_warnings<'p>(&'p self, _py: Python<'p>) -> Vec<ReadWarning> {
+        self.dtype_warnings
+            .iter()
+            .chain(&self.conversion_warnings)
+            .cloned()
+            .collect()
+    }
+
     #[getter]
     pub fn selected_columns<'p>(&'p self, _py: Python<'p>) -> Vec<ColumnInfo> {
         self.selected_columns.clone()
@@ -500,8 +1113,10 @@ impl ExcelSheet {
         (&visible).to_object(py)
     }
 
-    pub fn to_arrow(&self, py: Python<'_>) -> PyResult<PyObject> {
-        RecordBatch::try_from(self)
+    // Takes `&mut self` to record the warnings collected while reading
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_arrow(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        self.read_record_batch()
             .with_context(|| {
                 format!(
                     "could not create RecordBatch from sheet \"{}\"",
@@ -521,14 +1136,161 @@ impl ExcelSheet {
             .into_pyresult()
     }
 
+    #[getter]
+    pub fn schema(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.arrow_schema()
+            .to_pyarrow(py)
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            .into_pyresult()
+    }
+
+    /// The name and the pyarrow type name of the selected columns, from the sheet's arrow schema
+    #[getter]
+    pub fn columns(&self) -> Vec<(String, String)> {
+        self.arrow_schema()
+            .fields()
+            .iter()
+            .map(|field| (field.name().to_owned(), arrow_type_name(field.data_type())))
+            .collect()
+    }
+
+    pub fn schema_and_arrow(&mut self, py: Python<'_>) -> PyResult<(PyObject, PyObject)> {
+        let rb = self
+            .read_record_batch()
+            .with_context(|| {
+                format!(
+                    "could not create RecordBatch from sheet \"{}\"",
+                    self.name()
+                )
+            })
+            .into_pyresult()?;
+        Ok((rb.schema_ref().to_pyarrow(py)?, rb.to_pyarrow(py)?))
+    }
+
+    /// Converts a single selected column to an `ArrowColumn`
+    pub fn column(&self, idx_or_name: &Bound<'_, PyAny>) -> PyResult<ArrowColumn> {
+        let idx_or_name: IdxOrName = idx_or_name.try_into().into_pyresult()?;
+        find_selected_column(&self.selected_columns, &idx_or_name)
+            .and_then(|column_info| {
+                record_batch_from_data_and_columns(
+                    std::slice::from_ref(column_info),
+                    self.data(),
+                    self.offset(),
+                    self.limit(),
+                    &self.dtype_coercion,
+                    &self.read_options,
+                )
+            })
+            .and_then(ArrowColumn::try_from_record_batch)
+            .with_context(|| {
+                format!(
+                    "could not read column {column} of sheet \"{sheet}\"",
+                    column = idx_or_name.format_message(),
+                    sheet = self.name()
+                )
+            })
+            .into_pyresult()
+    }
+
+    /// Computes the statistics of the selected columns in a single scan of their cells, without
+    /// converting them to arrow
+    pub fn column_stats(&self) -> Vec<ColumnStats> {
+        column_stats(
+            &self.data,
+            &self.selected_columns,
+            self.offset(),
+            self.limit(),
+            &self.read_options,
+        )
+    }
+
+    pub fn rows(slf: Bound<'_, Self>) -> ExcelSheetRows {
+        let (offset, limit) = {
+            let sheet = slf.borrow();
+            (sheet.offset(), sheet.limit())
+        };
+        ExcelSheetRows::new(slf.unbind(), offset, limit)
+    }
+
+    /// Iterates over the selected columns in record batches of at most `batch_size` rows,
+    /// converting one batch at a time
+    pub fn to_arrow_batches(
+        slf: Bound<'_, Self>,
+        batch_size: usize,
+    ) -> PyResult<ExcelSheetBatches> {
+        let (schema, offset, limit) = {
+            let sheet = slf.borrow();
+            let (offset, limit) = (sheet.offset(), sheet.limit());
+            let schema = sheet.with_formula_fields(sheet.data.schema(
+                &sheet.selected_columns,
+                offset,
+                limit,
+                &sheet.read_options,
+            ));
+            (schema, offset, limit)
+        };
+        ExcelSheetBatches::try_new(slf.unbind(), schema, batch_size, offset, limit).into_pyresult()
+    }
+
+    #[pyo3(signature = (template, *, extra_columns = ExtraColumns::Drop))]
+    pub fn to_arrow_with_schema(
+        &self,
+        template: &Bound<'_, PyAny>,
+        extra_columns: ExtraColumns,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let template_schema = if let Ok(template_sheet) = template.extract::<PyRef<ExcelSheet>>() {
+            template_sheet.arrow_schema()
+        } else if let Ok(PyArrowType(schema)) = template.extract::<PyArrowType<Schema>>() {
+            schema
+        } else {
+            return Err(FastExcelErrorKind::InvalidParameters(format!(
+                "expected template to be a pyarrow Schema or an ExcelSheet, got {template:?}"
+            ))
+            .into())
+            .into_pyresult();
+        };
+
+        RecordBatch::try_from(self)
+            .and_then(|rb| self.with_formula_columns(rb, self.offset(), self.limit()))
+            .and_then(|rb| conform_record_batch(&rb, &template_schema, extra_columns))
+            .with_context(|| {
+                format!(
+                    "could not create RecordBatch from sheet \"{}\" with the template schema",
+                    self.name()
+                )
+            })
+            .and_then(|rb| {
+                rb.to_pyarrow(py)
+                    .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            })
+            .into_pyresult()
+    }
+
+    /// Shows the dimensions and the first selected columns of the sheet, without reading its data
     pub fn __repr__(&self) -> String {
-        format!("ExcelSheet<{}>", self.name())
+        format!(
+            "ExcelSheet<{name}, {height}x{width}, {columns}>",
+            name = self.name(),
+            height = self.limit() - self.offset(),
+            width = self.selected_columns.len(),
+            columns = columns_preview(&self.selected_columns),
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
+    use crate::types::{
+        dtype::DType,
+        python::excelsheet::column_info::{alias_available_columns, ColumnNameFrom, DTypeFrom},
+        read_options::{DuplicateColumns, UnknownRenames, DEFAULT_COLUMN_PREFIX},
+    };
+    use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
+    use calamine::{Cell, Data as CalData};
     use pretty_assertions::assert_eq;
     use pyo3::prelude::PyListMethods;
     use rstest::rstest;
@@ -671,4 +1433,765 @@ mod tests {
             }
         })
     }
+
+    fn range_from_rows(rows: Vec<Vec<CalData>>) -> Range<CalData> {
+        Range::from_sparse(
+            rows.into_iter()
+                .enumerate()
+                .flat_map(|(row_idx, row)| {
+                    row.into_iter().enumerate().map(move |(col_idx, value)| {
+                        Cell::new((row_idx as u32, col_idx as u32), value)
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn string(value: &str) -> CalData {
+        CalData::String(value.to_owned())
+    }
+
+    #[test]
+    fn detect_header_row_after_title_rows() {
+        let range = range_from_rows(vec![
+            vec![string("Report"), CalData::Empty, CalData::Empty],
+            vec![CalData::Empty, CalData::Empty, CalData::Empty],
+            vec![string("Name"), string("Age"), string("Score")],
+            vec![string("Alice"), CalData::Int(42), CalData::Float(13.37)],
+        ]);
+
+        assert_eq!(detect_header_row(&range), Some(2));
+    }
+
+    #[test]
+    fn find_header_row_with_expected_headers() {
+        let range = range_from_rows(vec![
+            vec![string("Name: export"), CalData::Empty, CalData::Empty],
+            vec![string("Name"), CalData::Empty, CalData::Empty],
+            vec![string(" Name "), string("Age"), string("Score")],
+            vec![string("Alice"), CalData::Int(42), CalData::Float(13.37)],
+        ]);
+        let expected_headers = |headers: &[&str]| {
+            headers
+                .iter()
+                .map(|header| header.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            find_header_row(&range, &expected_headers(&["Age", "Name"]), 100).unwrap(),
+            2
+        );
+        assert_eq!(
+            find_header_row(&range, &expected_headers(&["Name"]), 100).unwrap(),
+            1
+        );
+        // The header row is beyond the search limit
+        let err = find_header_row(&range, &expected_headers(&["Age"]), 2).unwrap_err();
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+    }
+
+    #[rstest]
+    #[case(0, None, Some(2))]
+    #[case(0, Some(1), Some(1))]
+    #[case(0, Some(5), Some(2))]
+    // The empty row is skipped, so reading stops at the end of the second block
+    #[case(3, None, Some(1))]
+    fn pagination_stops_at_empty_row(
+        #[case] skip_rows: usize,
+        #[case] n_rows: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let range = range_from_rows(vec![
+            vec![string("a"), string("b")],
+            vec![CalData::Float(1.0), CalData::Empty],
+            vec![CalData::Empty, CalData::Float(2.0)],
+            vec![CalData::Empty, CalData::Empty],
+            vec![string("notes"), CalData::Empty],
+            vec![CalData::Empty, CalData::Empty],
+        ]);
+
+        let pagination = Pagination::new(skip_rows, n_rows, &range).stop_at_empty_row(&range, 1);
+
+        assert_eq!(pagination.n_rows(), expected);
+    }
+
+    #[test]
+    fn pagination_skipping_past_the_end_is_empty() {
+        let range = range_from_rows((0..4).map(|row| vec![CalData::Float(row as f64)]).collect());
+
+        let pagination = Pagination::new(1_000_000, None, &range);
+
+        assert_eq!(pagination.offset(), 4);
+        assert_eq!(pagination.n_rows(), None);
+    }
+
+    #[rstest]
+    #[case(None, Some(3))]
+    #[case(Some(2), Some(2))]
+    #[case(Some(10), Some(3))]
+    fn size_limits_truncate_rows(#[case] n_rows: Option<usize>, #[case] expected: Option<usize>) {
+        let range = range_from_rows((0..6).map(|row| vec![CalData::Float(row as f64)]).collect());
+        let size_limits = SizeLimits {
+            max_rows: Some(3),
+            on_overflow: SizeLimitOverflow::Truncate,
+            ..Default::default()
+        };
+
+        let pagination = size_limits
+            .cap_rows(Pagination::new(1, n_rows, &range), &range, 1)
+            .unwrap();
+
+        assert_eq!(pagination.n_rows(), expected);
+    }
+
+    #[test]
+    fn used_range_ignores_empty_cells() {
+        let range = range_from_rows(vec![
+            vec![CalData::Empty, CalData::Empty, CalData::Empty],
+            vec![CalData::Empty, string("a"), CalData::Empty],
+            vec![CalData::Float(1.0), CalData::Empty, CalData::Empty],
+            vec![CalData::Empty, CalData::Empty, CalData::Empty],
+        ]);
+
+        assert_eq!(detect_used_range(&range), (2, 2));
+        assert_eq!(detect_used_range(&Range::<CalData>::empty()), (0, 0));
+
+        let size_limits = |max_cells| SizeLimits {
+            max_cells: Some(max_cells),
+            ..Default::default()
+        };
+        assert!(size_limits(4).check_cells(&range).is_ok());
+        let err = size_limits(3).check_cells(&range).unwrap_err();
+        assert!(matches!(err.kind, FastExcelErrorKind::ResourceLimit(_)));
+    }
+
+    #[test]
+    fn size_limits_on_columns() {
+        let range = range_from_rows(vec![
+            vec![string("a"), string("b"), string("c")],
+            vec![
+                CalData::Float(1.0),
+                CalData::Float(2.0),
+                CalData::Float(3.0),
+            ],
+        ]);
+        let mut size_limits = SizeLimits {
+            max_columns: Some(2),
+            ..Default::default()
+        };
+
+        let err = size_limits.cap_columns(range.clone()).unwrap_err();
+        assert_eq!(
+            err.kind.to_string(),
+            "size limit exceeded: the sheet has 3 columns, more than max_columns=2"
+        );
+
+        size_limits.on_overflow = SizeLimitOverflow::Truncate;
+        let truncated = size_limits.cap_columns(range.clone()).unwrap();
+        assert_eq!(truncated.get_size(), (2, 2));
+        assert_eq!(truncated.get((0, 1)), Some(&string("b")));
+
+        size_limits.max_columns = Some(3);
+        assert_eq!(size_limits.cap_columns(range).unwrap().get_size(), (2, 3));
+    }
+
+    #[test]
+    fn multi_row_header_names_are_flattened() {
+        let range = range_from_rows(vec![
+            vec![
+                CalData::Empty,
+                string("Sales"),
+                CalData::Empty,
+                string("Costs"),
+            ],
+            vec![string("Region"), string("Q1"), string("Q2"), CalData::Empty],
+            vec![
+                string("EU"),
+                CalData::Float(1.0),
+                CalData::Float(2.0),
+                CalData::Float(3.0),
+            ],
+        ]);
+        let header = Header::At(0).with_depth(2, " / ").unwrap();
+        assert_eq!(header.offset(), 2);
+
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &header,
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+
+        assert_eq!(
+            columns_info
+                .iter()
+                .map(|column_info| column_info.name())
+                .collect::<Vec<_>>(),
+            vec!["Region", "Sales / Q1", "Sales / Q2", "Costs"]
+        );
+    }
+
+    #[test]
+    fn column_names_are_aliased_without_reading_the_data() {
+        let range = range_from_rows(vec![
+            vec![string("a"), string("a"), string("b"), string("a")],
+            vec![
+                CalData::Error(calamine::CellErrorType::Div0),
+                CalData::Empty,
+                CalData::Empty,
+                CalData::Empty,
+            ],
+        ]);
+
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &Header::At(0),
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+
+        assert_eq!(
+            alias_available_columns(columns_info, DuplicateColumns::Underscore)
+                .unwrap()
+                .iter()
+                .map(|column_info| column_info.name())
+                .collect::<Vec<_>>(),
+            vec!["a", "a_1", "b", "a_2"]
+        );
+    }
+
+    #[test]
+    fn duplicate_column_names_are_aliased_with_the_chosen_separator() {
+        let range = range_from_rows(vec![vec![
+            string("a"),
+            string("a"),
+            string("b"),
+            string("a.1"),
+        ]]);
+        let columns_info = || {
+            build_available_columns_info(
+                &range,
+                &SelectedColumns::All,
+                &Header::At(0),
+                DEFAULT_COLUMN_PREFIX,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            alias_available_columns(columns_info(), DuplicateColumns::Dot)
+                .unwrap()
+                .iter()
+                .map(|column_info| column_info.name())
+                .collect::<Vec<_>>(),
+            vec!["a", "a.1", "b", "a.1.1"]
+        );
+
+        let err = alias_available_columns(columns_info(), DuplicateColumns::Error).unwrap_err();
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::DuplicateColumn(ref name) if name == "a"
+        ));
+        assert_eq!(err.to_string(), "duplicate column name \"a\"");
+
+        let range = range_from_rows(vec![vec![string("a"), string("b")]]);
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &Header::At(0),
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+        assert_eq!(
+            alias_available_columns(columns_info, DuplicateColumns::Error)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn column_names_are_aliased_on_huge_header_rows() {
+        let range = range_from_rows(vec![(0..5_000)
+            .map(|idx| {
+                if idx == 4_999 {
+                    string("a_3")
+                } else {
+                    string("a")
+                }
+            })
+            .collect()]);
+
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &Header::At(0),
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+        let names: Vec<_> = alias_available_columns(columns_info, DuplicateColumns::Underscore)
+            .unwrap()
+            .iter()
+            .map(|column_info| column_info.name().to_owned())
+            .collect();
+
+        assert_eq!(names[..3], ["a", "a_1", "a_2"]);
+        assert_eq!(names[4_998], "a_4998");
+        // "a_3" is already used by the fourth column
+        assert_eq!(names[4_999], "a_3_1");
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 5_000);
+    }
+
+    #[test]
+    fn identical_column_names_keep_the_suffix_scheme() {
+        let range = range_from_rows(vec![(0..5_000).map(|_| string("name")).collect()]);
+
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &Header::At(0),
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+
+        for (idx, column_info) in
+            alias_available_columns(columns_info, DuplicateColumns::Underscore)
+                .unwrap()
+                .iter()
+                .enumerate()
+        {
+            let expected = if idx == 0 {
+                "name".to_owned()
+            } else {
+                format!("name_{idx}")
+            };
+            assert_eq!(column_info.name(), expected);
+        }
+    }
+
+    #[test]
+    fn all_columns_dtype_skips_dtype_guessing() {
+        let range = range_from_rows(vec![
+            vec![string("a"), string("b"), string("c")],
+            vec![CalData::Float(1.0), CalData::Bool(true), string("x")],
+        ]);
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &Header::At(0),
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+        let dtypes = DTypeMap::from([(IdxOrName::Name("c".to_owned()), DType::Int)]);
+
+        let read_options = ReadOptions {
+            all_columns_dtype: Some(DType::String),
+            ..Default::default()
+        };
+
+        let columns = build_available_columns(
+            columns_info,
+            &range,
+            SampleRows::new(1, 2),
+            Some(&dtypes),
+            &DTypeCoercion::Strict,
+            &read_options,
+        )
+        .unwrap();
+
+        let column = |name: &str, index, dtype, dtype_from| {
+            ColumnInfo::new(
+                name.to_owned(),
+                index,
+                ColumnNameFrom::LookedUp,
+                dtype,
+                dtype_from,
+            )
+        };
+        assert_eq!(
+            columns,
+            vec![
+                column("a", 0, DType::String, DTypeFrom::ProvidedForAll),
+                column("b", 1, DType::String, DTypeFrom::ProvidedForAll),
+                column("c", 2, DType::Int, DTypeFrom::ProvidedByName),
+            ]
+        );
+    }
+
+    #[test]
+    fn datetime_columns_are_timezone_aware_with_a_timezone() {
+        let range = range_from_rows(vec![
+            vec![string("when"), string("amount")],
+            vec![
+                CalData::DateTimeIso("2023-05-01T12:00:00".to_owned()),
+                CalData::Float(1.0),
+            ],
+        ]);
+        let read_options = ReadOptions {
+            timezone: Some("+02:00".parse().unwrap()),
+            ..Default::default()
+        };
+
+        let columns = build_available_columns(
+            build_available_columns_info(
+                &range,
+                &SelectedColumns::All,
+                &Header::At(0),
+                DEFAULT_COLUMN_PREFIX,
+            )
+            .unwrap(),
+            &range,
+            SampleRows::new(1, 2),
+            None,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            columns
+                .iter()
+                .map(|column_info| *column_info.dtype())
+                .collect::<Vec<_>>(),
+            [DType::DateTimeTz, DType::Float]
+        );
+        assert_eq!(
+            DType::DateTimeTz.arrow_type(&read_options),
+            ArrowDataType::Timestamp(TimeUnit::Millisecond, Some("+02:00".into()))
+        );
+    }
+
+    #[rstest]
+    #[case(false, [DType::DateTime, DType::DateTime, DType::DateTime])]
+    #[case(true, [DType::Date, DType::DateTime, DType::DateTime])]
+    fn datetime_columns_at_midnight_are_dates_with_infer_dates(
+        #[case] infer_dates: bool,
+        #[case] expected: [DType; 3],
+    ) {
+        let range = range_from_rows(vec![
+            vec![string("day"), string("when"), string("provided")],
+            vec![
+                CalData::DateTimeIso("2023-05-01T00:00:00".to_owned()),
+                CalData::DateTimeIso("2023-05-01T00:00:00".to_owned()),
+                CalData::DateTimeIso("2023-05-01T00:00:00".to_owned()),
+            ],
+            vec![
+                CalData::Empty,
+                CalData::DateTimeIso("2023-05-02T12:00:00".to_owned()),
+                CalData::DateTimeIso("2023-05-02T00:00:00".to_owned()),
+            ],
+        ]);
+        let dtypes = DTypeMap::from([(IdxOrName::Name("provided".to_owned()), DType::DateTime)]);
+        let read_options = ReadOptions {
+            infer_dates,
+            ..Default::default()
+        };
+
+        let columns = build_available_columns(
+            build_available_columns_info(
+                &range,
+                &SelectedColumns::All,
+                &Header::At(0),
+                DEFAULT_COLUMN_PREFIX,
+            )
+            .unwrap(),
+            &range,
+            SampleRows::new(1, 3),
+            Some(&dtypes),
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            columns
+                .iter()
+                .map(|column_info| *column_info.dtype())
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(UnknownRenames::Ignore)]
+    #[case(UnknownRenames::Error)]
+    fn columns_are_renamed_before_being_aliased(#[case] unknown_renames: UnknownRenames) {
+        let range = range_from_rows(vec![vec![string("Amt"), string("amount"), string("b")]]);
+        let columns_info = build_available_columns_info(
+            &range,
+            &SelectedColumns::All,
+            &Header::At(0),
+            DEFAULT_COLUMN_PREFIX,
+        )
+        .unwrap();
+        let read_options = ReadOptions {
+            rename: HashMap::from([("Amt".to_owned(), "amount".to_owned())]),
+            unknown_renames,
+            ..Default::default()
+        };
+
+        let columns = build_available_columns(
+            columns_info,
+            &range,
+            SampleRows::new(1, 1),
+            None,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            columns.iter().map(ColumnInfo::name).collect::<Vec<_>>(),
+            vec!["amount", "amount_1", "b"]
+        );
+    }
+
+    #[test]
+    fn generated_names_use_the_column_prefix() {
+        let range = range_from_rows(vec![vec![string("a"), CalData::Float(1.0)]]);
+        let columns_info =
+            build_available_columns_info(&range, &SelectedColumns::All, &Header::None, "col_")
+                .unwrap();
+        let read_options = ReadOptions {
+            rename: HashMap::from([("col_0".to_owned(), "col_1".to_owned())]),
+            ..Default::default()
+        };
+
+        let columns = build_available_columns(
+            columns_info,
+            &range,
+            SampleRows::new(0, 1),
+            None,
+            &DTypeCoercion::Coerce,
+            &read_options,
+        )
+        .unwrap();
+
+        // The renamed first column collides with the generated name of the second one
+        assert_eq!(
+            columns.iter().map(ColumnInfo::name).collect::<Vec<_>>(),
+            vec!["col_1", "col_1_1"]
+        );
+    }
+
+    #[test]
+    fn renaming_unknown_columns() {
+        let range = range_from_rows(vec![vec![string("a")]]);
+        let build_columns = |unknown_renames| {
+            let columns_info = build_available_columns_info(
+                &range,
+                &SelectedColumns::All,
+                &Header::At(0),
+                DEFAULT_COLUMN_PREFIX,
+            )
+            .unwrap();
+            let read_options = ReadOptions {
+                rename: HashMap::from([("z".to_owned(), "y".to_owned())]),
+                unknown_renames,
+                ..Default::default()
+            };
+            build_available_columns(
+                columns_info,
+                &range,
+                SampleRows::new(1, 1),
+                None,
+                &DTypeCoercion::Coerce,
+                &read_options,
+            )
+        };
+
+        let columns = build_columns(UnknownRenames::Ignore).unwrap();
+        assert_eq!(columns[0].name(), "a");
+
+        let err = build_columns(UnknownRenames::Error).unwrap_err();
+        assert!(matches!(err.kind, FastExcelErrorKind::ColumnNotFound(_)));
+    }
+
+    #[test]
+    fn header_kept_in_data_is_the_first_data_row() {
+        let header = Header::At(2).keep_in_data().unwrap();
+
+        assert_eq!(header.offset(), 2);
+        assert_eq!(header.row(), Some(2));
+        assert!(Header::None.keep_in_data().is_err());
+    }
+
+    #[test]
+    fn header_depth_requires_a_header_row() {
+        assert!(Header::None.with_depth(2, " / ").is_err());
+        assert!(Header::At(0).with_depth(0, " / ").is_err());
+        assert!(matches!(
+            Header::None.with_depth(1, " / ").unwrap(),
+            Header::None
+        ));
+    }
+
+    #[test]
+    fn data_rows_are_selected_after_the_header() {
+        let range = range_from_rows(vec![
+            vec![string("a"), string("b")],
+            vec![CalData::Float(1.0), string("one")],
+            vec![CalData::Float(2.0), string("two")],
+            vec![CalData::Float(3.0), string("three")],
+        ]);
+
+        let selected = select_data_rows(&range, 1, &[3, 1]).unwrap();
+
+        assert_eq!(
+            selected.rows().collect::<Vec<_>>(),
+            vec![
+                &[string("a"), string("b")][..],
+                &[CalData::Float(3.0), string("three")],
+                &[CalData::Float(1.0), string("one")],
+            ]
+        );
+    }
+
+    #[rstest]
+    // The header row
+    #[case(0)]
+    // Beyond the sheet height
+    #[case(4)]
+    fn data_rows_out_of_range(#[case] row: usize) {
+        let range = range_from_rows(vec![vec![string("a")], vec![CalData::Float(1.0)]]);
+
+        let err = select_data_rows(&range, 1, &[1, row]).unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn transposed_range_swaps_rows_and_columns() {
+        // The range starts at B3
+        let range = Range::from_sparse(vec![
+            Cell::new((2, 1), string("name")),
+            Cell::new((2, 2), string("a")),
+            Cell::new((2, 3), string("b")),
+            Cell::new((3, 1), string("value")),
+            Cell::new((3, 3), CalData::Float(2.0)),
+        ]);
+
+        let transposed = transpose_range(&range);
+
+        assert_eq!(transposed.start(), Some((1, 2)));
+        assert_eq!(
+            transposed.rows().collect::<Vec<_>>(),
+            vec![
+                &[string("name"), string("value")][..],
+                &[string("a"), CalData::Empty],
+                &[string("b"), CalData::Float(2.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn hidden_rows_are_not_visible_data_rows() {
+        // The range starts on the second row of the sheet
+        let mut range = Range::new((1, 0), (4, 0));
+        for row in 1..5 {
+            range.set_value((row, 0), CalData::Float(row as f64));
+        }
+        let hidden_rows = HashSet::from([2, 4]);
+
+        assert_eq!(visible_data_rows(&range, 1, None, &hidden_rows), vec![2]);
+        assert_eq!(
+            visible_data_rows(&range, 1, Some(&[3, 2, 1]), &hidden_rows),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn detect_header_row_no_header() {
+        let only_numbers = range_from_rows(vec![
+            vec![CalData::Float(1.0), CalData::Float(2.0)],
+            vec![CalData::Float(3.0), CalData::Float(4.0)],
+        ]);
+        assert_eq!(detect_header_row(&only_numbers), None);
+
+        let only_strings = range_from_rows(vec![
+            vec![string("a"), string("b")],
+            vec![string("c"), string("d")],
+        ]);
+        assert_eq!(detect_header_row(&only_strings), None);
+    }
+
+    #[test]
+    fn validate_header_flags_suspicious_header_cells() {
+        let range = range_from_rows(vec![
+            vec![string("Name"), CalData::Float(2019.0), CalData::Float(1.0)],
+            vec![string("Alice"), string("yes"), CalData::Float(2.0)],
+        ]);
+        let columns: Vec<_> = [DType::String, DType::String, DType::Float]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, dtype)| {
+                ColumnInfo::new(
+                    format!("col{idx}"),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    dtype,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+
+        let warnings = validate_header(&range, &Header::At(0), &columns);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| warning.column_index)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        assert!(validate_header(&range, &Header::None, &columns).is_empty());
+    }
+
+    #[rstest]
+    #[case(2, "[col0:int, col1:int]")]
+    #[case(7, "[col0:int, col1:int, col2:int, col3:int, col4:int, ...]")]
+    fn columns_preview_lists_the_first_columns(#[case] width: usize, #[case] expected: &str) {
+        let columns: Vec<_> = (0..width)
+            .map(|idx| {
+                ColumnInfo::new(
+                    format!("col{idx}"),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    DType::Int,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect();
+
+        assert_eq!(columns_preview(&columns), expected);
+    }
+
+    #[rstest]
+    #[case(Header::At(1), 3, Ok(1))]
+    #[case(Header::At(1), 2, Ok(0))]
+    #[case(Header::At(1), 1, Err("must be greater than the header rows"))]
+    #[case(Header::At(1), 0, Err("must be greater than the header rows"))]
+    #[case(
+        Header::MultiRow { row: 0, depth: 2, separator: " / ".to_owned() },
+        1,
+        Err("must be greater than the header rows")
+    )]
+    #[case(Header::None, 3, Err("can only be used with a header_row"))]
+    fn gap_between_the_header_and_the_data(
+        #[case] header: Header,
+        #[case] data_start_row: usize,
+        #[case] expected: Result<usize, &str>,
+    ) {
+        match (header.gap_before(data_start_row), expected) {
+            (Ok(gap), Ok(expected)) => assert_eq!(gap, expected),
+            (Err(err), Err(message)) => assert!(
+                err.to_string().contains(message),
+                "expected \"{err}\" to contain \"{message}\""
+            ),
+            (result, expected) => panic!("expected {expected:?}, got {result:?}"),
+        }
+    }
 }
@@ -0,0 +1,129 @@
+use crate::error::column_letters;
+
+/// Maps the cells of a loaded range back to their position in the worksheet, which differs when
+/// data rows were selected or when the range was transposed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SheetPositions {
+    /// The absolute position of the top-left cell of the loaded range
+    start: (usize, usize),
+    /// The number of header rows of the loaded range
+    header_offset: usize,
+    /// The rows of the source range the data rows were selected from, relative to its start
+    data_rows: Option<Vec<usize>>,
+    /// Whether the rows and the columns of the source range were swapped
+    transposed: bool,
+}
+
+impl SheetPositions {
+    pub(crate) fn new(start: Option<(u32, u32)>, header_offset: usize) -> Self {
+        let (start_row, start_col) = start.unwrap_or((0, 0));
+        Self {
+            start: (start_row as usize, start_col as usize),
+            header_offset,
+            ..Default::default()
+        }
+    }
+
+    /// Records that the data rows were selected from the given rows of the source range, as done
+    /// by `select_data_rows`
+    pub(crate) fn with_data_rows(mut self, data_rows: Vec<usize>) -> Self {
+        self.data_rows = Some(data_rows);
+        self
+    }
+
+    /// Records that the loaded range is the transposition of the source range
+    pub(crate) fn transposed(mut self) -> Self {
+        self.transposed = true;
+        self
+    }
+
+    /// Converts a (row, column) position relative to the start of the loaded range to an absolute
+    /// (row, column) position in the worksheet
+    pub(crate) fn sheet_position(&self, row: usize, col: usize) -> (usize, usize) {
+        let row = match &self.data_rows {
+            Some(data_rows) if row >= self.header_offset => data_rows
+                .get(row - self.header_offset)
+                .copied()
+                .unwrap_or(row),
+            _ => row,
+        };
+        let position = (self.start.0 + row, self.start.1 + col);
+        if self.transposed {
+            (position.1, position.0)
+        } else {
+            position
+        }
+    }
+
+    /// Converts a (row, column) position relative to the start of the loaded range to an A1 cell
+    /// reference of the worksheet, e.g. "AB12"
+    pub(crate) fn cell_reference(&self, row: usize, col: usize) -> String {
+        let (row, col) = self.sheet_position(row, col);
+        format!("{}{}", column_letters(col), row + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{open_workbook_auto, Reader};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // The range starts at A1, with its header in row 1
+    #[case(None, 1, (1, 0), "A2")]
+    #[case(None, 1, (3, 26), "AA4")]
+    // The range starts at C5, with two header rows
+    #[case(Some((4, 2)), 2, (2, 0), "C7")]
+    #[case(Some((4, 2)), 2, (2, 25), "AB7")]
+    // The range starts at Z10, without header
+    #[case(Some((9, 25)), 0, (0, 2), "AB10")]
+    fn cell_references_are_absolute(
+        #[case] start: Option<(u32, u32)>,
+        #[case] header_offset: usize,
+        #[case] (row, col): (usize, usize),
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            SheetPositions::new(start, header_offset).cell_reference(row, col),
+            expected
+        );
+    }
+
+    #[test]
+    fn selected_data_rows_keep_their_source_row() {
+        // The header is in row 3, and the data rows 6 and 4 were selected in this order
+        let positions = SheetPositions::new(Some((2, 0)), 1).with_data_rows(vec![3, 1]);
+
+        assert_eq!(positions.sheet_position(0, 0), (2, 0));
+        assert_eq!(positions.cell_reference(1, 27), "AB6");
+        assert_eq!(positions.cell_reference(2, 27), "AB4");
+    }
+
+    #[test]
+    fn transposed_positions_are_swapped() {
+        // The source range starts at B3, so the transposed range starts at (1, 2)
+        let positions = SheetPositions::new(Some((1, 2)), 1).transposed();
+
+        // The second row is the second column of the source range, and the third column its third
+        // row
+        assert_eq!(positions.sheet_position(1, 2), (4, 2));
+        assert_eq!(positions.cell_reference(1, 2), "C5");
+    }
+
+    #[test]
+    fn cell_references_of_a_workbook() {
+        // The sheet has a title in A1, and a table with a header in Y3:AB3
+        let mut sheets =
+            open_workbook_auto("python/tests/fixtures/fixture-wide-columns.xlsx").unwrap();
+        let range = sheets.worksheet_range("Sheet1").unwrap();
+        let positions = SheetPositions::new(range.start(), 3);
+
+        assert_eq!(range.get((5, 27)).unwrap().to_string(), "8");
+        assert_eq!(positions.sheet_position(3, 0), (3, 0));
+        assert_eq!(positions.cell_reference(5, 27), "AB6");
+        assert_eq!(positions.cell_reference(3, 26), "AA4");
+    }
+}
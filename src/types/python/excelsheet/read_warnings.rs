@@ -0,0 +1,294 @@
+use std::fmt::{Debug, Display};
+
+use arrow::{array::Array, record_batch::RecordBatch};
+use calamine::{CellType, DataType, Range};
+use pyo3::{pyclass, pymethods};
+
+use crate::{
+    data::ExcelSheetData,
+    error::CellLocation,
+    types::dtype::{get_cell_dtype, DType},
+    utils::schema::SampleRows,
+};
+
+use super::column_info::{ColumnInfo, DTypeFrom};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ReadWarningKind {
+    /// The column contains cells of several types, which were coerced to the column's dtype
+    MixedTypes,
+    /// Cells of the column could not be converted to the column's dtype, and were read as null
+    NullOnConversion,
+}
+
+impl Display for ReadWarningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ReadWarningKind::MixedTypes => "mixed_types",
+            ReadWarningKind::NullOnConversion => "null_on_conversion",
+        })
+    }
+}
+
+/// Something that silently changed the data of a column while reading a sheet
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(name = "ReadWarning")]
+pub(crate) struct ReadWarning {
+    kind: ReadWarningKind,
+    /// `str`. The name of the column
+    #[pyo3(get)]
+    column_name: String,
+    /// `int`. The index of the column
+    #[pyo3(get)]
+    column_index: usize,
+    /// `int`. The row of the first cell concerned, in the sheet, starting at 0
+    #[pyo3(get)]
+    row: usize,
+    /// `int`. The number of cells concerned
+    #[pyo3(get)]
+    cell_count: usize,
+    dtype: DType,
+    /// The column of the first cell concerned, in the sheet
+    sheet_col: usize,
+}
+
+impl ReadWarning {
+    pub(crate) fn message(&self) -> String {
+        let location = CellLocation {
+            position: Some((self.row, self.sheet_col)),
+            ..Default::default()
+        };
+        match self.kind {
+            ReadWarningKind::MixedTypes => format!(
+                "column \"{name}\" has mixed types and was coerced to {dtype}: {count} cell(s) differ from the first one, starting at {location}",
+                name = self.column_name,
+                dtype = self.dtype,
+                count = self.cell_count,
+            ),
+            ReadWarningKind::NullOnConversion => format!(
+                "{count} cell(s) of column \"{name}\" could not be read as {dtype} and are null, starting at {location}",
+                name = self.column_name,
+                dtype = self.dtype,
+                count = self.cell_count,
+            ),
+        }
+    }
+}
+
+#[pymethods]
+impl ReadWarning {
+    /// `str`. The kind of warning, `"mixed_types"` or `"null_on_conversion"`
+    #[getter(kind)]
+    fn get_kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// `fastexcel.DType`. The dtype of the column
+    #[getter(dtype)]
+    fn get_dtype(&self) -> String {
+        self.dtype.to_string()
+    }
+
+    /// `str`. A human-readable description of the warning
+    #[getter(message)]
+    fn get_message(&self) -> String {
+        self.message()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ReadWarning(kind=\"{kind}\", column_name=\"{column_name}\", column_index={column_index}, row={row}, cell_count={cell_count}, dtype=\"{dtype}\")",
+            kind = self.kind,
+            column_name = self.column_name,
+            column_index = self.column_index,
+            row = self.row,
+            cell_count = self.cell_count,
+            dtype = self.dtype,
+        )
+    }
+}
+
+/// Returns a warning for every column whose dtype was guessed from cells of several types, in the
+/// rows used to guess dtypes
+pub(crate) fn dtype_warnings(
+    data: &ExcelSheetData,
+    columns: &[ColumnInfo],
+    sample_rows: SampleRows,
+) -> Vec<ReadWarning> {
+    columns
+        .iter()
+        .filter(|column_info| column_info.dtype_from() == &DTypeFrom::Guessed)
+        .filter_map(|column_info| match data {
+            ExcelSheetData::Owned(range) => mixed_types_warning(range, column_info, sample_rows),
+            ExcelSheetData::Ref(range) => mixed_types_warning(range, column_info, sample_rows),
+        })
+        .collect()
+}
+
+/// Returns a warning for every column of `record_batch` containing nulls where the sheet
+/// contains a value. `record_batch` must contain `columns`, read from `offset` to `limit`
+pub(crate) fn conversion_warnings(
+    data: &ExcelSheetData,
+    columns: &[ColumnInfo],
+    offset: usize,
+    limit: usize,
+    record_batch: &RecordBatch,
+) -> Vec<ReadWarning> {
+    columns
+        .iter()
+        .zip(record_batch.columns())
+        .filter_map(|(column_info, array)| match data {
+            ExcelSheetData::Owned(range) => {
+                null_on_conversion_warning(range, column_info, offset, limit, array.as_ref())
+            }
+            ExcelSheetData::Ref(range) => {
+                null_on_conversion_warning(range, column_info, offset, limit, array.as_ref())
+            }
+        })
+        .collect()
+}
+
+fn mixed_types_warning<DT: CellType + Debug + DataType>(
+    data: &Range<DT>,
+    column_info: &ColumnInfo,
+    sample_rows: SampleRows,
+) -> Option<ReadWarning> {
+    let col = column_info.index();
+    // Cells whose dtype cannot be determined are reported when reading the column
+    let mut cell_dtypes = sample_rows.rows().filter_map(|row| {
+        get_cell_dtype(data, row, col)
+            .ok()
+            .filter(|dtype| dtype != &DType::Null)
+            .map(|dtype| (row, dtype))
+    });
+    let (_, first_dtype) = cell_dtypes.next()?;
+    let mut differing_rows = cell_dtypes
+        .filter(|(_, dtype)| dtype != &first_dtype)
+        .map(|(row, _)| row);
+    let first_row = differing_rows.next()?;
+    Some(ReadWarning {
+        kind: ReadWarningKind::MixedTypes,
+        column_name: column_info.name().to_owned(),
+        column_index: col,
+        row: absolute_row(data, first_row),
+        cell_count: 1 + differing_rows.count(),
+        dtype: *column_info.dtype(),
+        sheet_col: absolute_col(data, col),
+    })
+}
+
+fn null_on_conversion_warning<DT: CellType + Debug + DataType>(
+    data: &Range<DT>,
+    column_info: &ColumnInfo,
+    offset: usize,
+    limit: usize,
+    array: &dyn Array,
+) -> Option<ReadWarning> {
+    let col = column_info.index();
+    // Empty cells and null-like values are expected to be null
+    let mut nulled_rows = (offset..limit).filter(|&row| {
+        array.is_null(row - offset)
+            && data.get((row, col)).is_some()
+            && !matches!(get_cell_dtype(data, row, col), Ok(DType::Null))
+    });
+    let first_row = nulled_rows.next()?;
+    Some(ReadWarning {
+        kind: ReadWarningKind::NullOnConversion,
+        column_name: column_info.name().to_owned(),
+        column_index: col,
+        row: absolute_row(data, first_row),
+        cell_count: 1 + nulled_rows.count(),
+        dtype: *column_info.dtype(),
+        sheet_col: absolute_col(data, col),
+    })
+}
+
+fn absolute_row<DT: CellType>(data: &Range<DT>, row: usize) -> usize {
+    data.start().map_or(0, |(start_row, _)| start_row as usize) + row
+}
+
+fn absolute_col<DT: CellType>(data: &Range<DT>, col: usize) -> usize {
+    data.start().map_or(0, |(_, start_col)| start_col as usize) + col
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::Float64Array;
+    use calamine::{Cell, CellErrorType, Data as CalData};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::python::excelsheet::column_info::ColumnNameFrom;
+
+    fn range() -> Range<CalData> {
+        // The range starts at B2
+        Range::from_sparse(vec![
+            Cell::new((1, 1), CalData::Float(1.0)),
+            Cell::new((2, 1), CalData::String("NULL".to_owned())),
+            Cell::new((3, 1), CalData::String("two".to_owned())),
+            Cell::new((4, 1), CalData::Error(CellErrorType::Div0)),
+            Cell::new((5, 1), CalData::String("four".to_owned())),
+        ])
+    }
+
+    fn column(dtype: DType, dtype_from: DTypeFrom) -> ColumnInfo {
+        ColumnInfo::new(
+            "value".to_owned(),
+            0,
+            ColumnNameFrom::LookedUp,
+            dtype,
+            dtype_from,
+        )
+    }
+
+    #[test]
+    fn mixed_types_are_reported_for_guessed_dtypes_only() {
+        let range = range();
+
+        let warning = mixed_types_warning(
+            &range,
+            &column(DType::String, DTypeFrom::Guessed),
+            SampleRows::new(0, 3),
+        )
+        .unwrap();
+        assert_eq!(warning.kind, ReadWarningKind::MixedTypes);
+        assert_eq!((warning.row, warning.sheet_col), (3, 1));
+        assert_eq!(warning.cell_count, 1);
+
+        let data = ExcelSheetData::from(range);
+        assert_eq!(
+            dtype_warnings(
+                &data,
+                &[column(DType::String, DTypeFrom::ProvidedByName)],
+                SampleRows::new(0, 3)
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn null_on_conversion_ignores_null_like_values() {
+        let range = range();
+        // As read for a float column, with the strings and the error cell as nulls
+        let array = Arc::new(Float64Array::from(vec![Some(1.0), None, None, None, None]));
+
+        let warning = null_on_conversion_warning(
+            &range,
+            &column(DType::Float, DTypeFrom::ProvidedByName),
+            0,
+            5,
+            array.as_ref(),
+        )
+        .unwrap();
+
+        assert_eq!(warning.kind, ReadWarningKind::NullOnConversion);
+        assert_eq!((warning.row, warning.sheet_col), (3, 1));
+        assert_eq!(warning.cell_count, 3);
+        assert_eq!(
+            warning.message(),
+            "3 cell(s) of column \"value\" could not be read as float and are null, starting at cell B4 (row 3, column 1)"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use pyo3::{
+    pyclass, pymethods,
+    types::{PyDict, PyDictMethods},
+    Bound, Py, PyObject, PyRef, PyResult, Python,
+};
+
+use crate::error::{py_errors::IntoPyResult, ErrorContext};
+
+use super::ExcelSheet;
+
+/// Iterator over the rows of a sheet, yielding dicts keyed by column name. Formula columns
+/// follow their column, as in record batches
+#[pyclass(name = "_ExcelSheetRows")]
+pub(crate) struct ExcelSheetRows {
+    sheet: Py<ExcelSheet>,
+    row: usize,
+    limit: usize,
+    /// The last non-null value of every selected column, which forward-filled columns continue
+    previous_values: Vec<Option<PyObject>>,
+}
+
+impl ExcelSheetRows {
+    pub(crate) fn new(sheet: Py<ExcelSheet>, offset: usize, limit: usize) -> Self {
+        Self {
+            sheet,
+            row: offset,
+            limit,
+            previous_values: Vec::new(),
+        }
+    }
+}
+
+#[pymethods]
+impl ExcelSheetRows {
+    pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if self.row >= self.limit {
+            return Ok(None);
+        }
+        let sheet = self.sheet.borrow(py);
+        let mut values = sheet
+            .data()
+            .row_to_py_values(
+                self.row,
+                &sheet.selected_columns,
+                &sheet.dtype_coercion,
+                &sheet.read_options,
+                py,
+            )
+            .with_context(|| {
+                format!(
+                    "could not read row {row} of sheet \"{sheet_name}\"",
+                    row = self.row,
+                    sheet_name = sheet.name()
+                )
+            })
+            .into_pyresult()?;
+
+        self.previous_values
+            .resize_with(sheet.selected_columns.len(), || None);
+        for ((column_info, value), previous_value) in sheet
+            .selected_columns
+            .iter()
+            .zip(values.iter_mut())
+            .zip(self.previous_values.iter_mut())
+        {
+            if !sheet.read_options.is_forward_filled(column_info) {
+                continue;
+            }
+            if !value.is_none(py) {
+                *previous_value = Some(value.clone_ref(py));
+            } else if let Some(previous_value) = previous_value {
+                *value = previous_value.clone_ref(py);
+            }
+        }
+
+        let row = PyDict::new_bound(py);
+        for (idx, (column_info, value)) in sheet.selected_columns.iter().zip(values).enumerate() {
+            row.set_item(column_info.name(), value)?;
+            if let Some(formulas) = &sheet.formulas {
+                row.set_item(
+                    &formulas.names()[idx],
+                    formulas.formula(self.row, column_info.index()),
+                )?;
+            }
+        }
+        self.row += 1;
+        Ok(Some(row))
+    }
+}
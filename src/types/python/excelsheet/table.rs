@@ -1,7 +1,29 @@
-use crate::error::{FastExcelError, FastExcelErrorKind, FastExcelResult};
+use crate::{
+    error::{column_letters, FastExcelError, FastExcelErrorKind, FastExcelResult},
+    types::read_options::DEFAULT_COLUMN_PREFIX,
+};
 use calamine::{Data, Sheets, Table};
 use std::io::{Read, Seek};
 
+/// The name of the format of a workbook, e.g. "XLSB"
+fn format_name<RS: Read + Seek>(sheets: &Sheets<RS>) -> &'static str {
+    match sheets {
+        Sheets::Xls(_) => "XLS",
+        Sheets::Xlsx(_) => "XLSX",
+        Sheets::Xlsb(_) => "XLSB",
+        Sheets::Ods(_) => "ODS",
+    }
+}
+
+/// calamine only reads the tables of XLSX workbooks
+fn unsupported_format_error<RS: Read + Seek>(sheets: &Sheets<RS>) -> FastExcelError {
+    FastExcelErrorKind::InvalidParameters(format!(
+        "tables are not supported for {} files, only for XLSX files",
+        format_name(sheets)
+    ))
+    .into()
+}
+
 pub(crate) fn extract_table_names<'a, RS: Read + Seek>(
     sheets: &'a mut Sheets<RS>,
     sheet_name: Option<&str>,
@@ -16,13 +38,71 @@ pub(crate) fn extract_table_names<'a, RS: Read + Seek>(
                 Some(sn) => Ok(xlsx.table_names_in_sheet(sn)),
             }
         }
-        _ => Err(FastExcelErrorKind::Internal(
-            "Currently only XLSX files are supported for tables".to_string(),
-        )
-        .into()),
+        _ => Err(unsupported_format_error(sheets)),
+    })
+}
+
+/// The name, sheet name and data range of a table
+pub(crate) type TableInfo = (String, String, String);
+
+/// Returns the name, sheet name and data range (e.g. "A2:F100", excluding the header and totals
+/// rows) of the tables of a workbook, or of a given sheet
+pub(crate) fn extract_table_infos<RS: Read + Seek>(
+    sheets: &mut Sheets<RS>,
+    sheet_name: Option<&str>,
+) -> Result<FastExcelResult<Vec<TableInfo>>, FastExcelError> {
+    Ok(match sheets {
+        Sheets::Xlsx(xlsx) => {
+            // Internally checks if tables already loaded; is fast
+            xlsx.load_tables()?;
+
+            let table_names: Vec<String> = match sheet_name {
+                None => xlsx.table_names(),
+                Some(sn) => xlsx.table_names_in_sheet(sn),
+            }
+            .into_iter()
+            .cloned()
+            .collect();
+            table_names
+                .into_iter()
+                .map(|name| {
+                    let table = xlsx.table_by_name(&name)?;
+                    let data_range = match (table.data().start(), table.data().end()) {
+                        (Some(start), Some(end)) => {
+                            format!("{}:{}", cell_reference(start), cell_reference(end))
+                        }
+                        _ => String::new(),
+                    };
+                    Ok((name, table.sheet_name().to_owned(), data_range))
+                })
+                .collect()
+        }
+        _ => Err(unsupported_format_error(sheets)),
     })
 }
 
+/// The column names of a table, from its definition. Blank names are replaced with generated
+/// ones, as for sheets. Duplicate names are aliased later on, along with the sheets' names
+pub(crate) fn table_column_names(table: &Table<Data>) -> Vec<String> {
+    table
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            if name.trim().is_empty() {
+                format!("{DEFAULT_COLUMN_PREFIX}{idx}")
+            } else {
+                name.to_owned()
+            }
+        })
+        .collect()
+}
+
+/// Converts an absolute (row, column) position to an A1 cell reference
+fn cell_reference((row, col): (u32, u32)) -> String {
+    format!("{}{}", column_letters(col as usize), row + 1)
+}
+
 pub(crate) fn extract_table_range<RS: Read + Seek>(
     name: &str,
     sheets: &mut Sheets<RS>,
@@ -37,9 +117,56 @@ pub(crate) fn extract_table_range<RS: Read + Seek>(
 
             Ok(table)
         }
-        _ => Err(FastExcelErrorKind::Internal(
-            "Currently only XLSX files are supported for tables".to_string(),
-        )
-        .into()),
+        _ => Err(unsupported_format_error(sheets)),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use calamine::open_workbook_auto;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn blank_table_column_names_are_generated() {
+        let mut sheets =
+            open_workbook_auto("python/tests/fixtures/table-with-duplicate-column-names.xlsx")
+                .unwrap();
+
+        let table = extract_table_range("measures", &mut sheets)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            table_column_names(&table),
+            vec!["id", "value", "value", "__UNNAMED__3"]
+        );
+    }
+
+    #[test]
+    fn tables_of_other_formats_are_unsupported() {
+        let mut sheets = open_workbook_auto("python/tests/fixtures/dates.ods").unwrap();
+
+        let err = extract_table_names(&mut sheets, None).unwrap().unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+        assert_eq!(
+            err.to_string(),
+            "invalid parameters: tables are not supported for ODS files, only for XLSX files"
+        );
+    }
+
+    #[test]
+    fn table_infos_contain_the_data_range() {
+        let mut sheets =
+            open_workbook_auto("python/tests/fixtures/sheet-with-tables.xlsx").unwrap();
+
+        let table_infos = extract_table_infos(&mut sheets, None).unwrap().unwrap();
+
+        assert_eq!(
+            table_infos,
+            vec![("users".to_owned(), "sheet1".to_owned(), "A7:D9".to_owned())]
+        );
+    }
+}
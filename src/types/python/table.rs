@@ -1,31 +1,27 @@
-use std::sync::Arc;
-
-use arrow::{
-    array::{NullArray, RecordBatch},
-    pyarrow::ToPyArrow,
-};
+use arrow::{array::RecordBatch, datatypes::Schema, pyarrow::ToPyArrow};
 use calamine::{Data, Range, Table};
-use pyo3::{pyclass, pymethods, PyObject, PyResult, Python, ToPyObject};
+use pyo3::{pyclass, pymethods, Bound, PyAny, PyObject, PyResult, Python, ToPyObject};
 
 use crate::{
     data::{
-        create_boolean_array_from_range, create_date_array_from_range,
-        create_datetime_array_from_range, create_duration_array_from_range,
-        create_float_array_from_range, create_int_array_from_range, create_string_array_from_range,
-        record_batch_from_name_array_iterator, selected_columns_to_schema,
+        record_batch_from_range_and_columns, selected_columns_to_schema, with_nullability_of,
+        with_tight_nullability,
     },
     error::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
     types::{
-        dtype::{DType, DTypeCoercion, DTypeMap},
+        dtype::{DTypeCoercion, DTypeMap},
+        idx_or_name::IdxOrName,
         python::excelsheet::column_info::build_available_columns,
+        read_options::ReadOptions,
     },
-    utils::schema::get_schema_sample_rows,
+    utils::schema::{get_schema_sample_rows, SchemaSampleRows},
 };
 
 use super::excelsheet::{
-    column_info::{build_available_columns_info, ColumnInfo},
+    column::{find_selected_column, ArrowColumn},
+    column_info::{build_available_columns_info, columns_preview, ColumnInfo},
     Header, Pagination, SelectedColumns,
 };
 
@@ -38,27 +34,37 @@ pub(crate) struct ExcelTable {
     selected_columns: Vec<ColumnInfo>,
     available_columns: Vec<ColumnInfo>,
     table: Table<Data>,
+    /// The data of the table including its header rows, when they are read as data rows
+    data_with_header_rows: Option<Range<Data>>,
     header: Header,
     pagination: Pagination,
     dtypes: Option<DTypeMap>,
     dtype_coercion: DTypeCoercion,
+    read_options: ReadOptions,
     height: Option<usize>,
     total_height: Option<usize>,
     width: Option<usize>,
 }
 
 impl ExcelTable {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn try_new(
         table: Table<Data>,
+        data_with_header_rows: Option<Range<Data>>,
         header: Header,
         pagination: Pagination,
-        schema_sample_rows: Option<usize>,
+        schema_sample_rows: Option<SchemaSampleRows>,
         dtype_coercion: DTypeCoercion,
         selected_columns: SelectedColumns,
         dtypes: Option<DTypeMap>,
+        read_options: ReadOptions,
     ) -> FastExcelResult<Self> {
-        let available_columns_info =
-            build_available_columns_info(table.data(), &selected_columns, &header)?;
+        let available_columns_info = build_available_columns_info(
+            data_with_header_rows.as_ref().unwrap_or(table.data()),
+            &selected_columns,
+            &header,
+            read_options.column_prefix(),
+        )?;
 
         let mut excel_table = ExcelTable {
             name: table.name().to_owned(),
@@ -67,17 +73,20 @@ impl ExcelTable {
             available_columns: Vec::with_capacity(0),
             selected_columns: Vec::with_capacity(0),
             table,
+            data_with_header_rows,
             header,
             pagination,
             dtypes,
             dtype_coercion,
+            read_options,
             height: None,
             total_height: None,
             width: None,
         };
 
-        let row_limit = get_schema_sample_rows(
+        let sample_rows = get_schema_sample_rows(
             schema_sample_rows,
+            excel_table.read_options.sample_strategy,
             excel_table.offset(),
             excel_table.limit(),
         );
@@ -86,14 +95,22 @@ impl ExcelTable {
         let available_columns = build_available_columns(
             available_columns_info,
             excel_table.data(),
-            excel_table.offset(),
-            row_limit,
+            sample_rows,
             excel_table.dtypes.as_ref(),
             &excel_table.dtype_coercion,
-        )?;
+            &excel_table.read_options,
+        )
+        .with_cell_location(|location| {
+            location.sheet_name = Some(excel_table.sheet_name.clone())
+        })?;
 
         // Figure out dtype for every column
-        let selected_columns = selected_columns.select_columns(&available_columns)?;
+        let selected_columns = excel_table
+            .read_options
+            .reorder_columns(selected_columns.select_columns(&available_columns)?)?;
+        excel_table
+            .read_options
+            .validate_fill_values(&selected_columns)?;
         excel_table.available_columns = available_columns;
         excel_table.selected_columns = selected_columns;
 
@@ -101,7 +118,26 @@ impl ExcelTable {
     }
 
     pub(crate) fn data(&self) -> &Range<Data> {
-        self.table.data()
+        self.data_with_header_rows
+            .as_ref()
+            .unwrap_or(self.table.data())
+    }
+
+    /// The arrow schema of the selected columns. Does not require to read the table's data, unless
+    /// `tighten_nullability` is set
+    fn arrow_schema(&self) -> Schema {
+        let schema = selected_columns_to_schema(&self.selected_columns, &self.read_options);
+        if !self.read_options.tighten_nullability {
+            return schema;
+        }
+        with_tight_nullability(
+            schema,
+            &self.selected_columns,
+            self.data(),
+            self.offset(),
+            self.limit(),
+            &self.read_options,
+        )
     }
 }
 
@@ -109,63 +145,21 @@ impl TryFrom<&ExcelTable> for RecordBatch {
     type Error = FastExcelError;
 
     fn try_from(table: &ExcelTable) -> FastExcelResult<Self> {
-        let offset = table.offset();
-        let limit = table.limit();
-
-        let iter = table.selected_columns.iter().map(|column_info| {
-            (
-                column_info.name(),
-                match column_info.dtype() {
-                    DType::Bool => create_boolean_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::Int => create_int_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::Float => create_float_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::String => create_string_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::DateTime => create_datetime_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::Date => create_date_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::Duration => create_duration_array_from_range(
-                        table.data(),
-                        column_info.index(),
-                        offset,
-                        limit,
-                    ),
-                    DType::Null => Arc::new(NullArray::new(limit - offset)),
-                },
-            )
-        });
-
-        let schema = selected_columns_to_schema(&table.selected_columns);
-
-        record_batch_from_name_array_iterator(iter, schema).with_context(|| {
+        record_batch_from_range_and_columns(
+            &table.selected_columns,
+            table.data(),
+            table.offset(),
+            table.limit(),
+            &table.dtype_coercion,
+            &table.read_options,
+        )
+        .and_then(|rb| {
+            if !table.read_options.tighten_nullability {
+                return Ok(rb);
+            }
+            with_nullability_of(rb, &table.arrow_schema())
+        })
+        .with_context(|| {
             format!(
                 "could not convert table {table} in sheet {sheet} to RecordBatch",
                 table = &table.name,
@@ -184,7 +178,8 @@ impl ExcelTable {
 
     #[getter]
     pub(crate) fn limit(&self) -> usize {
-        let upper_bound = self.data().height();
+        // The header and skipped rows may go beyond the end of the data
+        let upper_bound = std::cmp::max(self.data().height(), self.offset());
         if let Some(n_rows) = self.pagination.n_rows() {
             let limit = self.offset() + n_rows;
             if limit < upper_bound {
@@ -231,12 +226,25 @@ impl ExcelTable {
     #[getter]
     pub fn total_height(&mut self) -> usize {
         self.total_height.unwrap_or_else(|| {
-            let total_height = self.data().height() - self.header.offset();
+            let total_height = self.data().height().saturating_sub(self.header.offset());
             self.total_height = Some(total_height);
             total_height
         })
     }
 
+    #[getter]
+    pub fn schema(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.arrow_schema()
+            .to_pyarrow(py)
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            .into_pyresult()
+    }
+
+    pub fn schema_and_arrow(&self, py: Python<'_>) -> PyResult<(PyObject, PyObject)> {
+        let rb = RecordBatch::try_from(self).into_pyresult()?;
+        Ok((rb.schema_ref().to_pyarrow(py)?, rb.to_pyarrow(py)?))
+    }
+
     pub fn to_arrow(&self, py: Python<'_>) -> PyResult<PyObject> {
         RecordBatch::try_from(self)
             .with_context(|| {
@@ -258,11 +266,40 @@ impl ExcelTable {
             .into_pyresult()
     }
 
+    /// Converts a single selected column to an `ArrowColumn`
+    pub fn column(&self, idx_or_name: &Bound<'_, PyAny>) -> PyResult<ArrowColumn> {
+        let idx_or_name: IdxOrName = idx_or_name.try_into().into_pyresult()?;
+        find_selected_column(&self.selected_columns, &idx_or_name)
+            .and_then(|column_info| {
+                record_batch_from_range_and_columns(
+                    std::slice::from_ref(column_info),
+                    self.data(),
+                    self.offset(),
+                    self.limit(),
+                    &self.dtype_coercion,
+                    &self.read_options,
+                )
+            })
+            .and_then(ArrowColumn::try_from_record_batch)
+            .with_context(|| {
+                format!(
+                    "could not read column {column} of table \"{table}\" in sheet \"{sheet}\"",
+                    column = idx_or_name.format_message(),
+                    table = self.name,
+                    sheet = self.sheet_name
+                )
+            })
+            .into_pyresult()
+    }
+
     pub fn __repr__(&self) -> String {
         format!(
-            "ExcelTable<{sheet}/{name}>",
+            "ExcelTable<{sheet}/{name}, {height}x{width}, {columns}>",
             sheet = self.sheet_name,
-            name = self.name
+            name = self.name,
+            height = self.limit() - self.offset(),
+            width = self.selected_columns.len(),
+            columns = columns_preview(&self.selected_columns),
         )
     }
 }
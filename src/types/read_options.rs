@@ -0,0 +1,948 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use arrow::datatypes::TimeUnit;
+use chrono::{
+    DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone,
+};
+use chrono_tz::Tz;
+use pyo3::{prelude::PyAnyMethods, types::PyString, Bound, FromPyObject, PyAny, PyResult};
+
+use crate::error::{
+    py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
+};
+
+use super::{
+    dtype::{DType, UTC_TIMEZONE},
+    idx_or_name::IdxOrName,
+    python::excelsheet::column_info::ColumnInfo,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum TimestampUnit {
+    #[default]
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl FromStr for TimestampUnit {
+    type Err = FastExcelError;
+
+    fn from_str(raw_unit: &str) -> FastExcelResult<Self> {
+        match raw_unit {
+            "ms" => Ok(Self::Millisecond),
+            "us" => Ok(Self::Microsecond),
+            "ns" => Ok(Self::Nanosecond),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported timestamp_unit: \"{raw_unit}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for TimestampUnit {
+    fn extract_bound(py_unit: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(unit_pystr) = py_unit.extract::<&PyString>() {
+            unit_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_unit:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+impl From<TimestampUnit> for TimeUnit {
+    fn from(unit: TimestampUnit) -> Self {
+        match unit {
+            TimestampUnit::Millisecond => TimeUnit::Millisecond,
+            TimestampUnit::Microsecond => TimeUnit::Microsecond,
+            TimestampUnit::Nanosecond => TimeUnit::Nanosecond,
+        }
+    }
+}
+
+/// What to do with the columns of `rename` that are not part of the header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum UnknownRenames {
+    #[default]
+    Ignore,
+    Error,
+}
+
+impl FromStr for UnknownRenames {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        match raw_value {
+            "ignore" => Ok(Self::Ignore),
+            "error" => Ok(Self::Error),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported unknown_renames: \"{raw_value}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for UnknownRenames {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// How columns with the same name are given a unique name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum DuplicateColumns {
+    /// `name`, `name_1`, `name_2`...
+    #[default]
+    Underscore,
+    /// `name`, `name.1`, `name.2`..., as pandas does
+    Dot,
+    /// Duplicate names are an error
+    Error,
+}
+
+impl DuplicateColumns {
+    /// The separator between a duplicate name and its suffix. `None` if duplicates are an error
+    pub(crate) fn separator(&self) -> Option<char> {
+        match self {
+            Self::Underscore => Some('_'),
+            Self::Dot => Some('.'),
+            Self::Error => None,
+        }
+    }
+}
+
+impl FromStr for DuplicateColumns {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        match raw_value {
+            "underscore" => Ok(Self::Underscore),
+            "dot" => Ok(Self::Dot),
+            "error" => Ok(Self::Error),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported duplicate_columns: \"{raw_value}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for DuplicateColumns {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// How the rows used to guess the dtype of columns are chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum SampleStrategy {
+    /// The first `schema_sample_rows` rows
+    #[default]
+    Head,
+    /// `schema_sample_rows` rows evenly spaced over all the rows
+    Spread,
+    /// All the rows, regardless of `schema_sample_rows`
+    Full,
+}
+
+impl FromStr for SampleStrategy {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        match raw_value {
+            "head" => Ok(Self::Head),
+            "spread" => Ok(Self::Spread),
+            "full" => Ok(Self::Full),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported sample_strategy: \"{raw_value}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for SampleStrategy {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// A timezone, either with a fixed UTC offset or from the IANA database. Parsed from `"UTC"`, an
+/// offset such as `"+02:00"`, an IANA name such as `"Europe/Paris"`, a Python
+/// `datetime.timezone` or a `zoneinfo.ZoneInfo`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Timezone {
+    Fixed(FixedOffset),
+    Named(Tz),
+}
+
+impl Timezone {
+    /// The name of the timezone, as stored in arrow timestamp types, e.g. "UTC", "+02:00" or
+    /// "Europe/Paris"
+    pub(crate) fn name(&self) -> String {
+        match self {
+            Timezone::Fixed(offset) if offset.local_minus_utc() == 0 => UTC_TIMEZONE.to_owned(),
+            Timezone::Fixed(offset) => offset.to_string(),
+            Timezone::Named(tz) => tz.name().to_owned(),
+        }
+    }
+
+    /// Converts a datetime in this timezone to UTC. Ambiguous datetimes, repeated when clocks are
+    /// set back, are considered to be the earliest. Datetimes skipped when clocks are set forward
+    /// use the offset before the transition
+    pub(crate) fn local_to_utc(&self, datetime: NaiveDateTime) -> NaiveDateTime {
+        let offset = match self {
+            Timezone::Fixed(offset) => *offset,
+            Timezone::Named(tz) => match tz.offset_from_local_datetime(&datetime) {
+                LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset.fix(),
+                LocalResult::None => tz
+                    .offset_from_utc_datetime(&(datetime - TimeDelta::days(1)))
+                    .fix(),
+            },
+        };
+        datetime - TimeDelta::seconds(i64::from(offset.local_minus_utc()))
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        if raw_value.eq_ignore_ascii_case("utc") || raw_value == "Z" {
+            return Ok(Self::Fixed(
+                FixedOffset::east_opt(0).expect("0 is a valid offset"),
+            ));
+        }
+        raw_value
+            .parse()
+            .map(Self::Fixed)
+            .or_else(|_| raw_value.parse().map(Self::Named))
+            .map_err(|_| {
+                FastExcelErrorKind::InvalidParameters(format!(
+                    "unsupported timezone: \"{raw_value}\", expected \"UTC\", a UTC offset such as \"+02:00\" or an IANA timezone name such as \"Europe/Paris\""
+                ))
+                .into()
+            })
+    }
+}
+
+impl FromPyObject<'_> for Timezone {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else if let Ok(offset) = py_value.extract::<FixedOffset>() {
+            Ok(Self::Fixed(offset))
+        } else if let Ok(key) = py_value
+            .getattr("key")
+            .and_then(|key| key.extract::<String>())
+        {
+            // A `zoneinfo.ZoneInfo`, whose key is its IANA name
+            key.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be used as a timezone"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// A value replacing the nulls of a column
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FillValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    /// A timezone-aware datetime, converted to UTC
+    DateTimeTz(NaiveDateTime),
+    Duration(TimeDelta),
+}
+
+impl FillValue {
+    /// Whether the value can fill the nulls of a column of the given dtype. Ints can fill float
+    /// columns
+    pub(crate) fn matches(&self, dtype: &DType) -> bool {
+        matches!(
+            (self, dtype),
+            (Self::Bool(_), DType::Bool | DType::BoolFromInt)
+                | (Self::Int(_), DType::Int | DType::Float | DType::ExcelSerial)
+                | (Self::Float(_), DType::Float | DType::ExcelSerial)
+                | (Self::String(_), DType::String)
+                | (Self::Date(_), DType::Date)
+                | (Self::DateTime(_), DType::DateTime)
+                | (Self::DateTimeTz(_), DType::DateTimeTz)
+                | (Self::Duration(_), DType::Duration)
+        )
+    }
+}
+
+impl FromPyObject<'_> for FillValue {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        // bools are ints and datetimes are dates in Python, so they are extracted first
+        if let Ok(value) = py_value.extract::<bool>() {
+            Ok(Self::Bool(value))
+        } else if let Ok(value) = py_value.extract::<i64>() {
+            Ok(Self::Int(value))
+        } else if let Ok(value) = py_value.extract::<f64>() {
+            Ok(Self::Float(value))
+        } else if let Ok(value) = py_value.extract::<String>() {
+            Ok(Self::String(value))
+        } else if let Ok(value) = py_value.extract::<NaiveDateTime>() {
+            Ok(Self::DateTime(value))
+        } else if let Ok(value) = py_value.extract::<DateTime<FixedOffset>>() {
+            Ok(Self::DateTimeTz(value.naive_utc()))
+        } else if let Ok(value) = py_value.extract::<NaiveDate>() {
+            Ok(Self::Date(value))
+        } else if let Ok(value) = py_value.extract::<TimeDelta>() {
+            Ok(Self::Duration(value))
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be used as a fill value"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+pub(crate) const UNIX_EPOCH: NaiveDate = match NaiveDate::from_ymd_opt(1970, 1, 1) {
+    Some(date) => date,
+    None => unreachable!(),
+};
+
+/// Strings read as `true` in boolean columns when no `true_values` are specified
+const DEFAULT_TRUE_VALUES: [&str; 4] = ["true", "yes", "y", "1"];
+/// Strings read as `false` in boolean columns when no `false_values` are specified
+const DEFAULT_FALSE_VALUES: [&str; 4] = ["false", "no", "n", "0"];
+
+/// Prefix of the names generated for columns without a name, when no `column_prefix` is
+/// specified
+pub(crate) const DEFAULT_COLUMN_PREFIX: &str = "__UNNAMED__";
+
+/// Options impacting how cell values are converted to arrow arrays
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReadOptions {
+    pub(crate) timestamp_unit: TimestampUnit,
+    /// Whether boolean columns should be built as 0/1 `Int8` arrays
+    pub(crate) bool_as_int8: bool,
+    /// Value of the empty cells of boolean columns. Empty cells are null if `None`
+    pub(crate) bool_fill: Option<bool>,
+    /// Column to use as the index of the resulting frame
+    pub(crate) index_column: Option<IdxOrName>,
+    /// Origin of dates and timestamps. Defaults to the Unix epoch
+    pub(crate) epoch: Option<NaiveDate>,
+    /// Dtype of every column whose dtype was not specified via `dtypes`. Skips dtype guessing
+    pub(crate) all_columns_dtype: Option<DType>,
+    /// Whether the guessed "datetime" columns whose sampled cells are all at midnight are read as
+    /// "date" columns
+    pub(crate) infer_dates: bool,
+    /// Strings read as `true` in boolean columns. `DEFAULT_TRUE_VALUES` are used if empty
+    pub(crate) true_values: Vec<String>,
+    /// Strings read as `false` in boolean columns. `DEFAULT_FALSE_VALUES` are used if empty
+    pub(crate) false_values: Vec<String>,
+    /// New names of columns, by their name in the header. Applied before duplicate names are
+    /// deduplicated
+    pub(crate) rename: HashMap<String, String>,
+    pub(crate) unknown_renames: UnknownRenames,
+    /// Whether the dtypes specified for columns the sheet does not have are ignored rather than an
+    /// error. Set when the dtypes come from a schema rather than from the user
+    pub(crate) ignore_unknown_dtypes: bool,
+    pub(crate) duplicate_columns: DuplicateColumns,
+    /// Currency symbols stripped from the string cells of float columns before they are parsed
+    pub(crate) currency_symbols: Vec<String>,
+    /// Whether the currency symbols found in float columns are stored in their field's metadata
+    pub(crate) capture_currency: bool,
+    /// Whether the fields of the columns without null cells are non-nullable in the schemas of
+    /// the sheet, as well as in its record batches. Requires a scan of the selected columns
+    pub(crate) tighten_nullability: bool,
+    /// Prefix of the names generated for columns without a name. Defaults to
+    /// `DEFAULT_COLUMN_PREFIX`
+    pub(crate) column_prefix: Option<String>,
+    /// Decimal separator of the numbers of string cells. Defaults to `.`
+    pub(crate) decimal_separator: Option<char>,
+    /// Separator grouping the thousands of the numbers of string cells. Thousands are not
+    /// grouped if `None`
+    pub(crate) thousands_separator: Option<char>,
+    /// Whether the numbers of date and datetime columns are days since 1904-01-01 rather than
+    /// since 1899-12-30. Set from the workbook, not by the user
+    pub(crate) date1904: bool,
+    /// Values replacing the nulls of columns, by column name
+    pub(crate) fill_values: HashMap<String, FillValue>,
+    /// Columns whose nulls take the last non-null value above them, by index or name
+    pub(crate) forward_fill: Vec<IdxOrName>,
+    /// How the rows used to guess the dtype of columns are chosen
+    pub(crate) sample_strategy: SampleStrategy,
+    /// Timezone of the naive datetimes of "datetime_tz" columns. They are in UTC if `None`
+    pub(crate) assume_timezone: Option<Timezone>,
+    /// Timezone of the timestamps of "datetime_tz" columns. If set, "datetime" columns are read
+    /// as "datetime_tz" columns. Timestamps are in UTC if `None`
+    pub(crate) timezone: Option<Timezone>,
+    /// Strings read as null in string, int and float columns
+    pub(crate) na_values: Vec<String>,
+    /// Whether empty strings are read as null in string, int and float columns
+    pub(crate) empty_string_as_null: bool,
+    /// Whether leading and trailing whitespace is trimmed from the values of string columns,
+    /// before `na_values` and `empty_string_as_null` are applied
+    pub(crate) trim_strings: bool,
+    /// Maximum number of threads building the arrays of the columns. Defaults to the global rayon
+    /// pool, which has a thread per CPU
+    pub(crate) n_threads: Option<usize>,
+}
+
+impl ReadOptions {
+    /// The origin dates and timestamps are relative to
+    pub(crate) fn epoch(&self) -> NaiveDate {
+        self.epoch.unwrap_or(UNIX_EPOCH)
+    }
+
+    /// Whether dates and datetimes are relative to another epoch than the Unix one. They are then
+    /// read as integer offsets, as arrow dates and timestamps are relative to the Unix epoch
+    pub(crate) fn has_custom_epoch(&self) -> bool {
+        self.epoch() != UNIX_EPOCH
+    }
+
+    /// Converts a naive datetime of a "datetime_tz" column to UTC, using `assume_timezone`
+    pub(crate) fn naive_to_utc(&self, datetime: NaiveDateTime) -> NaiveDateTime {
+        match self.assume_timezone {
+            Some(timezone) => timezone.local_to_utc(datetime),
+            None => datetime,
+        }
+    }
+
+    /// The name of the timezone of the timestamps of "datetime_tz" columns, e.g. "UTC",
+    /// "+02:00" or "Europe/Paris"
+    pub(crate) fn timezone_name(&self) -> String {
+        self.timezone
+            .map_or_else(|| UTC_TIMEZONE.to_owned(), |timezone| timezone.name())
+    }
+
+    /// Whether a string cell of a string, int or float column is read as null
+    pub(crate) fn is_na_value(&self, value: &str) -> bool {
+        (self.empty_string_as_null && value.is_empty())
+            || self.na_values.iter().any(|na_value| na_value == value)
+    }
+
+    /// Trims `value` if `trim_strings` is set. Whitespace is made of the characters with the
+    /// Unicode `White_Space` property, which include the non-breaking space U+00A0
+    pub(crate) fn trim_string<'a>(&self, value: &'a str) -> &'a str {
+        if self.trim_strings {
+            value.trim()
+        } else {
+            value
+        }
+    }
+
+    /// The prefix of the names generated for columns without a name
+    pub(crate) fn column_prefix(&self) -> &str {
+        self.column_prefix
+            .as_deref()
+            .unwrap_or(DEFAULT_COLUMN_PREFIX)
+    }
+
+    /// Checks that `n_threads` allows reading columns
+    pub(crate) fn validate_n_threads(&self) -> FastExcelResult<()> {
+        if self.n_threads == Some(0) {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "`n_threads` must be at least 1".to_owned(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Checks that the separators of numbers can be told apart from the digits and from each other
+    pub(crate) fn validate_separators(&self) -> FastExcelResult<()> {
+        for (name, separator) in [
+            ("decimal_separator", self.decimal_separator),
+            ("thousands_separator", self.thousands_separator),
+        ] {
+            if separator.is_some_and(|separator| separator.is_ascii_digit()) {
+                return Err(FastExcelErrorKind::InvalidParameters(format!(
+                    "`{name}` cannot be a digit, got {separator:?}"
+                ))
+                .into());
+            }
+        }
+        if self.thousands_separator == Some(self.decimal_separator()) {
+            return Err(FastExcelErrorKind::InvalidParameters(format!(
+                "`thousands_separator` cannot be the decimal separator {:?}",
+                self.decimal_separator()
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// The decimal separator of the numbers of string cells
+    pub(crate) fn decimal_separator(&self) -> char {
+        self.decimal_separator.unwrap_or('.')
+    }
+
+    /// Parses the content of a string cell of a float column, using the `decimal_separator` and
+    /// the `thousands_separator`
+    pub(crate) fn parse_float(&self, value: &str) -> Option<f64> {
+        normalize_number(value, self.decimal_separator(), self.thousands_separator)?
+            .parse()
+            .ok()
+    }
+
+    /// Parses the content of a string cell of an int column, using the `thousands_separator`
+    pub(crate) fn parse_int(&self, value: &str) -> Option<i64> {
+        normalize_number(value, self.decimal_separator(), self.thousands_separator)?
+            .parse()
+            .ok()
+    }
+
+    /// Converts the content of a string cell of a boolean column. Values are compared
+    /// case-insensitively, ignoring surrounding whitespace. Returns `None` for unknown values
+    pub(crate) fn bool_from_str(&self, value: &str) -> Option<bool> {
+        let value = value.trim();
+        let matches = |tokens: &[String], defaults: &[&str]| {
+            if tokens.is_empty() {
+                defaults
+                    .iter()
+                    .any(|token| token.eq_ignore_ascii_case(value))
+            } else {
+                tokens
+                    .iter()
+                    .any(|token| token.trim().eq_ignore_ascii_case(value))
+            }
+        };
+        if matches(&self.true_values, &DEFAULT_TRUE_VALUES) {
+            Some(true)
+        } else if matches(&self.false_values, &DEFAULT_FALSE_VALUES) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Parses an amount starting or ending with one of the `currency_symbols`, as in "$1,234.56",
+    /// "-$5" or "12 €". Returns `None` if the value contains no currency symbol, or the symbol
+    /// and the parsed amount, which is `None` if what remains is not a number
+    pub(crate) fn currency_amount<'a>(&'a self, value: &str) -> Option<(&'a str, Option<f64>)> {
+        let value = value.trim();
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned.trim_start()),
+            None => ("", value),
+        };
+        // The longest symbol is used, so that "US$" wins over "$"
+        self.currency_symbols
+            .iter()
+            .filter(|symbol| !symbol.is_empty())
+            .filter_map(|symbol| {
+                let amount = unsigned
+                    .strip_prefix(symbol.as_str())
+                    .or_else(|| unsigned.strip_suffix(symbol.as_str()))?;
+                Some((symbol.as_str(), amount))
+            })
+            .max_by_key(|(symbol, _)| symbol.len())
+            .map(|(symbol, amount)| {
+                (
+                    symbol,
+                    self.parse_amount(&format!("{sign}{}", amount.trim())),
+                )
+            })
+    }
+
+    /// Parses a currency amount. Unless a `thousands_separator` is specified, thousands may be
+    /// grouped with commas, as in "1,234.56", if commas are not decimal separators
+    fn parse_amount(&self, amount: &str) -> Option<f64> {
+        let decimal_separator = self.decimal_separator();
+        let thousands_separator = self
+            .thousands_separator
+            .or_else(|| (decimal_separator != ',').then_some(','));
+        normalize_number(amount, decimal_separator, thousands_separator)?
+            .parse()
+            .ok()
+    }
+
+    /// The value replacing the nulls of the given column, if any
+    pub(crate) fn fill_value(&self, column_info: &ColumnInfo) -> Option<&FillValue> {
+        self.fill_values.get(column_info.name())
+    }
+
+    /// Whether the nulls of the given column take the last non-null value above them
+    pub(crate) fn is_forward_filled(&self, column_info: &ColumnInfo) -> bool {
+        self.forward_fill
+            .iter()
+            .any(|idx_or_name| column_info == idx_or_name)
+    }
+
+    /// Checks that the fill values of the selected columns match their dtype, and that
+    /// forward-filled columns have no fill value. Fill values of columns that are not selected
+    /// are ignored
+    pub(crate) fn validate_fill_values(&self, columns: &[ColumnInfo]) -> FastExcelResult<()> {
+        for column_info in columns {
+            match self.fill_value(column_info) {
+                Some(_) if self.is_forward_filled(column_info) => {
+                    return Err(FastExcelErrorKind::InvalidParameters(format!(
+                        "column \"{}\" cannot both be forward-filled and have a fill value",
+                        column_info.name()
+                    ))
+                    .into())
+                }
+                Some(fill_value) if !fill_value.matches(column_info.dtype()) => {
+                    return Err(FastExcelErrorKind::InvalidParameters(format!(
+                        "fill value {fill_value:?} cannot fill the nulls of column \"{}\" of dtype {}",
+                        column_info.name(),
+                        column_info.dtype()
+                    ))
+                    .into())
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether the given column is the index column
+    pub(crate) fn is_index_column(&self, column_info: &ColumnInfo) -> bool {
+        self.index_column
+            .as_ref()
+            .is_some_and(|index_column| column_info == index_column)
+    }
+
+    /// Moves the index column, if any, to the first position. The index column must be one of the
+    /// selected columns
+    pub(crate) fn reorder_columns(
+        &self,
+        mut columns: Vec<ColumnInfo>,
+    ) -> FastExcelResult<Vec<ColumnInfo>> {
+        if let Some(index_column) = &self.index_column {
+            let position = columns
+                .iter()
+                .position(|column_info| column_info == index_column)
+                .ok_or_else(|| FastExcelErrorKind::ColumnNotFound(index_column.clone()).into())
+                .with_context(|| "the index column must be one of the selected columns")?;
+            let column_info = columns.remove(position);
+            columns.insert(0, column_info);
+        }
+        Ok(columns)
+    }
+}
+
+/// Rewrites a number using the given separators so that it can be parsed by Rust, e.g.
+/// "1.234,56" to "1234.56". The thousands of the integer part must be grouped by three. Returns
+/// `None` if the number is malformed
+fn normalize_number(
+    number: &str,
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+) -> Option<Cow<'_, str>> {
+    let (integer_part, fractional_part) = match number.split_once(decimal_separator) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    // A dot is only valid as the decimal separator, or as the thousands separator
+    if decimal_separator != '.' && thousands_separator != Some('.') && number.contains('.') {
+        return None;
+    }
+    let integer_part = match thousands_separator {
+        Some(separator) if integer_part.contains(separator) => {
+            let unsigned = integer_part.strip_prefix('-').unwrap_or(integer_part);
+            let mut groups = unsigned.split(separator);
+            let first_group_is_valid = groups
+                .next()
+                .is_some_and(|group| (1..=3).contains(&group.len()));
+            if !(first_group_is_valid && groups.all(|group| group.len() == 3)) {
+                return None;
+            }
+            Cow::Owned(integer_part.replace(separator, ""))
+        }
+        _ => Cow::Borrowed(integer_part),
+    };
+    Some(match (integer_part, fractional_part) {
+        // Nothing to rewrite
+        (Cow::Borrowed(_), _) if decimal_separator == '.' => Cow::Borrowed(number),
+        (integer_part, Some(fraction)) => Cow::Owned(format!("{integer_part}.{fraction}")),
+        (integer_part, None) => integer_part,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::types::python::excelsheet::column_info::{ColumnNameFrom, DTypeFrom};
+
+    fn columns() -> Vec<ColumnInfo> {
+        ["a", "b", "c"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                ColumnInfo::new(
+                    name.to_owned(),
+                    idx,
+                    ColumnNameFrom::LookedUp,
+                    DType::Float,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(None, vec!["a", "b", "c"])]
+    #[case(Some(IdxOrName::Name("c".to_owned())), vec!["c", "a", "b"])]
+    #[case(Some(IdxOrName::Idx(1)), vec!["b", "a", "c"])]
+    fn reorder_columns_moves_index_column_first(
+        #[case] index_column: Option<IdxOrName>,
+        #[case] expected: Vec<&str>,
+    ) {
+        let read_options = ReadOptions {
+            index_column,
+            ..Default::default()
+        };
+
+        let reordered = read_options.reorder_columns(columns()).unwrap();
+
+        assert_eq!(
+            reordered.iter().map(ColumnInfo::name).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(vec![], vec![], "Yes", Some(true))]
+    #[case(vec![], vec![], " n ", Some(false))]
+    #[case(vec![], vec![], "Oui", None)]
+    #[case(vec![], vec![], "1", Some(true))]
+    #[case(vec![], vec![], "0", Some(false))]
+    #[case(vec!["Oui"], vec!["Non"], "oui", Some(true))]
+    #[case(vec!["Oui"], vec!["Non"], "NON", Some(false))]
+    #[case(vec!["1"], vec!["0"], "yes", None)]
+    #[case(vec!["Y"], vec![], "no", Some(false))]
+    fn bool_from_str_uses_the_specified_values(
+        #[case] true_values: Vec<&str>,
+        #[case] false_values: Vec<&str>,
+        #[case] value: &str,
+        #[case] expected: Option<bool>,
+    ) {
+        let to_strings = |values: Vec<&str>| values.into_iter().map(str::to_owned).collect();
+        let read_options = ReadOptions {
+            true_values: to_strings(true_values),
+            false_values: to_strings(false_values),
+            ..Default::default()
+        };
+
+        assert_eq!(read_options.bool_from_str(value), expected);
+    }
+
+    #[rstest]
+    #[case("$1,234.56", Some(("$", Some(1234.56))))]
+    #[case(" -$5 ", Some(("$", Some(-5.0))))]
+    #[case("12 €", Some(("€", Some(12.0))))]
+    #[case("US$1,000", Some(("US$", Some(1000.0))))]
+    #[case("€99,00", Some(("€", None)))]
+    #[case("$abc", Some(("$", None)))]
+    #[case("1,234.56", None)]
+    #[case("£12", None)]
+    fn currency_amount_strips_the_currency_symbol(
+        #[case] value: &str,
+        #[case] expected: Option<(&str, Option<f64>)>,
+    ) {
+        let read_options = ReadOptions {
+            currency_symbols: vec!["$".to_owned(), "€".to_owned(), "US$".to_owned()],
+            ..Default::default()
+        };
+
+        assert_eq!(read_options.currency_amount(value), expected);
+    }
+
+    #[rstest]
+    #[case(None, None, "1234.5", Some(1234.5))]
+    #[case(None, None, "1,234.5", None)]
+    #[case(Some(','), None, "1234,5", Some(1234.5))]
+    #[case(Some(','), None, "1234.5", None)]
+    #[case(Some(','), Some('.'), "-1.234.567,89", Some(-1_234_567.89))]
+    #[case(Some(','), Some('.'), "1.23,4", None)]
+    #[case(None, Some(' '), "12 345", Some(12345.0))]
+    #[case(None, Some(','), "1,234.5", Some(1234.5))]
+    fn parse_float_uses_the_separators(
+        #[case] decimal_separator: Option<char>,
+        #[case] thousands_separator: Option<char>,
+        #[case] value: &str,
+        #[case] expected: Option<f64>,
+    ) {
+        let read_options = ReadOptions {
+            decimal_separator,
+            thousands_separator,
+            ..Default::default()
+        };
+
+        assert_eq!(read_options.parse_float(value), expected);
+    }
+
+    #[test]
+    fn parse_int_uses_the_thousands_separator() {
+        let read_options = ReadOptions {
+            decimal_separator: Some(','),
+            thousands_separator: Some('.'),
+            ..Default::default()
+        };
+
+        assert_eq!(read_options.parse_int("1.234"), Some(1234));
+        assert_eq!(read_options.parse_int("1.234,5"), None);
+    }
+
+    #[test]
+    fn currency_amount_uses_the_decimal_separator() {
+        let read_options = ReadOptions {
+            currency_symbols: vec!["€".to_owned()],
+            decimal_separator: Some(','),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            read_options.currency_amount("€99,50"),
+            Some(("€", Some(99.5)))
+        );
+    }
+
+    #[rstest]
+    #[case(Some(','), Some(','))]
+    #[case(None, Some('.'))]
+    #[case(Some('0'), None)]
+    #[case(None, Some('1'))]
+    fn invalid_separators(
+        #[case] decimal_separator: Option<char>,
+        #[case] thousands_separator: Option<char>,
+    ) {
+        let read_options = ReadOptions {
+            decimal_separator,
+            thousands_separator,
+            ..Default::default()
+        };
+
+        let err = read_options.validate_separators().unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn reorder_columns_index_column_not_found() {
+        let read_options = ReadOptions {
+            index_column: Some(IdxOrName::Name("d".to_owned())),
+            ..Default::default()
+        };
+
+        let err = read_options.reorder_columns(columns()).unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::ColumnNotFound(_)));
+    }
+
+    #[rstest]
+    #[case(FillValue::Float(0.0), true)]
+    #[case(FillValue::Int(0), true)]
+    #[case(FillValue::String("0".to_owned()), false)]
+    #[case(FillValue::Bool(false), false)]
+    fn fill_values_must_match_the_dtype(#[case] fill_value: FillValue, #[case] is_valid: bool) {
+        // "d" is not a selected column, its fill value is ignored
+        let read_options = ReadOptions {
+            fill_values: HashMap::from([
+                ("b".to_owned(), fill_value),
+                ("d".to_owned(), FillValue::Bool(true)),
+            ]),
+            ..Default::default()
+        };
+
+        let result = read_options.validate_fill_values(&columns());
+
+        assert_eq!(result.is_ok(), is_valid);
+    }
+
+    #[test]
+    fn forward_filled_columns_cannot_have_a_fill_value() {
+        let read_options = ReadOptions {
+            fill_values: HashMap::from([("b".to_owned(), FillValue::Float(0.0))]),
+            forward_fill: vec![IdxOrName::Idx(0)],
+            ..Default::default()
+        };
+        assert!(read_options.validate_fill_values(&columns()).is_ok());
+
+        let read_options = ReadOptions {
+            forward_fill: vec![IdxOrName::Idx(0), IdxOrName::Name("b".to_owned())],
+            ..read_options
+        };
+        let err = read_options.validate_fill_values(&columns()).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid parameters: column \"b\" cannot both be forward-filled and have a fill value"
+        );
+    }
+
+    #[rstest]
+    #[case("UTC", "UTC")]
+    #[case("+02:00", "+02:00")]
+    #[case("-00:00", "UTC")]
+    #[case("Europe/Paris", "Europe/Paris")]
+    fn timezones_are_parsed_from_offsets_and_iana_names(
+        #[case] raw_value: &str,
+        #[case] expected_name: &str,
+    ) {
+        assert_eq!(raw_value.parse::<Timezone>().unwrap().name(), expected_name);
+    }
+
+    #[test]
+    fn unknown_timezone_is_an_invalid_parameter() {
+        let err = "Europe/Atlantis".parse::<Timezone>().unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+    }
+
+    #[rstest]
+    // Summer time
+    #[case("2024-07-01T12:00:00", "2024-07-01T10:00:00")]
+    // Winter time
+    #[case("2024-01-01T12:00:00", "2024-01-01T11:00:00")]
+    // Repeated when clocks are set back: the earliest, in summer time
+    #[case("2024-10-27T02:30:00", "2024-10-27T00:30:00")]
+    // Skipped when clocks are set forward: the offset before the transition
+    #[case("2024-03-31T02:30:00", "2024-03-31T01:30:00")]
+    fn iana_timezones_follow_daylight_saving_time(#[case] local: &str, #[case] utc: &str) {
+        let timezone: Timezone = "Europe/Paris".parse().unwrap();
+
+        assert_eq!(
+            timezone.local_to_utc(local.parse().unwrap()),
+            utc.parse::<NaiveDateTime>().unwrap()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    collections::HashSet,
+    io::{BufRead, BufReader, Read, Seek},
+};
+
+use quick_xml::{events::Event, Reader};
+use zip::ZipArchive;
+
+use crate::error::{ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult};
+
+use super::workbook_xml::{attribute_value, element_attributes, part_path, xlsx_error};
+
+/// Returns the rows of an xlsx sheet hidden by its autofilter, as absolute sheet rows starting
+/// at 0. calamine does not expose row attributes, so the sheet's XML is read from the archive.
+///
+/// Rows hidden in a sheet without an autofilter were hidden by hand, and are not returned.
+pub(crate) fn autofilter_hidden_rows<R: Read + Seek>(
+    reader: R,
+    sheet_name: &str,
+) -> FastExcelResult<HashSet<u32>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let sheet_path = sheet_path(&mut archive, sheet_name)?;
+    let sheet = archive.by_name(&sheet_path).map_err(xlsx_error)?;
+    hidden_rows(BufReader::new(sheet))
+        .with_context(|| format!("could not read the rows of sheet \"{sheet_name}\""))
+}
+
+/// Looks the path of a sheet's XML up in the workbook and its relationships
+fn sheet_path<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    sheet_name: &str,
+) -> FastExcelResult<String> {
+    let relationship_id =
+        element_attributes(archive, "xl/workbook.xml", b"sheet", &["name", "r:id"])?
+            .into_iter()
+            .find_map(|attributes| match attributes.as_slice() {
+                [Some(name), Some(id)] if name == sheet_name => Some(id.clone()),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                FastExcelError::from(FastExcelErrorKind::Internal(format!(
+                    "sheet \"{sheet_name}\" not found in xl/workbook.xml"
+                )))
+            })?;
+    let target = element_attributes(
+        archive,
+        "xl/_rels/workbook.xml.rels",
+        b"Relationship",
+        &["Id", "Target"],
+    )?
+    .into_iter()
+    .find_map(|attributes| match attributes.as_slice() {
+        [Some(id), Some(target)] if *id == relationship_id => Some(target.clone()),
+        _ => None,
+    })
+    .ok_or_else(|| {
+        FastExcelError::from(FastExcelErrorKind::Internal(format!(
+            "relationship {relationship_id} of sheet \"{sheet_name}\" not found"
+        )))
+    })?;
+    Ok(part_path(target))
+}
+
+/// Reads the hidden rows of a sheet's XML, if the sheet has an autofilter
+fn hidden_rows<R: BufRead>(sheet: R) -> FastExcelResult<HashSet<u32>> {
+    let mut reader = Reader::from_reader(sheet);
+    let mut buf = Vec::new();
+    let mut hidden_rows = HashSet::new();
+    let mut has_autofilter = false;
+    // Rows without a `r` attribute directly follow the previous one
+    let mut next_row = 0;
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xlsx_error)? {
+            Event::Start(element) | Event::Empty(element) => match element.local_name().as_ref() {
+                b"row" => {
+                    let row = match attribute_value(&reader, &element, "r")? {
+                        Some(r) => r.parse::<u32>().map_err(xlsx_error)?.saturating_sub(1),
+                        None => next_row,
+                    };
+                    next_row = row + 1;
+                    if let Some(hidden) = element.try_get_attribute("hidden").map_err(xlsx_error)? {
+                        if matches!(hidden.value.as_ref(), b"1" | b"true") {
+                            hidden_rows.insert(row);
+                        }
+                    }
+                }
+                b"autoFilter" => has_autofilter = true,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    if has_autofilter {
+        Ok(hidden_rows)
+    } else {
+        Ok(HashSet::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Cursor};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn rows_hidden_by_the_autofilter() {
+        let file = File::open("python/tests/fixtures/fixture-autofilter.xlsx").unwrap();
+
+        let hidden_rows = autofilter_hidden_rows(file, "Sheet1").unwrap();
+
+        assert_eq!(hidden_rows, HashSet::from([2, 4]));
+    }
+
+    #[test]
+    fn hidden_rows_without_autofilter_are_ignored() {
+        let sheet =
+            r#"<worksheet><sheetData><row r="1"/><row hidden="1"/></sheetData></worksheet>"#;
+
+        let hidden_rows = hidden_rows(Cursor::new(sheet)).unwrap();
+
+        assert_eq!(hidden_rows, HashSet::new());
+    }
+
+    #[test]
+    fn rows_without_reference_follow_the_previous_one() {
+        let sheet = r#"<worksheet><sheetData><row r="3"/><row hidden="1"/></sheetData><autoFilter ref="A1:A4"/></worksheet>"#;
+
+        let hidden_rows = hidden_rows(Cursor::new(sheet)).unwrap();
+
+        assert_eq!(hidden_rows, HashSet::from([3]));
+    }
+}
@@ -0,0 +1,503 @@
+use std::{str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{new_null_array, Array, ArrayRef, RecordBatch, StringArray},
+    compute::{cast, concat_batches},
+    datatypes::{DataType as ArrowDataType, Field, Schema, SchemaRef},
+};
+
+use arrow::pyarrow::ToPyArrow;
+use pyo3::{
+    prelude::PyAnyMethods, pyclass, pymethods, types::PyString, Bound, FromPyObject, PyAny,
+    PyObject, PyResult, Python,
+};
+
+use crate::{
+    error::{
+        py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
+    },
+    types::idx_or_name::IdxOrName,
+};
+
+/// Determines the type a column should have when it is of type `left` in a schema and `right` in
+/// another one. If `promote_types` is false, only the `Null` type can be reconciled with another
+/// type. Otherwise, the following rules apply:
+/// * `Int64` and `Float64` are promoted to `Float64`
+/// * Any other combination of different types is promoted to `Utf8`
+fn common_type(
+    left: &ArrowDataType,
+    right: &ArrowDataType,
+    promote_types: bool,
+) -> Option<ArrowDataType> {
+    match (left, right) {
+        _ if left == right => Some(left.clone()),
+        (ArrowDataType::Null, other) | (other, ArrowDataType::Null) => Some(other.clone()),
+        _ if !promote_types => None,
+        (ArrowDataType::Int64, ArrowDataType::Float64)
+        | (ArrowDataType::Float64, ArrowDataType::Int64) => Some(ArrowDataType::Float64),
+        _ => Some(ArrowDataType::Utf8),
+    }
+}
+
+/// Builds the union of the provided schemas.
+///
+/// Fields are ordered by first appearance. A field with the `Null` type takes the type it has in
+/// the other schemas. Fields with conflicting non-null types are promoted to a common type if
+/// `promote_types` is true (see `common_type`), and result in an error otherwise.
+pub(crate) fn union_schemas<'a, I: IntoIterator<Item = &'a Schema>>(
+    schemas: I,
+    promote_types: bool,
+) -> FastExcelResult<Schema> {
+    let mut fields: Vec<Field> = Vec::new();
+
+    for schema in schemas {
+        for field in schema.fields() {
+            match fields
+                .iter_mut()
+                .find(|existing| existing.name() == field.name())
+            {
+                None => fields.push(field.as_ref().clone().with_nullable(true)),
+                Some(existing) => {
+                    match common_type(existing.data_type(), field.data_type(), promote_types) {
+                        Some(dtype) => *existing = existing.clone().with_data_type(dtype),
+                        None => {
+                            return Err(FastExcelErrorKind::UnsupportedColumnTypeCombination(
+                                format!(
+                                    "column \"{name}\" is of type {existing_type} in one source and {new_type} in another",
+                                    name = field.name(),
+                                    existing_type = existing.data_type(),
+                                    new_type = field.data_type()
+                                ),
+                            )
+                            .into())
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Schema::new(fields))
+}
+
+/// A column whose type in one of the concatenated batches was promoted to another type
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(name = "TypePromotion")]
+pub(crate) struct TypePromotion {
+    /// `str`. The name of the column
+    #[pyo3(get)]
+    column_name: String,
+    /// `int`. The position of the batch in which the column had its original type
+    #[pyo3(get)]
+    batch_index: usize,
+    from_type: ArrowDataType,
+    to_type: ArrowDataType,
+}
+
+#[pymethods]
+impl TypePromotion {
+    /// `pyarrow.DataType`. The type of the column in the batch
+    #[getter(from_type)]
+    fn get_from_type(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.from_type.to_pyarrow(py)
+    }
+
+    /// `pyarrow.DataType`. The type of the column in the concatenated batch
+    #[getter(to_type)]
+    fn get_to_type(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.to_type.to_pyarrow(py)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "TypePromotion(column_name=\"{column_name}\", batch_index={batch_index}, from_type={from_type}, to_type={to_type})",
+            column_name = self.column_name,
+            batch_index = self.batch_index,
+            from_type = self.from_type,
+            to_type = self.to_type,
+        )
+    }
+}
+
+/// Lists the columns of `batches` whose type differs from their type in `schema`, the union of
+/// the batches' schemas. `Null` columns are not promoted, they take the type of the schema
+pub(crate) fn type_promotions(batches: &[RecordBatch], schema: &Schema) -> Vec<TypePromotion> {
+    batches
+        .iter()
+        .enumerate()
+        .flat_map(|(batch_index, rb)| {
+            rb.schema_ref()
+                .fields()
+                .iter()
+                .filter_map(move |field| {
+                    let (_, union_field) = schema.column_with_name(field.name())?;
+                    (field.data_type() != &ArrowDataType::Null
+                        && field.data_type() != union_field.data_type())
+                    .then(|| TypePromotion {
+                        column_name: field.name().to_owned(),
+                        batch_index,
+                        from_type: field.data_type().clone(),
+                        to_type: union_field.data_type().clone(),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Aligns a `RecordBatch` on the given schema. Columns are reordered to match the schema and cast
+/// to the schema's type if needed, and columns missing from the batch are filled with nulls of
+/// the schema's type.
+pub(crate) fn align_record_batch(
+    rb: &RecordBatch,
+    schema: SchemaRef,
+) -> FastExcelResult<RecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| match rb.column_by_name(field.name()) {
+            Some(column) if column.data_type() == field.data_type() => Ok(column.clone()),
+            Some(column) => cast(column, field.data_type())
+                .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+                .with_context(|| {
+                    format!(
+                        "could not cast column \"{name}\" from {from} to {to}",
+                        name = field.name(),
+                        from = column.data_type(),
+                        to = field.data_type()
+                    )
+                }),
+            None => Ok(new_null_array(field.data_type(), rb.num_rows())),
+        })
+        .collect::<FastExcelResult<Vec<ArrayRef>>>()?;
+
+    RecordBatch::try_new(schema, columns)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+        .with_context(|| "could not align RecordBatch on the union schema")
+}
+
+/// What to do with the columns of a `RecordBatch` that are not part of a template schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ExtraColumns {
+    #[default]
+    Drop,
+    Error,
+}
+
+impl FromStr for ExtraColumns {
+    type Err = FastExcelError;
+
+    fn from_str(raw_value: &str) -> FastExcelResult<Self> {
+        match raw_value {
+            "drop" => Ok(Self::Drop),
+            "error" => Ok(Self::Error),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported extra_columns: \"{raw_value}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for ExtraColumns {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value_pystr) = py_value.extract::<&PyString>() {
+            value_pystr.to_str()?.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// Conforms a `RecordBatch` to a template schema. Every column of the template is nullable in the
+/// result: columns missing from the batch are filled with nulls, and the other ones are cast to
+/// the template's type. Columns of the batch that are not part of the template are either dropped
+/// or result in an error, depending on `extra_columns`.
+pub(crate) fn conform_record_batch(
+    rb: &RecordBatch,
+    template: &Schema,
+    extra_columns: ExtraColumns,
+) -> FastExcelResult<RecordBatch> {
+    if extra_columns == ExtraColumns::Error {
+        if let Some(extra_field) = rb
+            .schema_ref()
+            .fields()
+            .iter()
+            .find(|field| template.column_with_name(field.name()).is_none())
+        {
+            return Err(FastExcelErrorKind::ColumnNotFound(IdxOrName::Name(
+                extra_field.name().to_owned(),
+            ))
+            .into())
+            .with_context(|| "the column is not part of the template schema");
+        }
+    }
+
+    let fields: Vec<Field> = template
+        .fields()
+        .iter()
+        .map(|field| field.as_ref().clone().with_nullable(true))
+        .collect();
+    align_record_batch(rb, Arc::new(Schema::new(fields)))
+}
+
+/// Concatenates several `RecordBatch`es into a single one, using the union of their schemas
+pub(crate) fn concat_record_batches(
+    batches: &[RecordBatch],
+    promote_types: bool,
+) -> FastExcelResult<RecordBatch> {
+    let schema = Arc::new(union_schemas(
+        batches.iter().map(|rb| rb.schema_ref().as_ref()),
+        promote_types,
+    )?);
+    concat_on_schema(batches, schema)
+}
+
+/// Concatenates several `RecordBatch`es with type promotion, returning the concatenated batch
+/// along with the promotions that were applied
+pub(crate) fn merge_record_batches(
+    batches: &[RecordBatch],
+) -> FastExcelResult<(RecordBatch, Vec<TypePromotion>)> {
+    let schema = Arc::new(union_schemas(
+        batches.iter().map(|rb| rb.schema_ref().as_ref()),
+        true,
+    )?);
+    let promotions = type_promotions(batches, &schema);
+    Ok((concat_on_schema(batches, schema)?, promotions))
+}
+
+fn concat_on_schema(batches: &[RecordBatch], schema: SchemaRef) -> FastExcelResult<RecordBatch> {
+    let aligned_batches = batches
+        .iter()
+        .enumerate()
+        .map(|(idx, rb)| {
+            align_record_batch(rb, schema.clone())
+                .with_context(|| format!("could not align RecordBatch at position {idx}"))
+        })
+        .collect::<FastExcelResult<Vec<_>>>()?;
+
+    concat_batches(&schema, &aligned_batches)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+        .with_context(|| "could not concatenate RecordBatches")
+}
+
+/// Appends a string column named `column_name`, containing `value` for every row of the batch
+pub(crate) fn with_constant_string_column(
+    rb: &RecordBatch,
+    column_name: &str,
+    value: &str,
+) -> FastExcelResult<RecordBatch> {
+    if rb.schema_ref().column_with_name(column_name).is_some() {
+        return Err(FastExcelErrorKind::InvalidParameters(format!(
+            "cannot add column \"{column_name}\": a column with that name already exists"
+        ))
+        .into());
+    }
+
+    let mut fields = rb.schema_ref().fields().to_vec();
+    fields.push(Arc::new(Field::new(
+        column_name,
+        ArrowDataType::Utf8,
+        false,
+    )));
+
+    let mut columns = rb.columns().to_vec();
+    columns.push(Arc::new(StringArray::from(vec![value; rb.num_rows()])));
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+        .with_context(|| format!("could not add column \"{column_name}\" to RecordBatch"))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{BooleanArray, Float64Array, Int64Array, NullArray};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn batch(columns: Vec<(&str, ArrayRef)>) -> RecordBatch {
+        RecordBatch::try_from_iter_with_nullable(
+            columns.into_iter().map(|(name, array)| (name, array, true)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn concat_fills_missing_columns_with_nulls() {
+        let first = batch(vec![
+            ("a", Arc::new(Float64Array::from(vec![1.0, 2.0]))),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"]))),
+        ]);
+        let second = batch(vec![
+            ("b", Arc::new(StringArray::from(vec!["z"]))),
+            ("c", Arc::new(Float64Array::from(vec![3.0]))),
+        ]);
+
+        let result = concat_record_batches(&[first, second], false).unwrap();
+
+        let expected = batch(vec![
+            (
+                "a",
+                Arc::new(Float64Array::from(vec![Some(1.0), Some(2.0), None])),
+            ),
+            ("b", Arc::new(StringArray::from(vec!["x", "y", "z"]))),
+            (
+                "c",
+                Arc::new(Float64Array::from(vec![None, None, Some(3.0)])),
+            ),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn concat_null_columns_take_the_other_type() {
+        let first = batch(vec![("a", Arc::new(NullArray::new(2)))]);
+        let second = batch(vec![("a", Arc::new(Float64Array::from(vec![1.0])))]);
+
+        let result = concat_record_batches(&[first, second], false).unwrap();
+
+        let expected = batch(vec![(
+            "a",
+            Arc::new(Float64Array::from(vec![None, None, Some(1.0)])),
+        )]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn concat_conflicting_types_is_an_error() {
+        let first = batch(vec![("a", Arc::new(Float64Array::from(vec![1.0])))]);
+        let second = batch(vec![("a", Arc::new(StringArray::from(vec!["x"])))]);
+
+        let err = concat_record_batches(&[first, second], false).unwrap_err();
+
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[test]
+    fn concat_promotes_int_and_float_to_float() {
+        let first = batch(vec![("a", Arc::new(Int64Array::from(vec![1, 2])))]);
+        let second = batch(vec![("a", Arc::new(Float64Array::from(vec![1.5])))]);
+
+        let result = concat_record_batches(&[first, second], true).unwrap();
+
+        let expected = batch(vec![(
+            "a",
+            Arc::new(Float64Array::from(vec![1.0, 2.0, 1.5])),
+        )]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn concat_promotes_other_conflicts_to_string() {
+        let first = batch(vec![
+            ("a", Arc::new(Float64Array::from(vec![1.5]))),
+            ("b", Arc::new(BooleanArray::from(vec![true]))),
+        ]);
+        let second = batch(vec![
+            ("a", Arc::new(StringArray::from(vec!["x"]))),
+            ("b", Arc::new(Int64Array::from(vec![42]))),
+        ]);
+
+        let result = concat_record_batches(&[first, second], true).unwrap();
+
+        let expected = batch(vec![
+            ("a", Arc::new(StringArray::from(vec!["1.5", "x"]))),
+            ("b", Arc::new(StringArray::from(vec!["true", "42"]))),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn merge_reports_the_promotions() {
+        let first = batch(vec![
+            ("a", Arc::new(Int64Array::from(vec![1]))),
+            ("b", Arc::new(NullArray::new(1))),
+        ]);
+        let second = batch(vec![
+            ("a", Arc::new(Float64Array::from(vec![1.5]))),
+            ("b", Arc::new(BooleanArray::from(vec![true]))),
+            ("c", Arc::new(StringArray::from(vec!["x"]))),
+        ]);
+
+        let (result, promotions) = merge_record_batches(&[first, second]).unwrap();
+
+        assert_eq!(result.num_rows(), 2);
+        assert_eq!(
+            promotions,
+            vec![TypePromotion {
+                column_name: "a".to_owned(),
+                batch_index: 0,
+                from_type: ArrowDataType::Int64,
+                to_type: ArrowDataType::Float64,
+            }]
+        );
+    }
+
+    fn template() -> Schema {
+        Schema::new(vec![
+            Field::new("a", ArrowDataType::Float64, false),
+            Field::new("c", ArrowDataType::Utf8, false),
+        ])
+    }
+
+    #[test]
+    fn conform_fills_missing_columns_and_drops_extra_ones() {
+        let rb = batch(vec![
+            ("b", Arc::new(StringArray::from(vec!["x", "y"]))),
+            ("a", Arc::new(Int64Array::from(vec![1, 2]))),
+        ]);
+
+        let result = conform_record_batch(&rb, &template(), ExtraColumns::Drop).unwrap();
+
+        let expected = batch(vec![
+            ("a", Arc::new(Float64Array::from(vec![1.0, 2.0]))),
+            ("c", Arc::new(StringArray::from(vec![None::<&str>, None]))),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn conform_extra_columns_can_be_an_error() {
+        let rb = batch(vec![
+            ("a", Arc::new(Float64Array::from(vec![1.0]))),
+            ("b", Arc::new(StringArray::from(vec!["x"]))),
+        ]);
+
+        let err = conform_record_batch(&rb, &template(), ExtraColumns::Error).unwrap_err();
+
+        assert!(matches!(
+            err.kind,
+            FastExcelErrorKind::ColumnNotFound(IdxOrName::Name(ref name)) if name == "b"
+        ));
+    }
+
+    #[test]
+    fn constant_string_column_is_appended() {
+        let rb = batch(vec![("a", Arc::new(Float64Array::from(vec![1.0, 2.0])))]);
+
+        let result = with_constant_string_column(&rb, "source", "file.xlsx").unwrap();
+
+        assert_eq!(result.num_columns(), 2);
+        assert_eq!(
+            result.column(1).as_ref(),
+            &StringArray::from(vec!["file.xlsx", "file.xlsx"]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn constant_string_column_cannot_shadow_existing_column() {
+        let rb = batch(vec![("a", Arc::new(Float64Array::from(vec![1.0])))]);
+
+        let err = with_constant_string_column(&rb, "a", "file.xlsx").unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::InvalidParameters(_)));
+    }
+}
@@ -1 +1,6 @@
+pub(crate) mod autofilter;
+pub(crate) mod concat;
 pub(crate) mod schema;
+pub(crate) mod thread_pool;
+pub(crate) mod workbook_xml;
+pub(crate) mod zip;
@@ -1,48 +1,177 @@
 use std::cmp::min;
 
-/// Determines how many rows should be used for schema sampling, based on the provided parameter,
+use pyo3::{prelude::PyAnyMethods, Bound, FromPyObject, PyAny, PyResult};
+
+use crate::{
+    error::{py_errors::IntoPyResult, FastExcelErrorKind},
+    types::read_options::SampleStrategy,
+};
+
+/// The rows used to determine the dtype of columns, either as a number of rows or as a fraction
+/// of the rows that are read
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SchemaSampleRows {
+    Count(usize),
+    Fraction(f64),
+}
+
+impl FromPyObject<'_> for SchemaSampleRows {
+    fn extract_bound(py_value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(count) = py_value.extract::<usize>() {
+            Ok(Self::Count(count))
+        } else if let Ok(fraction) = py_value.extract::<f64>() {
+            if fraction > 0.0 && fraction <= 1.0 {
+                Ok(Self::Fraction(fraction))
+            } else {
+                Err(FastExcelErrorKind::InvalidParameters(format!(
+                    "a fraction of the rows must be greater than 0 and at most 1, got {fraction}"
+                ))
+                .into())
+            }
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_value:?} is neither a number of rows nor a fraction of the rows"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// The rows used to guess the dtype of columns: every `step`th row from `start` to `end`
+/// (excluded)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SampleRows {
+    start: usize,
+    end: usize,
+    step: usize,
+}
+
+impl SampleRows {
+    /// All the rows from `start` to `end` (excluded)
+    pub(crate) fn new(start: usize, end: usize) -> Self {
+        Self {
+            start,
+            end,
+            step: 1,
+        }
+    }
+
+    pub(crate) fn rows(&self) -> impl Iterator<Item = usize> {
+        (self.start..self.end).step_by(self.step)
+    }
+}
+
+/// Determines which rows should be used for schema sampling, based on the provided parameters,
 /// and the sheet's offset and limit.
 ///
+/// A fraction of the rows is rounded up, so that at least one row is sampled. With the `Spread`
+/// strategy, the sampled rows are spread over all rows with a stride computed from their count.
+///
 /// Note that here, the limit should be retrieved from the sheet's `limit()` method, and must not
 /// be out of the sheet's bounds
 pub(crate) fn get_schema_sample_rows(
-    sample_rows: Option<usize>,
+    sample_rows: Option<SchemaSampleRows>,
+    strategy: SampleStrategy,
     offset: usize,
     limit: usize,
-) -> usize {
+) -> SampleRows {
+    let height = limit.saturating_sub(offset);
     // Checking how many rows we want to use to determine the dtype for a column. If sample_rows is
     // not provided, we sample limit rows, i.e on the entire column
-    let sample_rows = offset + sample_rows.unwrap_or(limit);
-    // If sample_rows is higher than the sheet's limit, use the limit instead
-    min(sample_rows, limit)
+    let sample_count = match sample_rows {
+        None => limit,
+        Some(SchemaSampleRows::Count(count)) => count,
+        Some(SchemaSampleRows::Fraction(fraction)) => {
+            ((height as f64 * fraction).ceil() as usize).max(1)
+        }
+    };
+    match strategy {
+        // If sample_rows is higher than the sheet's limit, use the limit instead
+        SampleStrategy::Head => SampleRows::new(offset, min(offset + sample_count, limit)),
+        SampleStrategy::Spread => SampleRows {
+            start: offset,
+            end: limit,
+            step: height.div_ceil(sample_count.max(1)).max(1),
+        },
+        SampleStrategy::Full => SampleRows::new(offset, limit),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::get_schema_sample_rows;
+    use super::{get_schema_sample_rows, SampleRows, SchemaSampleRows};
+    use crate::types::read_options::SampleStrategy;
     use rstest::rstest;
 
     #[rstest]
     // default value, 50 rows sheet, row limit should be 50
-    #[case(Some(1000), 0, 50, 50)]
+    #[case(Some(SchemaSampleRows::Count(1000)), 0, 50, 50)]
     // default value, 5000 rows sheet, row limit should be 1000
-    #[case(Some(1000), 0, 5000, 1000)]
+    #[case(Some(SchemaSampleRows::Count(1000)), 0, 5000, 1000)]
     // default value, 1500 rows sheet, offset of 1000, row limit should be 1500
-    #[case(Some(1000), 1000, 1500, 1500)]
+    #[case(Some(SchemaSampleRows::Count(1000)), 1000, 1500, 1500)]
     // 100 sampling size, 1500 rows sheet, offset of 1000, row limit should be 1100
-    #[case(Some(100), 1000, 1500, 1100)]
+    #[case(Some(SchemaSampleRows::Count(100)), 1000, 1500, 1100)]
     // No value, 50 rows sheet, row limit should be 50
     #[case(None, 0, 50, 50)]
     // No value, 5000 rows sheet, row limit should be 5000
     #[case(None, 0, 5000, 5000)]
     // no value, 1500 rows sheet, offset of 1000, row limit should be 1500
     #[case(None, 1000, 1500, 1500)]
+    // 10% of a 5000 rows sheet, row limit should be 500
+    #[case(Some(SchemaSampleRows::Fraction(0.1)), 0, 5000, 500)]
+    // 10% of 500 rows after an offset of 1000, row limit should be 1050
+    #[case(Some(SchemaSampleRows::Fraction(0.1)), 1000, 1500, 1050)]
+    // 10% of a 5 rows sheet is rounded up to one row
+    #[case(Some(SchemaSampleRows::Fraction(0.1)), 0, 5, 1)]
+    // the whole sheet
+    #[case(Some(SchemaSampleRows::Fraction(1.0)), 2, 50, 50)]
     fn test_get_schema_sample_rows_return_values(
-        #[case] sample_rows: Option<usize>,
+        #[case] sample_rows: Option<SchemaSampleRows>,
         #[case] offset: usize,
         #[case] limit: usize,
         #[case] expected: usize,
     ) {
-        assert_eq!(get_schema_sample_rows(sample_rows, offset, limit), expected);
+        assert_eq!(
+            get_schema_sample_rows(sample_rows, SampleStrategy::Head, offset, limit),
+            SampleRows::new(offset, expected)
+        );
+    }
+
+    #[rstest]
+    // 3 rows out of 9, every third row is sampled
+    #[case(Some(SchemaSampleRows::Count(3)), 0, 9, vec![0, 3, 6])]
+    // 4 rows out of 9, the stride is rounded up
+    #[case(Some(SchemaSampleRows::Count(4)), 0, 9, vec![0, 3, 6])]
+    // the last row is reached after an offset
+    #[case(Some(SchemaSampleRows::Count(2)), 2, 6, vec![2, 4])]
+    // more rows than available
+    #[case(Some(SchemaSampleRows::Count(1000)), 1, 4, vec![1, 2, 3])]
+    #[case(Some(SchemaSampleRows::Fraction(0.5)), 0, 6, vec![0, 2, 4])]
+    #[case(None, 0, 3, vec![0, 1, 2])]
+    fn spread_sample_rows(
+        #[case] sample_rows: Option<SchemaSampleRows>,
+        #[case] offset: usize,
+        #[case] limit: usize,
+        #[case] expected: Vec<usize>,
+    ) {
+        let sample_rows =
+            get_schema_sample_rows(sample_rows, SampleStrategy::Spread, offset, limit);
+
+        assert_eq!(sample_rows.rows().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn full_sample_ignores_the_number_of_rows() {
+        assert_eq!(
+            get_schema_sample_rows(
+                Some(SchemaSampleRows::Count(2)),
+                SampleStrategy::Full,
+                1,
+                50
+            ),
+            SampleRows::new(1, 50)
+        );
     }
 }
@@ -0,0 +1,66 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::error::{FastExcelErrorKind, FastExcelResult};
+
+/// The thread pools of the reads setting `n_threads`, by number of threads. A pool is built by the
+/// first read using its number of threads and shared by the following ones, so that reading a
+/// sheet in batches does not spawn threads for every batch
+static THREAD_POOLS: OnceLock<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceLock::new();
+
+/// Runs `op` in the pool of `n_threads` threads. Without `n_threads`, `op` runs on the calling
+/// thread, and its parallel iterators in the global rayon pool, which has a thread per CPU
+pub(crate) fn install<R: Send>(
+    n_threads: Option<usize>,
+    op: impl FnOnce() -> R + Send,
+) -> FastExcelResult<R> {
+    let Some(n_threads) = n_threads else {
+        return Ok(op());
+    };
+    let pool = {
+        let mut pools = THREAD_POOLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        match pools.get(&n_threads) {
+            Some(pool) => Arc::clone(pool),
+            None => {
+                let pool = ThreadPoolBuilder::new()
+                    .num_threads(n_threads)
+                    .thread_name(move |idx| format!("fastexcel-{n_threads}-{idx}"))
+                    .build()
+                    .map(Arc::new)
+                    .map_err(|err| {
+                        FastExcelErrorKind::Internal(format!(
+                            "could not start a pool of {n_threads} threads: {err}"
+                        ))
+                    })?;
+                pools.insert(n_threads, Arc::clone(&pool));
+                pool
+            }
+        }
+    };
+    Ok(pool.install(op))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pools_are_shared_by_number_of_threads() {
+        let thread_name = || std::thread::current().name().map(str::to_owned);
+
+        assert_eq!(install(Some(2), rayon::current_num_threads).unwrap(), 2);
+        assert_eq!(install(Some(3), rayon::current_num_threads).unwrap(), 3);
+        assert!(install(Some(2), thread_name)
+            .unwrap()
+            .is_some_and(|name| name.starts_with("fastexcel-2-")));
+        let pools = THREAD_POOLS.get().unwrap().lock().unwrap();
+        assert!(pools.contains_key(&2) && pools.contains_key(&3));
+    }
+}
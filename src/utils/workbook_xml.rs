@@ -0,0 +1,253 @@
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    fs::File,
+    io::{BufReader, Cursor, Read, Seek, SeekFrom},
+    sync::Arc,
+};
+
+use calamine::XlsxError;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use zip::ZipArchive;
+
+use crate::error::{FastExcelError, FastExcelErrorKind, FastExcelResult};
+
+/// The archive of an xlsx workbook, used to read what calamine does not expose
+pub(crate) enum WorkbookArchive {
+    File(BufReader<File>),
+    Bytes(Cursor<Arc<[u8]>>),
+}
+
+impl Read for WorkbookArchive {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::File(file) => file.read(buf),
+            Self::Bytes(bytes) => bytes.read(buf),
+        }
+    }
+}
+
+impl Seek for WorkbookArchive {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            Self::File(file) => file.seek(pos),
+            Self::Bytes(bytes) => bytes.seek(pos),
+        }
+    }
+}
+
+/// Returns whether an xlsx workbook stores its dates as days since 1904-01-01 rather than since
+/// 1899-12-30, as set by the `date1904` attribute of its `workbookPr` element
+pub(crate) fn uses_1904_date_system<R: Read + Seek>(reader: R) -> FastExcelResult<bool> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    Ok(
+        element_attributes(&mut archive, "xl/workbook.xml", b"workbookPr", &["date1904"])?
+            .into_iter()
+            .any(|attributes| {
+                matches!(attributes.as_slice(), [Some(date1904)] if date1904 == "1" || date1904 == "true")
+            }),
+    )
+}
+
+/// Returns the unique strings of the shared strings table of an xlsx workbook, in order of first
+/// appearance. The text of rich text runs is concatenated, and phonetic hints are ignored.
+///
+/// Workbooks storing their strings inline have no shared strings table.
+pub(crate) fn shared_strings<R: Read + Seek>(reader: R) -> FastExcelResult<Vec<String>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let target = element_attributes(
+        &mut archive,
+        "xl/_rels/workbook.xml.rels",
+        b"Relationship",
+        &["Type", "Target"],
+    )?
+    .into_iter()
+    .find_map(|attributes| match attributes.as_slice() {
+        [Some(relationship_type), Some(target)]
+            if relationship_type.ends_with("/sharedStrings") =>
+        {
+            Some(target.clone())
+        }
+        _ => None,
+    });
+    let Some(target) = target else {
+        return Ok(Vec::new());
+    };
+    let file = archive.by_name(&part_path(target)).map_err(xlsx_error)?;
+    let mut reader = Reader::from_reader(BufReader::new(file));
+    let mut buf = Vec::new();
+    let mut strings = Vec::new();
+    let mut current = String::new();
+    // Text is only read in the `t` elements of a string item, outside of phonetic runs
+    let (mut in_text, mut in_phonetic_run) = (false, false);
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xlsx_error)? {
+            Event::Start(element) => match element.local_name().as_ref() {
+                b"si" => current.clear(),
+                b"t" => in_text = !in_phonetic_run,
+                b"rPh" => in_phonetic_run = true,
+                _ => {}
+            },
+            Event::End(element) => match element.local_name().as_ref() {
+                b"si" => strings.push(std::mem::take(&mut current)),
+                b"t" => in_text = false,
+                b"rPh" => in_phonetic_run = false,
+                _ => {}
+            },
+            Event::Empty(element) if element.local_name().as_ref() == b"si" => {
+                strings.push(String::new())
+            }
+            Event::Text(text) if in_text => current.push_str(&text.unescape().map_err(xlsx_error)?),
+            Event::CData(text) if in_text => {
+                current.push_str(&String::from_utf8_lossy(&text.into_inner()))
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    let mut seen = HashSet::new();
+    strings.retain(|string| seen.insert(string.clone()));
+    Ok(strings)
+}
+
+/// Returns the number of header rows of an xlsx table, as set by the `headerRowCount` attribute
+/// of its `table` element. Tables have a single header row unless told otherwise
+pub(crate) fn table_header_row_count<R: Read + Seek>(
+    reader: R,
+    table_name: &str,
+) -> FastExcelResult<u32> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let table_paths: Vec<String> = archive
+        .file_names()
+        .filter(|path| path.starts_with("xl/tables/") && path.ends_with(".xml"))
+        .map(str::to_owned)
+        .collect();
+    for path in table_paths {
+        let header_row_count = element_attributes(
+            &mut archive,
+            &path,
+            b"table",
+            &["displayName", "headerRowCount"],
+        )?
+        .into_iter()
+        .find_map(|attributes| match attributes.as_slice() {
+            [Some(display_name), header_row_count] if display_name == table_name => {
+                Some(header_row_count.clone())
+            }
+            _ => None,
+        });
+        if let Some(header_row_count) = header_row_count {
+            return header_row_count
+                .map_or(Ok(1), |count| count.parse::<u32>().map_err(xlsx_error));
+        }
+    }
+    Err(
+        FastExcelErrorKind::Internal(format!("table \"{table_name}\" not found in xl/tables"))
+            .into(),
+    )
+}
+
+/// The path in the archive of a part targeted by a relationship of the workbook. Targets are
+/// either absolute, or relative to the xl directory
+pub(crate) fn part_path(target: String) -> String {
+    match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_owned(),
+        None if target.starts_with("xl/") => target,
+        None => format!("xl/{target}"),
+    }
+}
+
+/// Returns the values of the given attributes for every `element_name` element of an XML file
+/// of the archive
+pub(crate) fn element_attributes<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    path: &str,
+    element_name: &[u8],
+    attribute_names: &[&str],
+) -> FastExcelResult<Vec<Vec<Option<String>>>> {
+    let file = archive.by_name(path).map_err(xlsx_error)?;
+    let mut reader = Reader::from_reader(BufReader::new(file));
+    let mut buf = Vec::new();
+    let mut elements = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xlsx_error)? {
+            Event::Start(element) | Event::Empty(element)
+                if element.local_name().as_ref() == element_name =>
+            {
+                elements.push(
+                    attribute_names
+                        .iter()
+                        .map(|name| attribute_value(&reader, &element, name))
+                        .collect::<FastExcelResult<_>>()?,
+                );
+            }
+            Event::Eof => return Ok(elements),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+pub(crate) fn attribute_value<B>(
+    reader: &Reader<B>,
+    element: &BytesStart<'_>,
+    name: &str,
+) -> FastExcelResult<Option<String>> {
+    element
+        .try_get_attribute(name)
+        .map_err(xlsx_error)?
+        .map(|attribute| {
+            attribute
+                .decode_and_unescape_value(reader)
+                .map(Cow::into_owned)
+                .map_err(xlsx_error)
+        })
+        .transpose()
+}
+
+pub(crate) fn xlsx_error(err: impl Into<XlsxError>) -> FastExcelError {
+    FastExcelErrorKind::CalamineError(calamine::Error::Xlsx(err.into())).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("fixture-date-serials.xlsx", false)]
+    #[case("fixture-date-serials-1904.xlsx", true)]
+    fn date_system_of_the_workbook(#[case] fixture: &str, #[case] expected: bool) {
+        let file = File::open(format!("python/tests/fixtures/{fixture}")).unwrap();
+
+        assert_eq!(
+            uses_1904_date_system(BufReader::new(file)).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("fixture-shared-strings.xlsx", &["name", "apple", "pear", "rich text", ""])]
+    #[case("fixture-autofilter.xlsx", &[])]
+    fn unique_shared_strings(#[case] fixture: &str, #[case] expected: &[&str]) {
+        let file = File::open(format!("python/tests/fixtures/{fixture}")).unwrap();
+
+        assert_eq!(shared_strings(BufReader::new(file)).unwrap(), expected);
+    }
+
+    #[test]
+    fn header_row_count_of_a_table() {
+        let file = File::open("python/tests/fixtures/sheet-with-tables.xlsx").unwrap();
+
+        assert_eq!(
+            table_header_row_count(BufReader::new(file), "users").unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::error::{ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult};
+
+/// Signature of a zip local file header, which zip archives (xlsx, xlsb, ods) start with
+const LOCAL_FILE_HEADER_SIGNATURE: &[u8; 4] = b"PK\x03\x04";
+/// Signature of the end of central directory record
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: &[u8; 4] = b"PK\x05\x06";
+/// The end of central directory record is 22 bytes long, followed by a comment of at most
+/// `u16::MAX` bytes
+const END_OF_CENTRAL_DIRECTORY_MAX_SIZE: u64 = 22 + u16::MAX as u64;
+
+/// Quickly checks that a zip archive is complete, by looking for its end of central directory
+/// record. When a zip archive is truncated, that record is the first thing missing.
+///
+/// Readers that do not contain a zip archive are not checked. The reader is rewound before
+/// returning.
+pub(crate) fn check_zip_archive<R: Read + Seek>(reader: &mut R) -> FastExcelResult<()> {
+    let len = reader.seek(SeekFrom::End(0)).map_err(read_error)?;
+    reader.rewind().map_err(read_error)?;
+
+    let mut signature = [0; 4];
+    if len < signature.len() as u64 {
+        return Ok(());
+    }
+    reader.read_exact(&mut signature).map_err(read_error)?;
+    reader.rewind().map_err(read_error)?;
+    if &signature != LOCAL_FILE_HEADER_SIGNATURE {
+        return Ok(());
+    }
+
+    let tail_len = len.min(END_OF_CENTRAL_DIRECTORY_MAX_SIZE);
+    reader
+        .seek(SeekFrom::End(-(tail_len as i64)))
+        .map_err(read_error)?;
+    let mut tail = Vec::with_capacity(tail_len as usize);
+    reader.read_to_end(&mut tail).map_err(read_error)?;
+    reader.rewind().map_err(read_error)?;
+
+    if tail
+        .windows(END_OF_CENTRAL_DIRECTORY_SIGNATURE.len())
+        .any(|window| window == END_OF_CENTRAL_DIRECTORY_SIGNATURE)
+    {
+        Ok(())
+    } else {
+        Err(
+            FastExcelErrorKind::CorruptFile("the zip central directory is missing".to_owned())
+                .into(),
+        )
+    }
+}
+
+fn read_error(err: std::io::Error) -> FastExcelError {
+    FastExcelError::from(FastExcelErrorKind::Internal(err.to_string()))
+        .with_context(|| "could not read file")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn fixture_bytes() -> Vec<u8> {
+        std::fs::read("python/tests/fixtures/fixture-single-sheet.xlsx").unwrap()
+    }
+
+    #[test]
+    fn complete_archive_is_valid() {
+        let mut cursor = Cursor::new(fixture_bytes());
+        cursor.set_position(10);
+
+        check_zip_archive(&mut cursor).unwrap();
+
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn truncated_archive_is_corrupt() {
+        let bytes = fixture_bytes();
+        let mut cursor = Cursor::new(bytes[..bytes.len() / 2].to_vec());
+
+        let err = check_zip_archive(&mut cursor).unwrap_err();
+
+        assert!(matches!(err.kind, FastExcelErrorKind::CorruptFile(_)));
+    }
+
+    #[test]
+    fn non_zip_content_is_not_checked() {
+        let mut cursor = Cursor::new(b"\xD0\xCF\x11\xE0 not a zip archive".to_vec());
+
+        check_zip_archive(&mut cursor).unwrap();
+
+        assert_eq!(cursor.position(), 0);
+    }
+}